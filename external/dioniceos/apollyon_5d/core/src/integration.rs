@@ -2,6 +2,20 @@
 //!
 //! Implements Heun's method (RK2) for time-stepping the dynamical system.
 //! Section 4 of the specification.
+//!
+//! An adaptive Dormand–Prince RK45 scheme is available through
+//! [`Integrator::adaptive`]. It still reports states on the fixed output grid
+//! of the [`TimeConfig`], but covers each interval Δt with as many internal
+//! steps as its local error estimate requires, shrinking them near stiff or
+//! rapidly growing regions. Steps are never shrunk below a floor (see
+//! [`Integrator::with_min_step`]); reaching it means the system has become
+//! too stiff for an explicit scheme, and [`Integrator::integrate`] stops
+//! there instead of reporting states it could not resolve.
+//!
+//! For conservative systems [`Integrator::symplectic`] selects a Störmer–Verlet
+//! (leapfrog) scheme whose energy error stays bounded over long runs instead of
+//! drifting as it does under RK2. It is only valid for separable vector
+//! fields; see [`IntegrationMethod::StormerVerlet`].
 
 use crate::dynamics::VectorField;
 use crate::state::State5D;
@@ -37,15 +51,88 @@ impl TimeConfig {
     }
 }
 
+/// Default smallest internal step of the adaptive scheme, relative to Δt
+const MIN_STEP_FRACTION: f64 = 1e-9;
+
+/// Safety factor applied to the optimal step-size estimate
+const STEP_SAFETY: f64 = 0.9;
+
+/// Bounds on the step-size change between consecutive attempts
+const MIN_STEP_SCALE: f64 = 0.2;
+const MAX_STEP_SCALE: f64 = 5.0;
+
+// Dormand–Prince 5(4) Butcher tableau
+const DP_A21: f64 = 1.0 / 5.0;
+const DP_A31: f64 = 3.0 / 40.0;
+const DP_A32: f64 = 9.0 / 40.0;
+const DP_A41: f64 = 44.0 / 45.0;
+const DP_A42: f64 = -56.0 / 15.0;
+const DP_A43: f64 = 32.0 / 9.0;
+const DP_A51: f64 = 19372.0 / 6561.0;
+const DP_A52: f64 = -25360.0 / 2187.0;
+const DP_A53: f64 = 64448.0 / 6561.0;
+const DP_A54: f64 = -212.0 / 729.0;
+const DP_A61: f64 = 9017.0 / 3168.0;
+const DP_A62: f64 = -355.0 / 33.0;
+const DP_A63: f64 = 46732.0 / 5247.0;
+const DP_A64: f64 = 49.0 / 176.0;
+const DP_A65: f64 = -5103.0 / 18656.0;
+/// Fifth-order weights (also the last stage row, FSAL)
+const DP_B: [f64; 7] = [
+    35.0 / 384.0,
+    0.0,
+    500.0 / 1113.0,
+    125.0 / 192.0,
+    -2187.0 / 6784.0,
+    11.0 / 84.0,
+    0.0,
+];
+/// Difference between fifth- and embedded fourth-order weights
+const DP_E: [f64; 7] = [
+    71.0 / 57600.0,
+    0.0,
+    -71.0 / 16695.0,
+    71.0 / 1920.0,
+    -17253.0 / 339200.0,
+    22.0 / 525.0,
+    -1.0 / 40.0,
+];
+
+/// Time-stepping scheme used by an [`Integrator`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IntegrationMethod {
+    /// Fixed-step Heun's method (RK2)
+    Heun,
+    /// Adaptive Dormand–Prince RK45 with per-step error control
+    ///
+    /// `tolerance` bounds the local error of every internal step, mixed
+    /// absolute/relative: |eᵢ| ≤ tol · (1 + |σᵢ|). Steps that would need to
+    /// shrink below `min_step` count as a failure to resolve the dynamics.
+    DormandPrince { tolerance: f64, min_step: f64 },
+    /// Symplectic Störmer–Verlet (kick-drift-kick leapfrog)
+    ///
+    /// `positions[i]` marks σᵢ as a position coordinate q; all other
+    /// components are momenta p. The scheme is symplectic and second order
+    /// only for separable fields, where dq/dt depends on p alone and dp/dt on
+    /// q alone, as for Hamiltonians H(q, p) = T(p) + V(q). In coupling-matrix
+    /// terms every non-zero Cᵢⱼ must link a position to a momentum, no
+    /// intrinsic rates are allowed, and `Product` couplings (which depend on
+    /// σᵢ itself) break separability. Components with zero derivative may sit
+    /// on either side.
+    StormerVerlet { positions: [bool; 5] },
+}
+
 /// Numerical integrator for the dynamical system
 ///
-/// Implements Heun's method (RK2) as specified in Equations (12)-(13):
+/// By default implements Heun's method (RK2) as specified in
+/// Equations (12)-(13):
 /// σ̃ⁿ⁺¹ = σⁿ + Δt · F(σⁿ)
 /// σⁿ⁺¹ = σⁿ + (Δt/2) · [F(σⁿ) + F(σ̃ⁿ⁺¹)]
 #[derive(Debug)]
 pub struct Integrator {
     pub vector_field: VectorField,
     pub time_config: TimeConfig,
+    pub method: IntegrationMethod,
 }
 
 impl Integrator {
@@ -54,17 +141,93 @@ impl Integrator {
         Integrator {
             vector_field,
             time_config,
+            method: IntegrationMethod::Heun,
+        }
+    }
+
+    /// Create an adaptive Dormand–Prince integrator
+    ///
+    /// `time_config.dt` sets the output interval and the largest internal
+    /// step; `tolerance` controls the local error per internal step. The step
+    /// floor defaults to `1e-9 · Δt`.
+    pub fn adaptive(vector_field: VectorField, time_config: TimeConfig, tolerance: f64) -> Self {
+        assert!(tolerance > 0.0, "Tolerance must be positive");
+
+        Integrator {
+            vector_field,
+            method: IntegrationMethod::DormandPrince {
+                tolerance,
+                min_step: time_config.dt * MIN_STEP_FRACTION,
+            },
+            time_config,
+        }
+    }
+
+    /// Set the smallest internal step of an adaptive integrator
+    ///
+    /// A higher floor bounds the work spent per output interval: once the
+    /// error control asks for steps below it, integration stops.
+    pub fn with_min_step(mut self, min_step: f64) -> Self {
+        assert!(min_step > 0.0, "Minimum step must be positive");
+        match &mut self.method {
+            IntegrationMethod::DormandPrince {
+                min_step: floor, ..
+            } => *floor = min_step,
+            _ => panic!("Minimum step only applies to adaptive integrators"),
+        }
+        self
+    }
+
+    /// Create a symplectic Störmer–Verlet integrator for a separable field
+    ///
+    /// `positions` marks which components are position coordinates; see
+    /// [`IntegrationMethod::StormerVerlet`] for the fields this is valid for.
+    pub fn symplectic(
+        vector_field: VectorField,
+        time_config: TimeConfig,
+        positions: [bool; 5],
+    ) -> Self {
+        Integrator {
+            vector_field,
+            time_config,
+            method: IntegrationMethod::StormerVerlet { positions },
         }
     }
 
-    /// Perform a single Heun step: σⁿ → σⁿ⁺¹
+    /// Advance the state by one output interval Δt: σⁿ → σⁿ⁺¹
     ///
     /// # Arguments
     /// * `state` - Current state σⁿ
     ///
     /// # Returns
     /// Next state σⁿ⁺¹
+    ///
+    /// If an adaptive step hits its floor, the last accepted state inside the
+    /// interval is returned, mirroring the Heun fallback.
     pub fn step(&self, state: &State5D) -> State5D {
+        self.advance(state).unwrap_or_else(|partial| {
+            eprintln!("Warning: adaptive step size collapsed, keeping last accepted state");
+            partial
+        })
+    }
+
+    /// Advance by Δt, or return the last accepted state as the error when
+    /// the adaptive step size falls below its floor
+    fn advance(&self, state: &State5D) -> Result<State5D, State5D> {
+        match self.method {
+            IntegrationMethod::Heun => Ok(self.heun_step(state)),
+            IntegrationMethod::DormandPrince {
+                tolerance,
+                min_step,
+            } => self.dormand_prince_step(state, tolerance, min_step),
+            IntegrationMethod::StormerVerlet { positions } => {
+                Ok(self.stormer_verlet_step(state, &positions))
+            }
+        }
+    }
+
+    /// Perform a single Heun step over Δt
+    fn heun_step(&self, state: &State5D) -> State5D {
         let dt = self.time_config.dt;
 
         // Predictor step: σ̃ⁿ⁺¹ = σⁿ + Δt · F(σⁿ)
@@ -93,13 +256,138 @@ impl Integrator {
         state_new
     }
 
+    /// Perform a single Störmer–Verlet step over Δt
+    ///
+    /// p^(n+½) = pⁿ + (Δt/2) · F_p(qⁿ)
+    /// q^(n+1) = qⁿ + Δt · F_q(p^(n+½))
+    /// p^(n+1) = p^(n+½) + (Δt/2) · F_p(q^(n+1))
+    fn stormer_verlet_step(&self, state: &State5D, positions: &[bool; 5]) -> State5D {
+        let dt = self.time_config.dt;
+
+        // Advance the components selected by `move_positions` along F(σ)
+        let partial_update = |state: &State5D, h: f64, move_positions: bool| {
+            let rate = self.vector_field.evaluate(state);
+            let mut next = *state;
+            for (i, &is_position) in positions.iter().enumerate() {
+                if is_position == move_positions {
+                    next.data[i] += h * rate.get(i);
+                }
+            }
+            next
+        };
+
+        let half_kick = partial_update(state, dt / 2.0, false);
+        let drift = partial_update(&half_kick, dt, true);
+        let state_new = partial_update(&drift, dt / 2.0, false);
+
+        if !state_new.is_valid() {
+            eprintln!("Warning: Störmer-Verlet step produced invalid state, keeping current state");
+            return *state;
+        }
+
+        state_new
+    }
+
+    /// Cover Δt with adaptive Dormand–Prince steps
+    ///
+    /// Rejected steps are retried with a smaller step size. If the step size
+    /// collapses below `min_step` the solution is changing faster than the
+    /// tolerance can follow, and the last accepted state is returned as the
+    /// error.
+    fn dormand_prince_step(
+        &self,
+        state: &State5D,
+        tolerance: f64,
+        min_step: f64,
+    ) -> Result<State5D, State5D> {
+        let dt = self.time_config.dt;
+
+        let mut current = *state;
+        let mut elapsed = 0.0;
+        let mut h = dt;
+        let mut k1 = self.vector_field.evaluate(&current);
+
+        while elapsed < dt {
+            let remaining = dt - elapsed;
+            let last = h >= remaining;
+            h = h.min(remaining);
+            let (candidate, k7, error) = self.dormand_prince_trial(&current, &k1, h, tolerance);
+
+            if candidate.is_valid() && error <= 1.0 {
+                current = candidate;
+                elapsed = if last { dt } else { elapsed + h };
+                k1 = k7;
+            }
+
+            let scale = if error.is_finite() && candidate.is_valid() {
+                if error == 0.0 {
+                    MAX_STEP_SCALE
+                } else {
+                    (STEP_SAFETY * error.powf(-0.2)).clamp(MIN_STEP_SCALE, MAX_STEP_SCALE)
+                }
+            } else {
+                MIN_STEP_SCALE
+            };
+            h *= scale;
+
+            // A short tail of the interval may legitimately need a tiny step
+            if elapsed < dt && h < min_step.min(dt - elapsed) {
+                return Err(current);
+            }
+        }
+
+        Ok(current)
+    }
+
+    /// One Dormand–Prince trial step of size `h`
+    ///
+    /// Returns the fifth-order solution, F at that solution (first stage of
+    /// the next step) and the scaled RMS error estimate; the step is
+    /// acceptable when the error is at most 1.
+    fn dormand_prince_trial(
+        &self,
+        state: &State5D,
+        k1: &State5D,
+        h: f64,
+        tolerance: f64,
+    ) -> (State5D, State5D, f64) {
+        let f = &self.vector_field;
+        let k2 = f.evaluate(&(*state + *k1 * (h * DP_A21)));
+        let k3 = f.evaluate(&(*state + (*k1 * DP_A31 + k2 * DP_A32) * h));
+        let k4 = f.evaluate(&(*state + (*k1 * DP_A41 + k2 * DP_A42 + k3 * DP_A43) * h));
+        let k5 =
+            f.evaluate(&(*state + (*k1 * DP_A51 + k2 * DP_A52 + k3 * DP_A53 + k4 * DP_A54) * h));
+        let k6 = f.evaluate(
+            &(*state + (*k1 * DP_A61 + k2 * DP_A62 + k3 * DP_A63 + k4 * DP_A64 + k5 * DP_A65) * h),
+        );
+        let next = *state
+            + (*k1 * DP_B[0] + k3 * DP_B[2] + k4 * DP_B[3] + k5 * DP_B[4] + k6 * DP_B[5]) * h;
+        let k7 = f.evaluate(&next);
+
+        let stages = [*k1, k2, k3, k4, k5, k6, k7];
+        let mut error_sq = 0.0;
+        for i in 0..5 {
+            let local: f64 = stages
+                .iter()
+                .zip(DP_E.iter())
+                .map(|(k, e)| k.get(i) * e)
+                .sum::<f64>()
+                * h;
+            let scale = tolerance * (1.0 + state.get(i).abs().max(next.get(i).abs()));
+            error_sq += (local / scale).powi(2);
+        }
+
+        (next, k7, (error_sq / 5.0).sqrt())
+    }
+
     /// Integrate from initial state to final time, storing all states
     ///
     /// # Arguments
     /// * `initial_state` - Initial condition σ⁰
     ///
     /// # Returns
-    /// Vector of (time, state) pairs for the trajectory
+    /// Vector of (time, state) pairs for the trajectory. It ends early if the
+    /// state becomes invalid or an adaptive step hits its floor.
     pub fn integrate(&self, initial_state: State5D) -> Vec<(f64, State5D)> {
         let num_steps = self.time_config.num_steps();
         let mut trajectory = Vec::with_capacity(num_steps + 1);
@@ -108,7 +396,16 @@ impl Integrator {
         trajectory.push((self.time_config.t0, state));
 
         for n in 0..num_steps {
-            state = self.step(&state);
+            state = match self.advance(&state) {
+                Ok(next) => next,
+                Err(_) => {
+                    eprintln!(
+                        "Integration stopped at step {}: adaptive step size reached its floor",
+                        n + 1
+                    );
+                    break;
+                }
+            };
             let t = self.time_config.time_at_step(n + 1);
             trajectory.push((t, state));
 
@@ -131,12 +428,18 @@ impl Integrator {
     }
 
     /// Integrate and return only final state
+    ///
+    /// Like [`integrate`](Self::integrate), this stops at the last resolved
+    /// output state if an adaptive step hits its floor.
     pub fn integrate_final(&self, initial_state: State5D) -> State5D {
         let num_steps = self.time_config.num_steps();
         let mut state = initial_state;
 
         for _ in 0..num_steps {
-            state = self.step(&state);
+            state = match self.advance(&state) {
+                Ok(next) => next,
+                Err(_) => break,
+            };
             if !state.is_valid() {
                 break;
             }
@@ -149,7 +452,7 @@ impl Integrator {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::coupling::CouplingMatrix;
+    use crate::coupling::{CouplingMatrix, CouplingType};
     use crate::dynamics::{SystemParameters, VectorField};
 
     #[test]
@@ -204,6 +507,116 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_adaptive_matches_heun_on_smooth_system() {
+        // SIR and financial demo configurations
+        let demos = [
+            (
+                crate::template::Template::sir_model(0.3, 0.1, 0.01),
+                TimeConfig::new(0.1, 0.0, 100.0),
+                State5D::new(0.99, 0.01, 0.0, 0.0, 0.0),
+            ),
+            (
+                crate::template::Template::financial_market(0.2, 0.1, 0.05),
+                TimeConfig::new(0.05, 0.0, 50.0),
+                State5D::new(1.0, 0.5, 0.0, 1.0, 0.1),
+            ),
+        ];
+        for (template, tc, initial) in demos {
+            let vf = template.to_vector_field();
+            let heun = Integrator::new(vf.clone(), tc).integrate(initial);
+            let adaptive = Integrator::adaptive(vf, tc, 1e-8).integrate(initial);
+            assert_eq!(heun.len(), adaptive.len(), "{}", template.name);
+            for ((t_h, s_h), (t_a, s_a)) in heun.iter().zip(adaptive.iter()) {
+                assert_eq!(t_h, t_a);
+                // Relative once the state grows (the market model reaches |σ| ≈ 800)
+                let diff = (*s_h - *s_a).norm();
+                let bound = 1e-4 * s_h.norm().max(1.0);
+                assert!(diff < bound, "{} at t={}: {}", template.name, t_h, diff);
+            }
+        }
+
+        // Exact decay check: σ(1) = exp(-1) to within the tolerance
+        let mut coupling = CouplingMatrix::zero();
+        for i in 0..5 {
+            coupling.strengths[i][i] = -1.0;
+        }
+        let decay = Integrator::adaptive(
+            VectorField::from_coupling(coupling),
+            TimeConfig::new(0.5, 0.0, 1.0),
+            1e-10,
+        );
+        let final_state = decay.integrate_final(State5D::new(1.0, 1.0, 1.0, 1.0, 1.0));
+        for i in 0..5 {
+            assert!((final_state.get(i) - (-1.0_f64).exp()).abs() < 1e-8);
+        }
+    }
+
+    #[test]
+    fn test_adaptive_stabilizes_predator_prey() {
+        let vf = crate::template::Template::predator_prey(0.1, 0.05, 0.05).to_vector_field();
+        let tc = TimeConfig::new(0.5, 0.0, 30.0);
+        let initial = State5D::new(0.3, 0.2, 0.1, 1.0, 1.0);
+
+        // Heun diverges at this output interval ...
+        let heun = Integrator::new(vf.clone(), tc).integrate_final(initial);
+        assert!(heun.get(0).abs() > 1e6);
+
+        // ... while adaptive steps keep the prey populations bounded.
+        let trajectory = Integrator::adaptive(vf, tc, 1e-8).integrate(initial);
+        assert_eq!(trajectory.len(), tc.num_steps() + 1);
+        for (_, state) in &trajectory {
+            assert!(state.is_valid());
+            assert!(state.get(0) > 0.0 && state.get(0) < 100.0);
+        }
+    }
+
+    #[test]
+    fn test_adaptive_stops_at_step_floor() {
+        // Past t ≈ 30 predation stiffness grows with the resources; a floor
+        // of 1e-5 is reached well before t = 50.
+        let vf = crate::template::Template::predator_prey(0.1, 0.05, 0.05).to_vector_field();
+        let tc = TimeConfig::new(0.01, 0.0, 50.0);
+        let initial = State5D::new(0.3, 0.2, 0.1, 1.0, 1.0);
+
+        let integrator = Integrator::adaptive(vf, tc, 1e-8).with_min_step(1e-5);
+        let trajectory = integrator.integrate(initial);
+        let (t_end, last) = *trajectory.last().unwrap();
+        assert!(t_end > 30.0 && t_end < tc.t_final, "stopped at t={}", t_end);
+        assert!(trajectory.iter().all(|(_, s)| s.is_valid()));
+        assert_eq!(integrator.integrate_final(initial), last);
+    }
+
+    #[test]
+    fn test_symplectic_energy_stays_bounded() {
+        // q' = p, p' = -q with energy E = (q² + p²)/2
+        let mut coupling = CouplingMatrix::zero();
+        coupling.set(0, 1, 1.0, CouplingType::Linear);
+        coupling.set(1, 0, -1.0, CouplingType::Linear);
+        let vf = VectorField::from_coupling(coupling);
+
+        // 500 periods at 20 steps per period
+        let tc = TimeConfig::new(
+            0.1 * std::f64::consts::PI,
+            0.0,
+            1000.0 * std::f64::consts::PI,
+        );
+        let initial = State5D::new(1.0, 0.0, 0.0, 0.0, 0.0);
+        let energy = |s: &State5D| 0.5 * (s.get(0).powi(2) + s.get(1).powi(2));
+        let positions = [true, false, false, false, false];
+
+        let verlet = Integrator::symplectic(vf.clone(), tc, positions).integrate_states(initial);
+        let max_error = verlet
+            .iter()
+            .map(|s| (energy(s) - 0.5).abs())
+            .fold(0.0, f64::max);
+        assert!(max_error < 0.02);
+
+        // Heun's energy error grows monotonically over the same run
+        let heun = Integrator::new(vf, tc).integrate_final(initial);
+        assert!((energy(&heun) - 0.5).abs() > 10.0 * max_error);
+    }
+
     #[test]
     fn test_heun_single_step() {
         let coupling = CouplingMatrix::identity();
@@ -109,11 +109,16 @@ fn demo_predator_prey() {
     // Initial conditions: small populations
     let initial = State5D::new(0.3, 0.2, 0.1, 1.0, 1.0);
 
-    // Integrate for 50 time units with small timestep
-    let time_config = integration::TimeConfig::new(0.001, 0.0, 50.0);
-    let integrator = integration::Integrator::new(vf, time_config);
+    // Integrate for 50 time units with adaptive internal steps. Predation
+    // terms grow stiff as the predator population rises, which a fixed-step
+    // scheme cannot follow. Resources keep growing, so eventually even the
+    // adaptive steps would have to shrink below the floor; integration stops
+    // there rather than reporting unresolved states.
+    let time_config = integration::TimeConfig::new(0.01, 0.0, 50.0);
+    let integrator = integration::Integrator::adaptive(vf, time_config, 1e-8).with_min_step(1e-5);
 
-    let states = integrator.integrate_states(initial);
+    let trajectory = integrator.integrate(initial);
+    let states: Vec<State5D> = trajectory.iter().map(|&(_, s)| s).collect();
 
     // Check if integration was successful
     if states.is_empty() || !states.last().unwrap().is_valid() {
@@ -121,6 +126,14 @@ fn demo_predator_prey() {
         return;
     }
 
+    let (t_reached, _) = trajectory.last().unwrap();
+    if *t_reached < time_config.t_final {
+        println!(
+            "Limitation: stopped at t = {:.2} of {:.0}; the system became too stiff for an explicit integrator",
+            t_reached, time_config.t_final
+        );
+    }
+
     // Find extrema (for oscillations) - only use valid states
     let valid_states: Vec<&State5D> = states.iter().filter(|s| s.is_valid()).collect();
 
@@ -0,0 +1,153 @@
+//! Criterion harness behind the throughput figures quoted in the README.
+//!
+//! Run with `cargo bench --bench quantum_walk`. Besides Criterion's own
+//! report, a full run writes `target/criterion/quantum_walk_report.json`
+//! holding the `BenchmarkMetadata` and results of the quantum walk benchmark
+//! suite next to the measured operations per second of every benchmark, so
+//! numbers from different machines can be compared like the suite artifacts
+//! of `quantum_walk_bench`.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use criterion::{Criterion, black_box};
+use metatron_qso::prelude::*;
+use metatron_qso::quantum_walk::{BenchmarkMetadata, QuantumWalkBenchmarkSuite};
+use metatron_qso::vqa::qaoa::create_maxcut_hamiltonian;
+use serde::Serialize;
+
+/// Benchmarks in `group/function` form, as laid out by Criterion on disk.
+const BENCHMARKS: [(&str, &str); 5] = [
+    ("quantum_walk", "evolve"),
+    ("quantum_walk", "probabilities_at"),
+    ("quantum_walk", "benchmark_suite"),
+    ("vqe", "iteration"),
+    ("qaoa", "step"),
+];
+
+/// JSON artifact written after a full run.
+#[derive(Serialize)]
+struct PerformanceReport {
+    metadata: BenchmarkMetadata,
+    suite: QuantumWalkBenchmarkSuite,
+    ops_per_second: BTreeMap<String, f64>,
+}
+
+/// Suite configuration shared with the `quantum_walk_bench` binary.
+fn benchmark_suite(qso: &QuantumStateOperator) -> QuantumWalkBenchmarkSuite {
+    qso.quantum_walk_benchmarker()
+        .benchmark_suite(&qso.basis_state(0), 0.5, 40, 0.05, 0.25, 24)
+}
+
+fn quantum_walk(c: &mut Criterion) {
+    let hamiltonian = MetatronHamiltonian::new(&MetatronGraph::new(), &QSOParameters::default());
+    let walk = ContinuousTimeQuantumWalk::new(&hamiltonian);
+    let initial = QuantumState::basis_state(0).unwrap();
+    let propagator = walk.propagator(&initial);
+    let qso = QuantumStateOperator::new(QSOParameters::default().with_dephasing(0.032));
+
+    let mut group = c.benchmark_group("quantum_walk");
+    group.bench_function("evolve", |b| {
+        b.iter(|| walk.evolve(black_box(&initial), black_box(1.0)))
+    });
+    group.bench_function("probabilities_at", |b| {
+        b.iter(|| propagator.probabilities_at(black_box(1.0)))
+    });
+    group.sample_size(10);
+    group.bench_function("benchmark_suite", |b| b.iter(|| benchmark_suite(&qso)));
+    group.finish();
+}
+
+fn vqe(c: &mut Criterion) {
+    let hamiltonian = Arc::new(MetatronHamiltonian::new(
+        &MetatronGraph::new(),
+        &QSOParameters::default(),
+    ));
+    let vqe = VQEBuilder::new()
+        .hamiltonian(hamiltonian)
+        .ansatz_type(AnsatzType::Metatron)
+        .ansatz_depth(1)
+        .optimizer(OptimizerType::Adam)
+        .max_iterations(1)
+        .seed(7)
+        .build();
+
+    let mut group = c.benchmark_group("vqe");
+    group.bench_function("iteration", |b| b.iter(|| vqe.run()));
+    group.finish();
+}
+
+fn qaoa(c: &mut Criterion) {
+    let edges = [(0, 1), (1, 2), (2, 3), (3, 0), (0, 2), (1, 3)];
+    let qaoa = QAOABuilder::new()
+        .cost_hamiltonian(Arc::new(create_maxcut_hamiltonian(&edges)))
+        .depth(1)
+        .optimizer(OptimizerType::NelderMead)
+        .max_iterations(1)
+        .seed(7)
+        .build();
+
+    let mut group = c.benchmark_group("qaoa");
+    group.bench_function("step", |b| b.iter(|| qaoa.run()));
+    group.finish();
+}
+
+/// Criterion's output directory, `<target>/criterion`.
+fn criterion_dir() -> PathBuf {
+    Path::new(env!("CARGO_TARGET_TMPDIR"))
+        .parent()
+        .expect("target directory")
+        .join("criterion")
+}
+
+/// Mean time per iteration from Criterion's estimates, as operations per second.
+fn ops_per_second(group: &str, function: &str) -> Option<f64> {
+    let path = criterion_dir()
+        .join(group)
+        .join(function)
+        .join("new/estimates.json");
+    let estimates: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(path).ok()?).ok()?;
+    let mean_ns = estimates["mean"]["point_estimate"].as_f64()?;
+    Some(1e9 / mean_ns)
+}
+
+fn write_report() -> Result<(), Box<dyn std::error::Error>> {
+    let qso = QuantumStateOperator::new(QSOParameters::default().with_dephasing(0.032));
+    let suite = benchmark_suite(&qso);
+    let ops_per_second = BENCHMARKS
+        .iter()
+        .filter_map(|&(group, function)| {
+            ops_per_second(group, function).map(|ops| (format!("{group}/{function}"), ops))
+        })
+        .collect();
+    let report = PerformanceReport {
+        metadata: suite.metadata.clone(),
+        suite,
+        ops_per_second,
+    };
+
+    let path = criterion_dir().join("quantum_walk_report.json");
+    fs::write(&path, serde_json::to_string_pretty(&report)? + "\n")?;
+    println!("Wrote {}", path.display());
+    Ok(())
+}
+
+fn main() {
+    let mut criterion = Criterion::default()
+        .output_directory(&criterion_dir())
+        .configure_from_args();
+    quantum_walk(&mut criterion);
+    vqe(&mut criterion);
+    qaoa(&mut criterion);
+    criterion.final_summary();
+
+    // `cargo test` runs benches once in test mode; only real runs report
+    if std::env::args().any(|arg| arg == "--bench")
+        && let Err(error) = write_report()
+    {
+        eprintln!("Failed to write benchmark report: {error}");
+    }
+}
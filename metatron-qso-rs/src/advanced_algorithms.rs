@@ -3,7 +3,9 @@
 //! This module implements cutting-edge quantum algorithms specifically
 //! optimized for the 13-node Metatron Cube geometry:
 //!
-//! 1. **Metatron Grover Search** - Spatial search variant for 13-node graph
+//! 1. **Metatron Grover Search** - Continuous-time spatial search on the
+//!    13-node graph, and discrete Grover search built on the reusable
+//!    [`amplitude_amplification`] primitive
 //! 2. **Platonic Boson Sampling** - Interference patterns in platonic solids
 //! 3. **Graph-based Quantum ML** - Machine learning on Metatron structure
 //!
@@ -11,10 +13,13 @@
 //! tailored to sacred geometry structures.
 
 use crate::MetatronGraph;
+use crate::graph::metatron::PlatonicSolid;
 use crate::hamiltonian::MetatronHamiltonian;
+use crate::quantum::operator::{OperatorMatrix, QuantumOperator};
 use crate::quantum::state::QuantumState;
 use nalgebra::DMatrix;
 use num_complex::Complex64 as Complex;
+use rand::Rng;
 use std::f64::consts::PI;
 
 // Custom error type for this module
@@ -57,6 +62,16 @@ impl Default for GroverConfig {
 /// - Symmetry group G_M for error mitigation
 /// - Natural oracle implementation via graph Laplacian
 ///
+/// # Search modes
+/// [`search`](Self::search), [`multi_target_search`](Self::multi_target_search),
+/// [`search_with_oracle`](Self::search_with_oracle) and
+/// [`adaptive_search`](Self::adaptive_search) evolve the uniform state under
+/// H = −L − γ Σ_t |t⟩⟨t| for a continuous time. That evolution is not a
+/// product of oracle and diffusion reflections, so these methods do not go
+/// through [`amplitude_amplification`]. Discrete Grover search, with
+/// [`optimal_iterations`] rounds of the standard iterate, is
+/// [`amplified_search`](Self::amplified_search).
+///
 /// # Performance
 /// - Classical: O(N) = O(13) steps
 /// - Standard Grover: O(√N) = O(3.6) steps
@@ -185,7 +200,10 @@ impl MetatronGroverSearch {
         }
     }
 
-    /// Execute spatial search for target node
+    /// Execute continuous-time spatial search for target node
+    ///
+    /// See [`amplified_search`](Self::amplified_search) for the discrete
+    /// Grover variant.
     ///
     /// # Arguments
     /// - `target_node`: Node index to search for (0-12)
@@ -267,12 +285,23 @@ impl MetatronGroverSearch {
 
     /// Multi-target search: find any of multiple marked nodes
     ///
-    /// This is more efficient than sequential single-target searches.
+    /// This is more efficient than sequential single-target searches. Like
+    /// [`search`](Self::search) it evolves under the search Hamiltonian
+    /// rather than applying Grover iterations.
     pub fn multi_target_search(
         &self,
         targets: &[usize],
         oracle_strength: f64,
     ) -> Result<MultiGroverSearchResult> {
+        if targets.is_empty() {
+            return Err(String::from(
+                "Multi-target search requires at least one target",
+            ));
+        }
+        if let Some(&target) = targets.iter().find(|&&t| t >= self.dimension) {
+            return Err(format!("Target node {} out of bounds", target));
+        }
+
         // Construct Hamiltonian with multiple oracle terms
         let laplacian = self.graph.laplacian_matrix();
         let mut h = -laplacian;
@@ -304,9 +333,66 @@ impl MetatronGroverSearch {
         })
     }
 
+    /// Search for any node satisfying `oracle`
+    ///
+    /// Every node for which the predicate returns `true` receives an oracle
+    /// term, then the search proceeds as [`multi_target_search`](Self::multi_target_search).
+    /// Fails if no node is marked.
+    pub fn search_with_oracle(
+        &self,
+        oracle: impl Fn(usize) -> bool,
+        oracle_strength: f64,
+    ) -> Result<MultiGroverSearchResult> {
+        let targets: Vec<usize> = (0..self.dimension).filter(|&node| oracle(node)).collect();
+        if targets.is_empty() {
+            return Err(String::from("Oracle marks no nodes"));
+        }
+        self.multi_target_search(&targets, oracle_strength)
+    }
+
+    /// Discrete-time Grover search for any of `targets`
+    ///
+    /// This is the discrete Grover entry point; the other search methods are
+    /// continuous-time (see the type docs).
+    ///
+    /// Starts from the uniform superposition and applies
+    /// [`optimal_iterations`] rounds of [`amplitude_amplification`] with a
+    /// phase oracle on the targets. `optimal_time` in the result holds the
+    /// number of iterations performed.
+    pub fn amplified_search(&self, targets: &[usize]) -> Result<MultiGroverSearchResult> {
+        if targets.is_empty() {
+            return Err(String::from(
+                "Amplified search requires at least one target",
+            ));
+        }
+        if let Some(&target) = targets.iter().find(|&&t| t >= self.dimension) {
+            return Err(format!("Target node {} out of bounds", target));
+        }
+
+        let initial_state = QuantumState::uniform_superposition();
+        let oracle = phase_oracle(targets);
+        let diffusion = diffusion_operator(&initial_state);
+        let iterations = optimal_iterations(targets.len(), self.dimension);
+        let final_state = amplitude_amplification(&initial_state, &oracle, &diffusion, iterations);
+
+        let success_prob: f64 = targets
+            .iter()
+            .map(|&t| final_state.probability_at_node(t))
+            .sum();
+
+        Ok(MultiGroverSearchResult {
+            targets: targets.to_vec(),
+            success_prob,
+            optimal_time: iterations as f64,
+            final_state,
+        })
+    }
+
     /// Adaptive search: adjusts oracle strength dynamically
     ///
-    /// Useful when the number of marked items is unknown.
+    /// Runs the continuous-time [`search`](Self::search) for several oracle
+    /// strengths and keeps the best. Useful when the number of marked items
+    /// is unknown.
     pub fn adaptive_search(&self, target: usize) -> Result<GroverSearchResult> {
         // Try different oracle strengths and select best
         let oracle_candidates = vec![1.0, 2.0, 5.0, 10.0, 20.0];
@@ -346,6 +432,50 @@ pub struct MultiGroverSearchResult {
     pub final_state: QuantumState,
 }
 
+/// Apply `iterations` rounds of amplitude amplification, (D·O)^k |ψ₀⟩
+///
+/// `oracle` flips the phase of the good subspace and `diffusion` reflects
+/// about the initial state; [`phase_oracle`] and [`diffusion_operator`] build
+/// the standard choices, but any pair of unitaries may be supplied.
+pub fn amplitude_amplification(
+    initial_state: &QuantumState,
+    oracle: &QuantumOperator,
+    diffusion: &QuantumOperator,
+    iterations: usize,
+) -> QuantumState {
+    let grover_iterate = diffusion.compose(oracle);
+    (0..iterations).fold(initial_state.clone(), |state, _| {
+        state.apply(&grover_iterate)
+    })
+}
+
+/// Iteration count ⌊π/4·√(N/M)⌋ maximising the marked-subspace probability
+///
+/// Returns 0 when nothing (or everything) is marked, since amplification
+/// cannot improve on the initial state in either case.
+pub fn optimal_iterations(marked_count: usize, n: usize) -> usize {
+    if marked_count == 0 || marked_count >= n {
+        return 0;
+    }
+    (PI / 4.0 * (n as f64 / marked_count as f64).sqrt()).floor() as usize
+}
+
+/// Phase oracle O = I − 2 Σ_t |t⟩⟨t| marking the given nodes
+pub fn phase_oracle(marked: &[usize]) -> QuantumOperator {
+    let mut matrix = OperatorMatrix::identity();
+    for &node in marked {
+        matrix[(node, node)] = Complex::new(-1.0, 0.0);
+    }
+    QuantumOperator::from_matrix(matrix)
+}
+
+/// Diffusion operator D = 2|ψ⟩⟨ψ| − I reflecting about `state`
+pub fn diffusion_operator(state: &QuantumState) -> QuantumOperator {
+    let psi = state.amplitudes();
+    let projector = psi * psi.adjoint();
+    QuantumOperator::from_matrix(projector * Complex::new(2.0, 0.0) - OperatorMatrix::identity())
+}
+
 // ============================================================================
 // 2. PLATONIC BOSON SAMPLING
 // ============================================================================
@@ -376,7 +506,9 @@ pub struct MultiGroverSearchResult {
 /// println!("Output state: {:?}", output);
 /// ```
 pub struct PlatonicBosonSampling {
-    graph: MetatronGraph,
+    /// H = -L on the Metatron graph; its cached eigendecomposition is reused
+    /// for every scattering matrix.
+    hamiltonian: MetatronHamiltonian,
     dimension: usize,
 }
 
@@ -390,7 +522,7 @@ impl PlatonicBosonSampling {
     /// Create new Platonic Boson Sampler
     pub fn new() -> Self {
         Self {
-            graph: MetatronGraph::new(),
+            hamiltonian: MetatronHamiltonian::from_matrix(-MetatronGraph::new().laplacian_matrix()),
             dimension: 13,
         }
     }
@@ -405,7 +537,7 @@ impl PlatonicBosonSampling {
     /// Sampled output mode
     pub fn sample_single_photon(&self, input_mode: usize, time: f64) -> Result<usize> {
         // Scattering matrix U = exp(-iLt)
-        let u = self.compute_scattering_matrix(time)?;
+        let u = self.compute_scattering_matrix(time);
 
         // For single photon: P(output|input) = |U[input, output]|²
         let mut output_probs = vec![0.0; self.dimension];
@@ -415,7 +547,7 @@ impl PlatonicBosonSampling {
         }
 
         // Sample output mode
-        let output_mode = self.sample_from_distribution(&output_probs)?;
+        let output_mode = self.sample_from_distribution(&output_probs, &mut rand::thread_rng())?;
 
         Ok(output_mode)
     }
@@ -437,9 +569,29 @@ impl PlatonicBosonSampling {
         input_mode: usize,
         time: f64,
         num_samples: usize,
+    ) -> Result<Vec<usize>> {
+        self.batch_sample_single_photon_with_rng(
+            input_mode,
+            time,
+            num_samples,
+            &mut rand::thread_rng(),
+        )
+    }
+
+    /// Batch sampling with a caller-supplied RNG
+    ///
+    /// Identical to [`batch_sample_single_photon`](Self::batch_sample_single_photon)
+    /// but draws from `rng`, so a seeded generator (e.g. `StdRng::seed_from_u64`)
+    /// makes the samples reproducible.
+    pub fn batch_sample_single_photon_with_rng<R: Rng + ?Sized>(
+        &self,
+        input_mode: usize,
+        time: f64,
+        num_samples: usize,
+        rng: &mut R,
     ) -> Result<Vec<usize>> {
         // Compute scattering matrix ONCE
-        let u = self.compute_scattering_matrix(time)?;
+        let u = self.compute_scattering_matrix(time);
 
         // Compute output probability distribution ONCE
         let mut output_probs = vec![0.0; self.dimension];
@@ -451,7 +603,7 @@ impl PlatonicBosonSampling {
         // Sample multiple times from same distribution
         let mut samples = Vec::with_capacity(num_samples);
         for _ in 0..num_samples {
-            let output_mode = self.sample_from_distribution(&output_probs)?;
+            let output_mode = self.sample_from_distribution(&output_probs, rng)?;
             samples.push(output_mode);
         }
 
@@ -505,12 +657,12 @@ impl PlatonicBosonSampling {
     /// This function analyzes how Bos samplers interference patterns
     /// differ when restricted to these substructures.
     pub fn analyze_platonic_interference(&self, time: f64) -> Result<PlatonicInterferenceAnalysis> {
-        let u = self.compute_scattering_matrix(time)?;
+        let u = self.compute_scattering_matrix(time);
 
         // Extract submatrices for each Platonic solid
-        let tetrahedron_nodes = vec![0, 1, 2, 7]; // Example: center + 3 hexagon vertices
-        let cube_nodes = vec![7, 8, 9, 10, 11, 12]; // The cube subgraph (nodes 0-12, 6 cube vertices)
-        let octahedron_nodes = vec![1, 2, 3, 4, 5, 6]; // Hexagon forms octahedron
+        let tetrahedron_nodes = PlatonicSolid::Tetrahedron.nodes();
+        let cube_nodes = PlatonicSolid::Cube.nodes();
+        let octahedron_nodes = PlatonicSolid::Octahedron.nodes();
 
         // Compute interference visibility for each solid
         let tetra_visibility = self.compute_interference_visibility(&u, &tetrahedron_nodes);
@@ -526,37 +678,35 @@ impl PlatonicBosonSampling {
         })
     }
 
-    /// Compute scattering matrix U = exp(-iLt)
-    fn compute_scattering_matrix(&self, time: f64) -> Result<DMatrix<Complex>> {
-        let l = self.graph.laplacian_matrix();
-        let h = -l; // Hamiltonian
-
-        // Matrix exponential: U = exp(-iHt)
-        // For now, use eigendecomposition (can be optimized later)
-        let eigen = h.symmetric_eigen();
-        let eigenvalues = eigen.eigenvalues;
-        let eigenvectors = eigen.eigenvectors;
-
-        // U = V · diag(exp(-iλt)) · V†
-        let mut u = DMatrix::zeros(self.dimension, self.dimension);
-        for i in 0..self.dimension {
-            for j in 0..self.dimension {
-                let mut sum = Complex::new(0.0, 0.0);
-                for k in 0..self.dimension {
-                    let phase = Complex::new(0.0, -eigenvalues[k] * time).exp();
-                    sum += eigenvectors[(i, k)] * phase * eigenvectors[(j, k)];
-                }
-                u[(i, j)] = sum;
-            }
-        }
+    /// Scattering matrix U = exp(-iHt) with H = -L of the 13-mode interferometer
+    ///
+    /// `U[(i, j)]` is the amplitude for a photon entering mode `i` to leave
+    /// through mode `j`. U is built from the cached eigendecomposition of H,
+    /// so its unitarity is only as good as that decomposition; see
+    /// [`PlatonicBosonSampling::is_unitary`].
+    pub fn compute_scattering_matrix(&self, time: f64) -> DMatrix<Complex> {
+        let u = self.hamiltonian.time_evolution_operator(time);
+        DMatrix::from_column_slice(self.dimension, self.dimension, u.matrix().as_slice())
+    }
 
-        Ok(u)
+    /// Check that the scattering matrix at `time` satisfies U†U = I, entry by
+    /// entry within `tol`
+    pub fn is_unitary(&self, time: f64, tol: f64) -> bool {
+        let u = self.compute_scattering_matrix(time);
+        let product = u.adjoint() * &u;
+        product.iter().enumerate().all(|(idx, value)| {
+            let (row, col) = (idx % self.dimension, idx / self.dimension);
+            let expected = if row == col { 1.0 } else { 0.0 };
+            (value - Complex::new(expected, 0.0)).norm() < tol
+        })
     }
 
     /// Sample from probability distribution
-    fn sample_from_distribution(&self, probs: &[f64]) -> Result<usize> {
-        use rand::Rng;
-        let mut rng = rand::thread_rng();
+    fn sample_from_distribution<R: Rng + ?Sized>(
+        &self,
+        probs: &[f64],
+        rng: &mut R,
+    ) -> Result<usize> {
         let r: f64 = rng.r#gen(); // `gen` is a reserved keyword in Rust 2024
 
         let mut cumsum = 0.0;
@@ -570,16 +720,63 @@ impl PlatonicBosonSampling {
         Ok(probs.len() - 1) // Fallback
     }
 
-    /// Compute permanent for multi-photon sampling (exponentially hard!)
+    /// Sample an N-photon output Fock state (N ≤ [`MAX_PERMANENT_PHOTONS`])
     ///
-    /// This is the core computational bottleneck of Boson Sampling.
-    /// For N photons, this scales as O(N! · 2^N).
-    fn sample_via_permanent(&self, _input_state: &[usize], _time: f64) -> Result<Vec<usize>> {
-        // TODO: Implement Ryser's algorithm or other permanent approximation
-        // For now, return error
-        Err(String::from(
-            "Multi-photon boson sampling not yet implemented (requires permanent computation)",
-        ))
+    /// Every output pattern T with occupations t_j has probability
+    /// P(T) = |Perm(U_{S,T})|² / (Π s_i! · Π t_j!), where U_{S,T} repeats row i
+    /// of U s_i times and column j t_j times. All C(13+N−1, N) patterns are
+    /// enumerated and each permanent costs O(N·2^N) via [`permanent`], so the
+    /// total work is O(C(N+12, N) · N · 2^N); N = 8 needs 125 970 permanents.
+    fn sample_via_permanent(&self, input_state: &[usize], time: f64) -> Result<Vec<usize>> {
+        let distribution = self.multi_photon_distribution(input_state, time)?;
+        let probs: Vec<f64> = distribution.iter().map(|(_, p)| *p).collect();
+        let index = self.sample_from_distribution(&probs, &mut rand::thread_rng())?;
+        Ok(distribution[index].0.clone())
+    }
+
+    /// Exact output distribution over Fock states for a multi-photon input
+    fn multi_photon_distribution(
+        &self,
+        input_state: &[usize],
+        time: f64,
+    ) -> Result<Vec<(Vec<usize>, f64)>> {
+        if input_state.len() != self.dimension {
+            return Err(format!(
+                "Input state must have {} modes, got {}",
+                self.dimension,
+                input_state.len()
+            ));
+        }
+        let total_photons: usize = input_state.iter().sum();
+        if total_photons > MAX_PERMANENT_PHOTONS {
+            return Err(format!(
+                "Permanent-based sampling supports at most {} photons, got {}",
+                MAX_PERMANENT_PHOTONS, total_photons
+            ));
+        }
+
+        // Number of output patterns C(m + N - 1, N); checked so oversized
+        // requests fail instead of wrapping
+        let num_patterns = binomial(self.dimension + total_photons - 1, total_photons)
+            .ok_or_else(|| String::from("Output pattern count overflows usize"))?;
+
+        let u = self.compute_scattering_matrix(time);
+        let input_modes = occupied_modes(input_state);
+        let input_norm: f64 = input_state.iter().map(|&n| factorial(n)).product();
+
+        let mut distribution = Vec::with_capacity(num_patterns);
+        let mut pattern = vec![0; self.dimension];
+        enumerate_fock_states(&mut pattern, 0, total_photons, &mut |output_state| {
+            let output_modes = occupied_modes(output_state);
+            let sub = DMatrix::from_fn(total_photons, total_photons, |a, b| {
+                u[(input_modes[a], output_modes[b])]
+            });
+            let output_norm: f64 = output_state.iter().map(|&n| factorial(n)).product();
+            let probability = permanent(&sub).norm_sqr() / (input_norm * output_norm);
+            distribution.push((output_state.to_vec(), probability));
+        });
+
+        Ok(distribution)
     }
 
     /// Compute interference visibility metric
@@ -613,6 +810,91 @@ pub struct PlatonicInterferenceAnalysis {
     pub full_metatron_visibility: f64,
 }
 
+/// Largest photon number accepted by permanent-based multi-photon sampling
+pub const MAX_PERMANENT_PHOTONS: usize = 8;
+
+/// Permanent of a square matrix via Ryser's formula
+///
+/// Perm(A) = (−1)ⁿ Σ_{S ⊆ \[n\]} (−1)^{|S|} Π_i Σ_{j∈S} a_ij
+///
+/// Subsets are visited in Gray-code order, so each step adds or removes a
+/// single column from the running row sums: O(n·2ⁿ) instead of O(n²·2ⁿ).
+pub fn permanent(matrix: &DMatrix<Complex>) -> Complex {
+    let n = matrix.nrows();
+    assert_eq!(n, matrix.ncols(), "permanent requires a square matrix");
+    if n == 0 {
+        return Complex::new(1.0, 0.0);
+    }
+
+    let mut row_sums = vec![Complex::new(0.0, 0.0); n];
+    let mut in_subset = vec![false; n];
+    let mut total = Complex::new(0.0, 0.0);
+
+    for k in 1..(1usize << n) {
+        // Gray code g(k) = k ^ (k >> 1) differs from g(k−1) in bit trailing_zeros(k)
+        let column = k.trailing_zeros() as usize;
+        let sign = if in_subset[column] { -1.0 } else { 1.0 };
+        in_subset[column] = !in_subset[column];
+        for (i, sum) in row_sums.iter_mut().enumerate() {
+            *sum += matrix[(i, column)] * sign;
+        }
+
+        let product: Complex = row_sums.iter().product();
+        let subset_size = (k ^ (k >> 1)).count_ones() as usize;
+        if subset_size % 2 == n % 2 {
+            total += product;
+        } else {
+            total -= product;
+        }
+    }
+
+    total
+}
+
+/// Binomial coefficient C(n, k), `None` on overflow
+fn binomial(n: usize, k: usize) -> Option<usize> {
+    let k = k.min(n.checked_sub(k)?);
+    let mut result: usize = 1;
+    for i in 0..k {
+        // result · (n − i) is divisible by (i + 1) at every step
+        result = result.checked_mul(n - i)? / (i + 1);
+    }
+    Some(result)
+}
+
+fn factorial(n: usize) -> f64 {
+    (1..=n).map(|k| k as f64).product()
+}
+
+/// Mode index of every photon, repeated by occupation
+fn occupied_modes(fock_state: &[usize]) -> Vec<usize> {
+    fock_state
+        .iter()
+        .enumerate()
+        .flat_map(|(mode, &count)| std::iter::repeat_n(mode, count))
+        .collect()
+}
+
+/// Visit every distribution of `remaining` photons over modes `mode..`
+fn enumerate_fock_states(
+    pattern: &mut [usize],
+    mode: usize,
+    remaining: usize,
+    visit: &mut dyn FnMut(&[usize]),
+) {
+    if mode == pattern.len() - 1 {
+        pattern[mode] = remaining;
+        visit(pattern);
+        pattern[mode] = 0;
+        return;
+    }
+    for count in (0..=remaining).rev() {
+        pattern[mode] = count;
+        enumerate_fock_states(pattern, mode + 1, remaining - count, visit);
+    }
+    pattern[mode] = 0;
+}
+
 // ============================================================================
 // 3. GRAPH-BASED QUANTUM MACHINE LEARNING
 // ============================================================================
@@ -931,6 +1213,109 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_search_with_oracle_predicate() {
+        let searcher = MetatronGroverSearch::new();
+        let graph = MetatronGraph::new();
+
+        // Cube-layer nodes (7-12) adjacent to node 3
+        let neighbours = graph.neighbours(3);
+        let result = searcher
+            .search_with_oracle(|node| node >= 7 && neighbours.contains(&node), 5.0)
+            .unwrap();
+        let expected: Vec<usize> = (7..13).filter(|n| neighbours.contains(n)).collect();
+        assert_eq!(result.targets, expected);
+        let listed = searcher.multi_target_search(&expected, 5.0).unwrap();
+        assert!((result.success_prob - listed.success_prob).abs() < 1e-12);
+        assert!((0.0..=1.0 + 1e-9).contains(&result.success_prob));
+
+        assert!(searcher.search_with_oracle(|_| false, 5.0).is_err());
+    }
+
+    #[test]
+    fn test_amplitude_amplification() {
+        assert_eq!(optimal_iterations(1, 13), 2);
+        assert_eq!(optimal_iterations(0, 13), 0);
+        assert_eq!(optimal_iterations(13, 13), 0);
+
+        let searcher = MetatronGroverSearch::new();
+        let result = searcher.amplified_search(&[5]).unwrap();
+        assert_eq!(result.optimal_time, 2.0);
+        assert!(result.final_state.is_normalized(1e-10));
+        // sin²(5θ) with sin θ = 1/√13
+        let theta = (1.0 / 13.0_f64).sqrt().asin();
+        assert!((result.success_prob - (5.0 * theta).sin().powi(2)).abs() < 1e-10);
+        assert!(result.success_prob > 0.9);
+    }
+
+    #[test]
+    fn test_ryser_permanent() {
+        let ones = DMatrix::from_element(4, 4, Complex::new(1.0, 0.0));
+        assert!((permanent(&ones) - Complex::new(24.0, 0.0)).norm() < 1e-12);
+
+        let m = DMatrix::from_row_slice(
+            2,
+            2,
+            &[
+                Complex::new(1.0, 0.0),
+                Complex::new(2.0, 0.0),
+                Complex::new(3.0, 0.0),
+                Complex::new(4.0, 0.0),
+            ],
+        );
+        assert!((permanent(&m) - Complex::new(10.0, 0.0)).norm() < 1e-12);
+        assert_eq!(binomial(14, 2), Some(91));
+        assert_eq!(binomial(200, 100), None);
+    }
+
+    #[test]
+    fn test_scattering_matrix_is_unitary() {
+        let sampler = PlatonicBosonSampling::new();
+        for time in [0.0, 0.3, 1.0, 2.5, 10.0, 100.0] {
+            assert!(
+                sampler.is_unitary(time, 1e-10),
+                "U(t = {time}) is not unitary"
+            );
+        }
+
+        // U(0) = I, and H = -L is real symmetric, so U(t) is symmetric
+        let identity = sampler.compute_scattering_matrix(0.0);
+        assert!((identity - DMatrix::<Complex>::identity(13, 13)).norm() < 1e-12);
+        let u = sampler.compute_scattering_matrix(1.0);
+        assert!((&u - u.transpose()).norm() < 1e-10);
+    }
+
+    #[test]
+    fn test_multi_photon_distribution_is_normalized() {
+        let sampler = PlatonicBosonSampling::new();
+        let mut input = vec![0; 13];
+        input[0] = 1;
+        input[3] = 2;
+
+        let distribution = sampler.multi_photon_distribution(&input, 0.7).unwrap();
+        assert_eq!(distribution.len(), 455);
+        let total: f64 = distribution.iter().map(|(_, p)| p).sum();
+        assert!((total - 1.0).abs() < 1e-10);
+
+        let output = sampler.sample_multi_photon(&input, 0.7).unwrap();
+        assert_eq!(output.iter().sum::<usize>(), 3);
+    }
+
+    #[test]
+    fn test_seeded_batch_sampling_is_reproducible() {
+        use rand::SeedableRng;
+        use rand::rngs::StdRng;
+
+        let sampler = PlatonicBosonSampling::new();
+        let first = sampler
+            .batch_sample_single_photon_with_rng(0, 1.0, 200, &mut StdRng::seed_from_u64(5))
+            .unwrap();
+        let second = sampler
+            .batch_sample_single_photon_with_rng(0, 1.0, 200, &mut StdRng::seed_from_u64(5))
+            .unwrap();
+        assert_eq!(first, second);
+    }
+
     #[test]
     fn test_platonic_boson_sampling() {
         let sampler = PlatonicBosonSampling::new();
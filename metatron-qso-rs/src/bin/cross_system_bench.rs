@@ -215,7 +215,7 @@ fn benchmark_metatron_system() -> SystemBenchmark {
     let execution_time = start.elapsed().as_secs_f64() * 1000.0;
 
     // Calculate QAOA performance scores
-    let qaoa_convergence = if qaoa_result.optimization_result.converged {
+    let qaoa_convergence = if qaoa_result.optimization_result.converged() {
         1.0
     } else {
         0.8
@@ -102,7 +102,7 @@ fn benchmark_qaoa_problem(
         std_dev_cost: std_dev,
         iterations: result.optimization_result.iterations,
         quantum_evaluations: result.optimization_result.history.total_quantum_evaluations,
-        converged: result.optimization_result.converged,
+        converged: result.optimization_result.converged(),
         execution_time_ms: execution_time,
     }
 }
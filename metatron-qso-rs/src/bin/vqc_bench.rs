@@ -108,7 +108,7 @@ fn benchmark_vqc_problem(
             .optimization_result
             .history
             .total_quantum_evaluations,
-        converged: training_result.optimization_result.converged,
+        converged: training_result.optimization_result.converged(),
         execution_time_ms: execution_time,
     }
 }
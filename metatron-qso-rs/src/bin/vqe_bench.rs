@@ -120,13 +120,13 @@ fn benchmark_vqe_ansatz(
     let quality_score = calculate_quality_score(
         result.approximation_error,
         result.classical_ground_energy,
-        result.optimization_result.converged,
+        result.optimization_result.converged(),
     );
 
     println!(
         "  → Energy: {:.6}, Converged: {}, Quality: {:.3}, Time: {:.2}ms",
         result.ground_state_energy,
-        result.optimization_result.converged,
+        result.optimization_result.converged(),
         quality_score,
         execution_time
     );
@@ -140,7 +140,7 @@ fn benchmark_vqe_ansatz(
         approximation_error: result.approximation_error,
         iterations: result.optimization_result.iterations,
         quantum_evaluations: result.optimization_result.history.total_quantum_evaluations,
-        converged: result.optimization_result.converged,
+        converged: result.optimization_result.converged(),
         execution_time_ms: execution_time,
         final_gradient_norm,
         quality_score,
@@ -6,25 +6,58 @@ use rand::{Rng, SeedableRng};
 use crate::graph::metatron::MetatronGraph;
 use crate::params::QSOParameters;
 
+/// Time step used to sample the dynamics for [`DTLResonatorNetwork::resonance_spectrum`].
+const SPECTRUM_DT: f64 = 0.02;
+/// Number of samples in the spectral window (window length 81.92).
+const SPECTRUM_SAMPLES: usize = 4096;
+/// Peaks weaker than this fraction of the strongest one are dropped.
+const PEAK_THRESHOLD: f64 = 0.01;
+
 /// Network of 13 coupled DTL resonators placed on the Metatron Cube graph.
 pub struct DTLResonatorNetwork {
     graph: MetatronGraph,
     params: QSOParameters,
     phases: [f64; crate::quantum::METATRON_DIMENSION],
+    num_resonators: usize,
+    damping: f64,
+}
+
+/// Tunable settings of a [`DTLResonatorNetwork`].
+#[derive(Clone, Debug)]
+pub struct DTLNetworkConfig {
+    /// Number of active resonators, on nodes `0..num_resonators` (1–13).
+    /// The remaining nodes stay uncoupled and frozen, and are left out of
+    /// the order parameter.
+    pub num_resonators: usize,
+    /// Kuramoto coupling κ, overriding `QSOParameters::kappa`.
+    pub coupling_strength: f64,
+    /// Pull γ towards the rest phase 0 (the L1 pole), adding −γ·sin φᵢ to
+    /// dφᵢ/dt; a resonator with |ωᵢ| < γ settles instead of rotating.
+    pub damping: f64,
+    /// Seed for the random initial phases (`None` draws from entropy).
+    pub seed: Option<u64>,
+}
+
+impl Default for DTLNetworkConfig {
+    fn default() -> Self {
+        Self {
+            num_resonators: crate::quantum::METATRON_DIMENSION,
+            coupling_strength: QSOParameters::default().kappa,
+            damping: 0.0,
+            seed: None,
+        }
+    }
 }
 
 impl DTLResonatorNetwork {
     /// Instantiate the network with random initial phases.
     pub fn new(graph: MetatronGraph, params: QSOParameters) -> Self {
-        let mut rng = SmallRng::from_entropy();
-        let mut phases = [0.0; crate::quantum::METATRON_DIMENSION];
-        for phase in phases.iter_mut() {
-            *phase = rng.gen_range(0.0..std::f64::consts::TAU);
-        }
         Self {
             graph,
             params,
-            phases,
+            phases: random_phases(SmallRng::from_entropy()),
+            num_resonators: crate::quantum::METATRON_DIMENSION,
+            damping: 0.0,
         }
     }
 
@@ -41,19 +74,27 @@ impl DTLResonatorNetwork {
         _t: f64,
     ) -> [f64; crate::quantum::METATRON_DIMENSION] {
         let adjacency = self.graph.adjacency_matrix();
-        let mut derivatives = self.params.omega;
-        for i in 0..crate::quantum::METATRON_DIMENSION {
+        let mut derivatives = [0.0; crate::quantum::METATRON_DIMENSION];
+        for i in 0..self.num_resonators {
             let mut coupling = 0.0;
-            for j in 0..crate::quantum::METATRON_DIMENSION {
+            for j in 0..self.num_resonators {
                 if adjacency[(i, j)] != 0.0 {
                     coupling += self.params.kappa * (phases[j] - phases[i]).sin();
                 }
             }
-            derivatives[i] += coupling;
+            derivatives[i] = self.params.omega[i] + coupling - self.damping * phases[i].sin();
         }
         derivatives
     }
 
+    /// Advance the phases by exactly one explicit Euler step of size `dt`.
+    pub fn step(&mut self, time: f64, dt: f64) {
+        let derivatives = self.derivative(&self.phases, time);
+        for (phase, derivative) in self.phases.iter_mut().zip(derivatives) {
+            *phase += derivative * dt;
+        }
+    }
+
     /// Integrate the Kuramoto system with explicit Euler integration.
     pub fn integrate(
         &mut self,
@@ -81,13 +122,9 @@ impl DTLResonatorNetwork {
         (times, history)
     }
 
-    /// Kuramoto order parameter r ∈ \[0,1\].
+    /// Kuramoto order parameter r ∈ \[0,1\] over the active resonators.
     pub fn order_parameter(&self, phases: &[f64; crate::quantum::METATRON_DIMENSION]) -> f64 {
-        let sum: Complex64 = phases
-            .iter()
-            .map(|&phi| Complex64::from_polar(1.0, phi))
-            .sum();
-        (sum / (crate::quantum::METATRON_DIMENSION as f64)).norm()
+        self.mean_field(phases).norm()
     }
 
     /// Convert instantaneous phases to DTL amplitude values in \[0,1\].
@@ -122,10 +159,201 @@ impl DTLResonatorNetwork {
         }
     }
 
+    /// Resonant modes of the network as `(frequency, amplitude)` peaks.
+    ///
+    /// The network is integrated from its current phases (without changing
+    /// them) and the complex mean field z(t) = (1/13) Σⱼ e^{iφⱼ(t)}, whose
+    /// modulus is the [order parameter](Self::order_parameter), is analysed
+    /// with a Hann-windowed DFT over 4096 steps of 0.02. Frequencies are in
+    /// cycles per unit time (ω/2π, resolution ≈ 0.012) and signed by the sense
+    /// of rotation; amplitudes are normalised so that a network rotating
+    /// rigidly at one frequency yields a single peak of amplitude 1. Locked
+    /// clusters show up as one peak each, drifting pairs as sidebands split
+    /// by their beat frequency. Peaks are sorted by frequency.
+    pub fn resonance_spectrum(&self) -> Vec<(f64, f64)> {
+        let n = SPECTRUM_SAMPLES;
+        let mut phases = self.phases;
+        let mut signal = Vec::with_capacity(n);
+        for step in 0..n {
+            let window = 0.5 - 0.5 * (std::f64::consts::TAU * step as f64 / n as f64).cos();
+            signal.push(self.mean_field(&phases) * window);
+            let derivatives = self.derivative(&phases, step as f64 * SPECTRUM_DT);
+            for (phase, derivative) in phases.iter_mut().zip(derivatives) {
+                *phase += derivative * SPECTRUM_DT;
+            }
+        }
+
+        // Plain DFT; the Hann window has coherent gain 1/2.
+        let twiddles: Vec<Complex64> = (0..n)
+            .map(|k| Complex64::from_polar(1.0, -std::f64::consts::TAU * k as f64 / n as f64))
+            .collect();
+        let magnitudes: Vec<f64> = (0..n)
+            .map(|k| {
+                let sum: Complex64 = signal
+                    .iter()
+                    .enumerate()
+                    .map(|(m, &z)| z * twiddles[(k * m) % n])
+                    .sum();
+                2.0 * sum.norm() / n as f64
+            })
+            .collect();
+
+        let strongest = magnitudes.iter().copied().fold(0.0, f64::max);
+        let mut peaks: Vec<(f64, f64)> = (0..n)
+            .filter(|&k| {
+                let (left, right) = (magnitudes[(k + n - 1) % n], magnitudes[(k + 1) % n]);
+                magnitudes[k] > left
+                    && magnitudes[k] >= right
+                    && magnitudes[k] >= PEAK_THRESHOLD * strongest
+            })
+            .map(|k| {
+                let bin = if k < n / 2 {
+                    k as f64
+                } else {
+                    k as f64 - n as f64
+                };
+                (bin / (n as f64 * SPECTRUM_DT), magnitudes[k])
+            })
+            .collect();
+        peaks.sort_by(|a, b| a.0.total_cmp(&b.0));
+        peaks
+    }
+
+    /// Strongest peak of [`resonance_spectrum`](Self::resonance_spectrum).
+    pub fn dominant_resonance(&self) -> Option<(f64, f64)> {
+        self.resonance_spectrum()
+            .into_iter()
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+    }
+
+    /// Complex mean field (1/N) Σⱼ e^{iφⱼ} over the N active resonators.
+    fn mean_field(&self, phases: &[f64; crate::quantum::METATRON_DIMENSION]) -> Complex64 {
+        phases[..self.num_resonators]
+            .iter()
+            .map(|&phi| Complex64::from_polar(1.0, phi))
+            .sum::<Complex64>()
+            / self.num_resonators as f64
+    }
+
     /// Access internal phases (useful for analysis).
     pub fn phases(&self) -> &[f64; crate::quantum::METATRON_DIMENSION] {
         &self.phases
     }
+
+    /// Number of active resonators.
+    pub fn num_resonators(&self) -> usize {
+        self.num_resonators
+    }
+}
+
+/// Uniformly random phases in [0, 2π).
+fn random_phases(mut rng: SmallRng) -> [f64; crate::quantum::METATRON_DIMENSION] {
+    let mut phases = [0.0; crate::quantum::METATRON_DIMENSION];
+    for phase in phases.iter_mut() {
+        *phase = rng.gen_range(0.0..std::f64::consts::TAU);
+    }
+    phases
+}
+
+/// Builder for [`DTLResonatorNetwork`].
+///
+/// Defaults to the canonical Metatron graph, [`QSOParameters::default`] and
+/// [`DTLNetworkConfig::default`], which matches [`DTLResonatorNetwork::new`].
+pub struct DTLResonatorNetworkBuilder {
+    graph: MetatronGraph,
+    params: QSOParameters,
+    config: DTLNetworkConfig,
+}
+
+impl DTLResonatorNetworkBuilder {
+    /// Start from the Metatron graph, default parameters and default config.
+    pub fn new() -> Self {
+        Self {
+            graph: MetatronGraph::new(),
+            params: QSOParameters::default(),
+            config: DTLNetworkConfig::default(),
+        }
+    }
+
+    /// Graph the resonators are coupled along.
+    pub fn graph(mut self, graph: MetatronGraph) -> Self {
+        self.graph = graph;
+        self
+    }
+
+    /// Intrinsic frequencies ωᵢ (rad per unit time).
+    pub fn frequencies(mut self, omega: [f64; crate::quantum::METATRON_DIMENSION]) -> Self {
+        self.params.omega = omega;
+        self
+    }
+
+    /// Number of active resonators (1–13), see [`DTLNetworkConfig::num_resonators`].
+    pub fn num_resonators(mut self, num_resonators: usize) -> Self {
+        self.config.num_resonators = num_resonators;
+        self
+    }
+
+    /// Kuramoto coupling κ.
+    pub fn coupling_strength(mut self, kappa: f64) -> Self {
+        self.config.coupling_strength = kappa;
+        self
+    }
+
+    /// Pull γ towards the rest phase, see [`DTLNetworkConfig::damping`].
+    pub fn damping(mut self, damping: f64) -> Self {
+        self.config.damping = damping;
+        self
+    }
+
+    /// Seed for reproducible initial phases.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.config.seed = Some(seed);
+        self
+    }
+
+    /// Replace all tunable settings at once.
+    pub fn config(mut self, config: DTLNetworkConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `num_resonators` is not in 1–13 or `damping` is negative.
+    pub fn build(self) -> DTLResonatorNetwork {
+        let DTLNetworkConfig {
+            num_resonators,
+            coupling_strength,
+            damping,
+            seed,
+        } = self.config;
+        assert!(
+            (1..=crate::quantum::METATRON_DIMENSION).contains(&num_resonators),
+            "num_resonators must be between 1 and 13"
+        );
+        assert!(damping >= 0.0, "damping must be non-negative");
+
+        let rng = match seed {
+            Some(seed) => SmallRng::seed_from_u64(seed),
+            None => SmallRng::from_entropy(),
+        };
+        DTLResonatorNetwork {
+            graph: self.graph,
+            params: QSOParameters {
+                kappa: coupling_strength,
+                ..self.params
+            },
+            phases: random_phases(rng),
+            num_resonators,
+            damping,
+        }
+    }
+}
+
+impl Default for DTLResonatorNetworkBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 #[cfg(test)]
@@ -141,4 +369,103 @@ mod tests {
         let phases = [0.0; crate::quantum::METATRON_DIMENSION];
         assert!((network.order_parameter(&phases) - 1.0).abs() < 1e-12);
     }
+
+    #[test]
+    fn step_matches_a_single_integrate_step() {
+        let mut stepped = DTLResonatorNetworkBuilder::new().seed(5).build();
+        let mut integrated = DTLResonatorNetworkBuilder::new().seed(5).build();
+        stepped.step(0.0, 0.01);
+        integrated.integrate((0.0, 0.01), 0.01);
+        assert_eq!(stepped.phases(), integrated.phases());
+    }
+
+    #[test]
+    fn builder_configures_the_network() {
+        let defaults = DTLResonatorNetworkBuilder::new().seed(7).build();
+        let again = DTLResonatorNetworkBuilder::new().seed(7).build();
+        assert_eq!(defaults.phases(), again.phases());
+        assert_eq!(
+            defaults.num_resonators(),
+            crate::quantum::METATRON_DIMENSION
+        );
+        let phases = *defaults.phases();
+        let reference = DTLResonatorNetwork::new(MetatronGraph::new(), QSOParameters::default());
+        assert_eq!(
+            defaults.derivative(&phases, 0.0),
+            reference.derivative(&phases, 0.0)
+        );
+
+        // Damping stronger than the drive locks every resonator at the
+        // fixed point sin φ = ω/γ, i.e. φ = asin(0.25)
+        let mut damped = DTLResonatorNetworkBuilder::new()
+            .frequencies([0.5; crate::quantum::METATRON_DIMENSION])
+            .coupling_strength(0.0)
+            .damping(2.0)
+            .seed(1)
+            .build();
+        damped.integrate((0.0, 20.0), 0.01);
+        let settled = (0.5_f64 / 2.0).asin();
+        assert!(damped.phases().iter().all(|&phi| {
+            let wrapped = phi.rem_euclid(std::f64::consts::TAU);
+            (wrapped - settled).abs() < 1e-6
+        }));
+
+        // Inactive resonators stay frozen
+        let mut partial = DTLResonatorNetworkBuilder::new()
+            .num_resonators(4)
+            .frequencies([1.0; crate::quantum::METATRON_DIMENSION])
+            .seed(3)
+            .build();
+        let before = *partial.phases();
+        partial.integrate((0.0, 1.0), 0.01);
+        assert_eq!(partial.phases()[4..], before[4..]);
+        assert_ne!(partial.phases()[..4], before[..4]);
+    }
+
+    #[test]
+    fn two_resonator_spectrum() {
+        use std::f64::consts::TAU;
+
+        // Resonators 0 and 1 coupled by one edge; the other eleven are idle
+        let graph = MetatronGraph::from_weighted_edges(&[(0, 1, 1.0)]).unwrap();
+        let (f0, f1) = (1.0, 2.0);
+        let mut omega = [0.0; crate::quantum::METATRON_DIMENSION];
+        omega[0] = TAU * f0;
+        omega[1] = TAU * f1;
+        let params = |kappa| {
+            QSOParameters::new(1.0, [0.0; crate::quantum::METATRON_DIMENSION], omega, kappa)
+        };
+        let resolution = 1.0 / (SPECTRUM_SAMPLES as f64 * SPECTRUM_DT);
+        let start = [0.0; crate::quantum::METATRON_DIMENSION];
+
+        // Strong coupling (|Δω| ≤ 2κ) locks the pair at the mean frequency
+        let locked = DTLResonatorNetwork::new(graph.clone(), params(4.0)).with_phases(start);
+        let peaks = locked.resonance_spectrum();
+        let has_peak = |f: f64, amplitude: f64| {
+            peaks
+                .iter()
+                .any(|&(p, a)| (p - f).abs() <= resolution && (a - amplitude).abs() < 0.02)
+        };
+        assert!(has_peak(1.5, 2.0 / 13.0), "{peaks:?}");
+        assert!(has_peak(0.0, 11.0 / 13.0), "{peaks:?}");
+        assert_eq!(locked.dominant_resonance().unwrap().0, 0.0);
+
+        // Weak coupling lets them drift: sidebands ω̄ ± Ω/2 with the beat
+        // frequency Ω = √(Δω² − 4κ²)
+        let kappa = 1.0;
+        let drifting = DTLResonatorNetwork::new(graph, params(kappa)).with_phases(start);
+        let peaks = drifting.resonance_spectrum();
+        let beat = ((TAU * (f1 - f0)).powi(2) - 4.0 * kappa * kappa).sqrt() / TAU;
+        for f in [1.5 - beat / 2.0, 1.5 + beat / 2.0] {
+            assert!(
+                peaks.iter().any(|&(p, _)| (p - f).abs() <= resolution),
+                "no peak near {f}: {peaks:?}"
+            );
+        }
+        assert!(
+            !peaks
+                .iter()
+                .any(|&(p, a)| (p - 1.5).abs() <= resolution && a > 0.05)
+        );
+    }
 }
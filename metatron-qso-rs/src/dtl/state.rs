@@ -1,8 +1,18 @@
+use std::f64::consts::PI;
 use std::fmt;
 use std::sync::Arc;
 
+use num_complex::Complex64;
 use serde::{Deserialize, Serialize};
 
+use crate::quantum::state::{METATRON_DIMENSION, QuantumState, StateVector};
+
+/// Number of trajectory samples carried by the outer Metatron nodes.
+const QUANTUM_SAMPLES: usize = METATRON_DIMENSION - 1;
+
+/// Values within this distance of a pole decode as a static state.
+const POLE_TOLERANCE: f64 = 1e-9;
+
 /// Enumeration of the fundamental tripolar state classes.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TripolarStateKind {
@@ -103,6 +113,63 @@ impl DTLState {
     pub fn kind(&self) -> TripolarStateKind {
         self.kind
     }
+
+    /// Encode the state as a phase pattern on the 13D Metatron register.
+    ///
+    /// Every node carries amplitude 1/√13, so the result is normalised. The
+    /// centre (node 0) is the phase reference; node k = 1, …, 12 holds the
+    /// sample xₖ = x(tₖ) at tₖ = (k − 1)/12 as the relative phase π·xₖ.
+    /// L0 is thus the uniform superposition and L1 flips the sign of all
+    /// outer nodes, while an LD state records one unit-time period of its
+    /// trajectory. Relative phases survive a global phase, so the encoding
+    /// is a physical state rather than a vector representative.
+    pub fn to_quantum_state(&self) -> QuantumState {
+        let amplitude = 1.0 / (METATRON_DIMENSION as f64).sqrt();
+        let vector = StateVector::from_fn(|node, _| match node {
+            0 => Complex64::new(amplitude, 0.0),
+            k => Complex64::from_polar(amplitude, PI * self.evaluate(sample_time(k - 1))),
+        });
+        QuantumState::from_vector(vector, false)
+    }
+
+    /// Decode a state produced by [`DTLState::to_quantum_state`].
+    ///
+    /// Samples are read back from the phases of nodes 1–12 relative to the
+    /// centre (whose phase counts as zero if it vanishes); magnitudes and
+    /// normalisation are ignored. If every sample sits at the same pole the
+    /// result is the static L0 or L1, otherwise an LD state whose trajectory
+    /// interpolates the samples linearly and repeats with period 1. Round
+    /// trips therefore keep the kind of static states and the values of LD
+    /// states at the sample times.
+    pub fn from_quantum_state(state: &QuantumState) -> Self {
+        let amplitudes = state.amplitudes();
+        let reference = amplitudes[0].arg();
+        let samples: [f64; QUANTUM_SAMPLES] = std::array::from_fn(|k| {
+            let relative = amplitudes[k + 1].arg() - reference;
+            // Wrap into (−π, π]; x and −x encode the same |phase|.
+            let wrapped = Complex64::from_polar(1.0, relative).arg();
+            (wrapped.abs() / PI).clamp(0.0, 1.0)
+        });
+
+        if samples.iter().all(|&x| x < POLE_TOLERANCE) {
+            return Self::l0();
+        }
+        if samples.iter().all(|&x| x > 1.0 - POLE_TOLERANCE) {
+            return Self::l1();
+        }
+        Self::ld_from_function(move |t: f64| {
+            let position = t.rem_euclid(1.0) * QUANTUM_SAMPLES as f64;
+            let index = (position.floor() as usize).min(QUANTUM_SAMPLES - 1);
+            let alpha = position - index as f64;
+            let next = samples[(index + 1) % QUANTUM_SAMPLES];
+            samples[index] + alpha * (next - samples[index])
+        })
+    }
+}
+
+/// Time of the `k`-th trajectory sample in the quantum encoding.
+fn sample_time(k: usize) -> f64 {
+    k as f64 / QUANTUM_SAMPLES as f64
 }
 
 /// Information-theoretic properties of tripolar logic.
@@ -146,6 +213,39 @@ mod tests {
         assert!((0.0..=1.0).contains(&value));
     }
 
+    #[test]
+    fn quantum_state_round_trip_preserves_tripolar_content() {
+        for state in [DTLState::l0(), DTLState::l1()] {
+            let encoded = state.to_quantum_state();
+            assert!(encoded.is_normalized(1e-12));
+            assert_eq!(DTLState::from_quantum_state(&encoded).kind(), state.kind());
+        }
+        // L0 and L1 are distinct physical states, not just a global phase apart
+        let overlap = DTLState::l0()
+            .to_quantum_state()
+            .fidelity(&DTLState::l1().to_quantum_state());
+        assert!(overlap < 0.9);
+
+        let ld = DTLState::ld_oscillatory(1.0, 0.3, 0.4, 0.5);
+        let encoded = ld.to_quantum_state();
+        let decoded = DTLState::from_quantum_state(&encoded);
+        assert_eq!(decoded.kind(), TripolarStateKind::Ld);
+        for k in 0..QUANTUM_SAMPLES {
+            let t = sample_time(k);
+            assert!((decoded.evaluate(t) - ld.evaluate(t)).abs() < 1e-12);
+            // The decoded trajectory has period 1, like the source here
+            assert!((decoded.evaluate(t + 3.0) - ld.evaluate(t)).abs() < 1e-9);
+        }
+
+        // The encoding ignores global phase
+        let rotated = QuantumState::from_vector(
+            encoded.amplitudes() * Complex64::from_polar(1.0, 2.0),
+            false,
+        );
+        let again = DTLState::from_quantum_state(&rotated);
+        assert!((again.evaluate(0.25) - ld.evaluate(0.25)).abs() < 1e-12);
+    }
+
     #[test]
     fn tripolar_capacity_advantage() {
         let advantage = TripolarInformationTheory::relative_advantage();
@@ -1,15 +1,44 @@
 use std::collections::VecDeque;
+use std::fmt::Write;
 
-use nalgebra::SMatrix;
+use nalgebra::{SMatrix, SymmetricEigen};
 use petgraph::graph::UnGraph;
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 
 use crate::quantum::METATRON_DIMENSION;
+use crate::quantum::operator::QuantumOperator;
 
 /// Alias for the 13×13 adjacency matrix type.
 pub type AdjacencyMatrix = SMatrix<f64, 13, 13>;
 /// Alias for the Laplacian matrix type.
 pub type LaplacianMatrix = SMatrix<f64, 13, 13>;
+/// Alias for the hop-distance matrix type.
+pub type DistanceMatrix = SMatrix<usize, 13, 13>;
+
+/// Errors raised when building a graph from a custom edge list.
+#[derive(Debug, Error, PartialEq)]
+pub enum GraphError {
+    /// Edge endpoint outside the 13-node space.
+    #[error("edge ({u}, {v}) references a node outside 0..{dimension}")]
+    NodeOutOfRange {
+        u: usize,
+        v: usize,
+        dimension: usize,
+    },
+
+    /// Edge connects a node to itself.
+    #[error("self-loop on node {node} is not allowed")]
+    SelfLoop { node: usize },
+
+    /// The same undirected edge was listed twice.
+    #[error("edge ({u}, {v}) listed more than once")]
+    DuplicateEdge { u: usize, v: usize },
+
+    /// Edge weight is NaN or infinite.
+    #[error("edge ({u}, {v}) has non-finite weight {weight}")]
+    InvalidWeight { u: usize, v: usize, weight: f64 },
+}
 
 /// Classification of Metatron Cube nodes.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -19,6 +48,127 @@ pub enum NodeType {
     Cube,
 }
 
+/// Platonic solids embedded in the Metatron Cube node set.
+///
+/// The dodecahedron needs 20 vertices and has no embedding in 13 nodes, so
+/// only four solids are available. Memberships follow the node coordinates:
+///
+/// - **Tetrahedron**: the cube-layer corners with an odd number of negative
+///   coordinates, nodes 8, 9, 11, 12.
+/// - **Octahedron**: the hexagon, read as an octahedron seen down a 3-fold
+///   axis; every vertex is adjacent to all others except its antipode.
+/// - **Cube**: the classic Metatron's Cube projection down a body diagonal,
+///   where the hexagon holds six corners and the two remaining corners
+///   coincide at the centre (node 0).
+/// - **Icosahedron**: nodes 1–12 seen down a 3-fold axis; the hexagon is the
+///   zig-zag equatorial belt, nodes 7–9 and 10–12 the polar faces.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PlatonicSolid {
+    Tetrahedron,
+    Cube,
+    Octahedron,
+    Icosahedron,
+}
+
+impl PlatonicSolid {
+    /// All embeddable solids.
+    pub const ALL: [PlatonicSolid; 4] = [
+        PlatonicSolid::Tetrahedron,
+        PlatonicSolid::Cube,
+        PlatonicSolid::Octahedron,
+        PlatonicSolid::Icosahedron,
+    ];
+
+    /// Metatron nodes belonging to the solid, in ascending order.
+    pub fn nodes(self) -> Vec<usize> {
+        match self {
+            PlatonicSolid::Tetrahedron => vec![8, 9, 11, 12],
+            PlatonicSolid::Cube => (0..=6).collect(),
+            PlatonicSolid::Octahedron => (1..=6).collect(),
+            PlatonicSolid::Icosahedron => (1..=12).collect(),
+        }
+    }
+
+    /// Edges of the solid as `(min, max)` node pairs.
+    pub fn edges(self) -> Vec<(usize, usize)> {
+        match self {
+            PlatonicSolid::Tetrahedron => {
+                let nodes = self.nodes();
+                let mut edges = Vec::new();
+                for (i, &u) in nodes.iter().enumerate() {
+                    for &v in &nodes[i + 1..] {
+                        edges.push((u, v));
+                    }
+                }
+                edges
+            }
+            PlatonicSolid::Cube => {
+                let mut edges: Vec<(usize, usize)> = (1..=6).map(|hex| (0, hex)).collect();
+                edges.extend(hexagon_ring());
+                edges
+            }
+            PlatonicSolid::Octahedron => {
+                let mut edges = Vec::new();
+                for u in 1..=6 {
+                    for v in u + 1..=6 {
+                        if v - u != 3 {
+                            edges.push((u, v));
+                        }
+                    }
+                }
+                edges
+            }
+            PlatonicSolid::Icosahedron => vec![
+                (1, 2),
+                (1, 6),
+                (1, 7),
+                (1, 10),
+                (1, 12),
+                (2, 3),
+                (2, 7),
+                (2, 8),
+                (2, 10),
+                (3, 4),
+                (3, 8),
+                (3, 10),
+                (3, 11),
+                (4, 5),
+                (4, 8),
+                (4, 9),
+                (4, 11),
+                (5, 6),
+                (5, 9),
+                (5, 11),
+                (5, 12),
+                (6, 7),
+                (6, 9),
+                (6, 12),
+                (7, 8),
+                (7, 9),
+                (8, 9),
+                (10, 11),
+                (10, 12),
+                (11, 12),
+            ],
+        }
+    }
+}
+
+fn hexagon_ring() -> impl Iterator<Item = (usize, usize)> {
+    (1..=6).map(|hex| if hex == 6 { (1, 6) } else { (hex, hex + 1) })
+}
+
+impl NodeType {
+    /// Fill colour used by the DOT and GraphML exports.
+    pub fn color(self) -> &'static str {
+        match self {
+            NodeType::Center => "gold",
+            NodeType::Hexagon => "lightblue",
+            NodeType::Cube => "lightcoral",
+        }
+    }
+}
+
 /// Metadata describing a node within the Metatron Cube.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct NodeMetadata {
@@ -41,11 +191,39 @@ pub struct GraphStatistics {
     pub diameter: usize,
 }
 
+/// Edge-level difference between two graphs on the Metatron nodes.
+///
+/// Produced by [`MetatronGraph::structural_diff`]; edges are `(min, max)`
+/// pairs listed in lexicographic order.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GraphDiff {
+    /// Edges only present in the other graph, with their weight.
+    pub added_edges: Vec<(usize, usize, f64)>,
+    /// Edges only present in this graph, with their weight.
+    pub removed_edges: Vec<(usize, usize, f64)>,
+    /// Edges present in both with different weights, as `(u, v, old, new)`.
+    pub changed_weights: Vec<(usize, usize, f64, f64)>,
+    /// Euclidean distance between the sorted Laplacian spectra.
+    pub spectral_distance: f64,
+}
+
+impl GraphDiff {
+    /// Whether the edge sets and weights agree.
+    pub fn is_empty(&self) -> bool {
+        self.added_edges.is_empty()
+            && self.removed_edges.is_empty()
+            && self.changed_weights.is_empty()
+    }
+}
+
 /// Explicit graph representation of the Metatron Cube.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct MetatronGraph {
     nodes: Vec<NodeMetadata>,
     edges: Vec<(usize, usize)>,
+    /// Per-edge coupling weights, parallel to `edges` (missing entries count as 1).
+    #[serde(default)]
+    weights: Vec<f64>,
 }
 
 impl Default for MetatronGraph {
@@ -59,7 +237,51 @@ impl MetatronGraph {
     pub fn new() -> Self {
         let nodes = build_nodes();
         let edges = build_edges();
-        Self { nodes, edges }
+        let weights = vec![1.0; edges.len()];
+        Self {
+            nodes,
+            edges,
+            weights,
+        }
+    }
+
+    /// Build a graph on the 13 Metatron nodes with a custom weighted edge set.
+    ///
+    /// Node metadata is the canonical one; only the topology and couplings
+    /// change. Edges are undirected and stored as `(min, max)`.
+    pub fn from_weighted_edges(edges: &[(usize, usize, f64)]) -> Result<Self, GraphError> {
+        let mut stored_edges = Vec::with_capacity(edges.len());
+        let mut weights = Vec::with_capacity(edges.len());
+        for &(u, v, weight) in edges {
+            if u >= METATRON_DIMENSION || v >= METATRON_DIMENSION {
+                return Err(GraphError::NodeOutOfRange {
+                    u,
+                    v,
+                    dimension: METATRON_DIMENSION,
+                });
+            }
+            if u == v {
+                return Err(GraphError::SelfLoop { node: u });
+            }
+            if !weight.is_finite() {
+                return Err(GraphError::InvalidWeight { u, v, weight });
+            }
+            let edge = (u.min(v), u.max(v));
+            if stored_edges.contains(&edge) {
+                return Err(GraphError::DuplicateEdge {
+                    u: edge.0,
+                    v: edge.1,
+                });
+            }
+            stored_edges.push(edge);
+            weights.push(weight);
+        }
+
+        Ok(Self {
+            nodes: build_nodes(),
+            edges: stored_edges,
+            weights,
+        })
     }
 
     /// Access immutable node metadata.
@@ -72,12 +294,43 @@ impl MetatronGraph {
         &self.edges
     }
 
-    /// Construct dense adjacency matrix.
+    /// Undirected edges paired with their coupling weights.
+    pub fn weighted_edges(&self) -> impl Iterator<Item = (usize, usize, f64)> + '_ {
+        let weights = self.weights.iter().copied().chain(std::iter::repeat(1.0));
+        self.edges.iter().zip(weights).map(|(&(u, v), w)| (u, v, w))
+    }
+
+    /// Weight of the undirected edge `(u, v)`, if present.
+    pub fn edge_weight(&self, u: usize, v: usize) -> Option<f64> {
+        let edge = (u.min(v), u.max(v));
+        self.weighted_edges()
+            .find(|&(a, b, _)| (a.min(b), a.max(b)) == edge)
+            .map(|(_, _, weight)| weight)
+    }
+
+    /// Subgraph formed by the edges of `solid` that are present in this graph.
+    ///
+    /// The result keeps all 13 nodes so its matrices stay 13×13; nodes outside
+    /// the solid are isolated. Edge weights are inherited.
+    pub fn platonic_subgraph(&self, solid: PlatonicSolid) -> MetatronGraph {
+        let (edges, weights) = solid
+            .edges()
+            .into_iter()
+            .filter_map(|(u, v)| self.edge_weight(u, v).map(|w| ((u, v), w)))
+            .unzip();
+        MetatronGraph {
+            nodes: self.nodes.clone(),
+            edges,
+            weights,
+        }
+    }
+
+    /// Construct dense (weighted) adjacency matrix.
     pub fn adjacency_matrix(&self) -> AdjacencyMatrix {
         let mut adjacency = AdjacencyMatrix::zeros();
-        for &(u, v) in &self.edges {
-            adjacency[(u, v)] = 1.0;
-            adjacency[(v, u)] = 1.0;
+        for (u, v, weight) in self.weighted_edges() {
+            adjacency[(u, v)] = weight;
+            adjacency[(v, u)] = weight;
         }
         adjacency
     }
@@ -92,13 +345,12 @@ impl MetatronGraph {
         degrees
     }
 
-    /// Graph Laplacian L = D - A.
+    /// Graph Laplacian L = D - A, with D the weighted degree Σⱼ Aᵢⱼ.
     pub fn laplacian_matrix(&self) -> LaplacianMatrix {
         let adjacency = self.adjacency_matrix();
-        let degrees = self.degree_sequence();
         let mut laplacian = LaplacianMatrix::zeros();
         for i in 0..METATRON_DIMENSION {
-            laplacian[(i, i)] = degrees[i] as f64;
+            laplacian[(i, i)] = adjacency.row(i).sum();
         }
         laplacian - adjacency
     }
@@ -115,6 +367,88 @@ impl MetatronGraph {
             .collect()
     }
 
+    /// Whether the edge weights differ from one another.
+    fn has_uniform_weights(&self) -> bool {
+        let mut weights = self.weighted_edges().map(|(_, _, w)| w);
+        match weights.next() {
+            Some(first) => weights.all(|w| w == first),
+            None => true,
+        }
+    }
+
+    /// Render as a Graphviz DOT graph, nodes coloured by [`NodeType`].
+    ///
+    /// Edge weights are emitted as labels only when they are not all equal.
+    pub fn to_dot(&self) -> String {
+        let labelled = !self.has_uniform_weights();
+        let mut dot = String::from("graph metatron {\n    node [style=filled];\n");
+        for node in &self.nodes {
+            writeln!(
+                dot,
+                "    {} [label=\"{}\", fillcolor={}];",
+                node.index,
+                node.label.replace('"', "\\\""),
+                node.node_type.color()
+            )
+            .unwrap();
+        }
+        for (u, v, weight) in self.weighted_edges() {
+            if labelled {
+                writeln!(dot, "    {} -- {} [label=\"{}\"];", u, v, weight).unwrap();
+            } else {
+                writeln!(dot, "    {} -- {};", u, v).unwrap();
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Render as GraphML for Gephi, yEd and similar tools.
+    ///
+    /// Nodes carry `label`, `node_type` and `color` attributes; edges carry a
+    /// `weight` attribute when the weights are not all equal.
+    pub fn to_graphml(&self) -> String {
+        let weighted = !self.has_uniform_weights();
+        let mut xml = String::from(concat!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+            "<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n",
+            "  <key id=\"label\" for=\"node\" attr.name=\"label\" attr.type=\"string\"/>\n",
+            "  <key id=\"node_type\" for=\"node\" attr.name=\"node_type\" attr.type=\"string\"/>\n",
+            "  <key id=\"color\" for=\"node\" attr.name=\"color\" attr.type=\"string\"/>\n",
+        ));
+        if weighted {
+            xml.push_str(
+                "  <key id=\"weight\" for=\"edge\" attr.name=\"weight\" attr.type=\"double\"/>\n",
+            );
+        }
+        xml.push_str("  <graph id=\"metatron\" edgedefault=\"undirected\">\n");
+        for node in &self.nodes {
+            writeln!(
+                xml,
+                "    <node id=\"n{}\"><data key=\"label\">{}</data><data key=\"node_type\">{:?}</data><data key=\"color\">{}</data></node>",
+                node.index,
+                escape_xml(&node.label),
+                node.node_type,
+                node.node_type.color()
+            )
+            .unwrap();
+        }
+        for (u, v, weight) in self.weighted_edges() {
+            if weighted {
+                writeln!(
+                    xml,
+                    "    <edge source=\"n{}\" target=\"n{}\"><data key=\"weight\">{}</data></edge>",
+                    u, v, weight
+                )
+                .unwrap();
+            } else {
+                writeln!(xml, "    <edge source=\"n{}\" target=\"n{}\"/>", u, v).unwrap();
+            }
+        }
+        xml.push_str("  </graph>\n</graphml>\n");
+        xml
+    }
+
     /// Convert to a `petgraph` undirected graph.
     pub fn to_petgraph(&self) -> UnGraph<NodeMetadata, ()> {
         let mut graph = UnGraph::with_capacity(self.nodes.len(), self.edges.len());
@@ -150,6 +484,42 @@ impl MetatronGraph {
         }
     }
 
+    /// Changes that turn this graph into `other`.
+    ///
+    /// Edges are compared pairwise, so the listed changes are exact. The
+    /// spectral distance summarises the change in a basis-independent way:
+    /// it is zero for relabelled (isomorphic) graphs, and a single edge of
+    /// weight w moves it by at most 2w. Isospectral graphs also give zero, so
+    /// it complements rather than replaces the edge lists.
+    pub fn structural_diff(&self, other: &MetatronGraph) -> GraphDiff {
+        let mut diff = GraphDiff {
+            added_edges: Vec::new(),
+            removed_edges: Vec::new(),
+            changed_weights: Vec::new(),
+            spectral_distance: 0.0,
+        };
+        for u in 0..METATRON_DIMENSION {
+            for v in u + 1..METATRON_DIMENSION {
+                match (self.edge_weight(u, v), other.edge_weight(u, v)) {
+                    (None, Some(new)) => diff.added_edges.push((u, v, new)),
+                    (Some(old), None) => diff.removed_edges.push((u, v, old)),
+                    (Some(old), Some(new)) if old != new => {
+                        diff.changed_weights.push((u, v, old, new))
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let spectrum = |graph: &MetatronGraph| {
+            let mut eigenvalues = SymmetricEigen::new(graph.laplacian_matrix()).eigenvalues;
+            eigenvalues.as_mut_slice().sort_by(f64::total_cmp);
+            eigenvalues
+        };
+        diff.spectral_distance = (spectrum(self) - spectrum(other)).norm();
+        diff
+    }
+
     fn is_connected(&self) -> bool {
         if self.nodes.is_empty() {
             return true;
@@ -171,15 +541,31 @@ impl MetatronGraph {
         visited.into_iter().all(|v| v)
     }
 
-    fn diameter(&self) -> usize {
-        let mut max_distance = 0usize;
-        for start in 0..self.nodes.len() {
-            let distances = self.single_source_shortest_path(start);
-            if let Some(local_max) = distances.into_iter().max() {
-                max_distance = max_distance.max(local_max);
+    /// All-pairs hop distances computed by breadth-first search.
+    ///
+    /// Edge weights are ignored. Unreachable pairs are `usize::MAX`.
+    pub fn distance_matrix(&self) -> DistanceMatrix {
+        let mut distances = DistanceMatrix::zeros();
+        for start in 0..METATRON_DIMENSION {
+            for (target, distance) in self
+                .single_source_shortest_path(start)
+                .into_iter()
+                .enumerate()
+            {
+                distances[(start, target)] = distance;
             }
         }
-        max_distance
+        distances
+    }
+
+    /// Longest shortest-path hop distance between reachable node pairs.
+    pub fn diameter(&self) -> usize {
+        self.distance_matrix()
+            .iter()
+            .copied()
+            .filter(|&d| d != usize::MAX)
+            .max()
+            .unwrap_or(0)
     }
 
     fn single_source_shortest_path(&self, start: usize) -> Vec<usize> {
@@ -351,6 +737,47 @@ impl MetatronGraph {
         true
     }
 
+    /// Node permutations of the dihedral group D6 that preserve this graph.
+    ///
+    /// The centre is fixed and the hexagon (1–6) and cube layer (7–12) rings
+    /// are rotated or reflected together, mirroring the planar Metatron's Cube
+    /// drawing. The first six entries are the rotations by k·60° (k = 0 is the
+    /// identity), followed by the six reflections. Elements that are not
+    /// automorphisms of a custom edge set are dropped.
+    pub fn symmetry_permutations(&self) -> Vec<Vec<usize>> {
+        (0..12)
+            .map(|element| {
+                let ring_map = |i: usize| {
+                    if element < 6 {
+                        (i + element) % 6
+                    } else {
+                        (element + 6 - i) % 6
+                    }
+                };
+                let mut perm = vec![0; METATRON_DIMENSION];
+                for i in 0..6 {
+                    perm[1 + i] = 1 + ring_map(i);
+                    perm[7 + i] = 7 + ring_map(i);
+                }
+                perm
+            })
+            .filter(|perm| self.is_automorphism(perm))
+            .collect()
+    }
+
+    /// Unitary permutation operators for [`symmetry_permutations`](Self::symmetry_permutations).
+    ///
+    /// Each operator maps the basis state |i⟩ to |perm\[i\]⟩ and commutes with
+    /// the graph Laplacian.
+    pub fn symmetry_operators(&self) -> Vec<QuantumOperator> {
+        self.symmetry_permutations()
+            .iter()
+            .map(|perm| {
+                QuantumOperator::from_permutation(perm).expect("D6 permutations are 13-dimensional")
+            })
+            .collect()
+    }
+
     /// Compute the order of the automorphism group
     pub fn automorphism_group_order(&self) -> usize {
         self.enumerate_automorphisms().len()
@@ -373,6 +800,13 @@ impl MetatronGraph {
     }
 }
 
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
 fn build_nodes() -> Vec<NodeMetadata> {
     let mut nodes = Vec::with_capacity(METATRON_DIMENSION);
 
@@ -534,4 +968,158 @@ mod tests {
         assert_eq!(stats.num_edges, 78);
         assert!(stats.is_connected);
     }
+
+    #[test]
+    fn platonic_subgraphs_have_expected_edge_counts() {
+        let graph = MetatronGraph::new();
+        let expected = [
+            (PlatonicSolid::Tetrahedron, 4, 6, 3),
+            (PlatonicSolid::Cube, 7, 12, 3),
+            (PlatonicSolid::Octahedron, 6, 12, 4),
+            (PlatonicSolid::Icosahedron, 12, 30, 5),
+        ];
+        for (solid, vertices, edges, degree) in expected {
+            let subgraph = graph.platonic_subgraph(solid);
+            assert_eq!(solid.nodes().len(), vertices, "{:?}", solid);
+            assert_eq!(subgraph.edges().len(), edges, "{:?}", solid);
+
+            let degrees = subgraph.degree_sequence();
+            for node in solid.nodes() {
+                // The projected cube's centre carries both coincident corners
+                let expected_degree = if solid == PlatonicSolid::Cube && node == 0 {
+                    2 * degree
+                } else {
+                    degree
+                };
+                assert_eq!(degrees[node], expected_degree, "{:?} node {}", solid, node);
+            }
+        }
+    }
+
+    #[test]
+    fn centre_is_one_hop_from_every_node() {
+        let graph = MetatronGraph::new();
+        let distances = graph.distance_matrix();
+        for node in 1..METATRON_DIMENSION {
+            assert_eq!(distances[(0, node)], 1);
+            assert_eq!(distances[(node, 0)], 1);
+        }
+        assert_eq!(distances[(0, 0)], 0);
+        assert_eq!(graph.diameter(), 1);
+
+        let tetrahedron = graph.platonic_subgraph(PlatonicSolid::Tetrahedron);
+        assert_eq!(tetrahedron.distance_matrix()[(0, 8)], usize::MAX);
+        assert_eq!(tetrahedron.diameter(), 1);
+    }
+
+    #[test]
+    fn symmetry_operators_form_d6_and_commute_with_laplacian() {
+        use num_complex::Complex64;
+
+        let graph = MetatronGraph::new();
+        let operators = graph.symmetry_operators();
+        assert_eq!(operators.len(), 12);
+
+        let laplacian = graph.laplacian_matrix().map(|x| Complex64::new(x, 0.0));
+        for op in &operators {
+            assert!(op.is_unitary(1e-12));
+            assert_eq!(op.matrix()[(0, 0)], Complex64::new(1.0, 0.0));
+            let commutator = op.matrix() * laplacian - laplacian * op.matrix();
+            assert!(commutator.norm() < 1e-12);
+            // Closure under composition
+            for other in &operators {
+                let product = op.compose(other);
+                assert!(operators.iter().any(|g| g == &product));
+            }
+        }
+
+        // A lone cube-layer edge breaks most of the group
+        let edges: Vec<(usize, usize, f64)> = (1..=6)
+            .map(|h| (h, if h == 6 { 1 } else { h + 1 }, 1.0))
+            .chain([(7, 8, 1.0)])
+            .collect();
+        let broken = MetatronGraph::from_weighted_edges(&edges).unwrap();
+        assert!(broken.symmetry_operators().len() < 12);
+    }
+
+    #[test]
+    fn exports_colour_nodes_and_label_weights() {
+        let graph = MetatronGraph::new();
+        let dot = graph.to_dot();
+        assert!(dot.contains("0 [label=\"v1 (C)\", fillcolor=gold];"));
+        assert_eq!(dot.matches(" -- ").count(), 78);
+        assert!(!dot.contains("-- 1 [label"));
+
+        let weighted = MetatronGraph::from_weighted_edges(&[(0, 1, 1.0), (1, 7, 2.5)]).unwrap();
+        assert!(weighted.to_dot().contains("1 -- 7 [label=\"2.5\"];"));
+
+        let graphml = weighted.to_graphml();
+        assert_eq!(graphml.matches("<node ").count(), METATRON_DIMENSION);
+        assert!(graphml.contains("<data key=\"node_type\">Cube</data>"));
+        assert!(graphml.contains("<data key=\"weight\">2.5</data>"));
+        assert!(!graph.to_graphml().contains("key=\"weight\""));
+    }
+
+    #[test]
+    fn structural_diff_recovers_a_single_edge_change() {
+        let graph = MetatronGraph::new();
+        assert!(graph.structural_diff(&graph).is_empty());
+        assert!(graph.structural_diff(&graph).spectral_distance < 1e-9);
+
+        let edges: Vec<_> = graph
+            .weighted_edges()
+            .filter(|&(u, v, _)| (u, v) != (3, 9))
+            .collect();
+        let pruned = MetatronGraph::from_weighted_edges(&edges).unwrap();
+        let diff = graph.structural_diff(&pruned);
+        assert_eq!(diff.removed_edges, vec![(3, 9, 1.0)]);
+        assert!(diff.added_edges.is_empty() && diff.changed_weights.is_empty());
+        // K13 minus an edge: eigenvalues 13 and 13 become 11 and 13
+        assert!((diff.spectral_distance - 2.0).abs() < 1e-9);
+
+        let back = pruned.structural_diff(&graph);
+        assert_eq!(back.added_edges, vec![(3, 9, 1.0)]);
+
+        let reweighted = MetatronGraph::from_weighted_edges(&[(0, 1, 1.0), (2, 5, 1.0)]).unwrap();
+        let heavier = MetatronGraph::from_weighted_edges(&[(5, 2, 3.0), (0, 1, 1.0)]).unwrap();
+        let diff = reweighted.structural_diff(&heavier);
+        assert_eq!(diff.changed_weights, vec![(2, 5, 1.0, 3.0)]);
+        assert!(diff.added_edges.is_empty() && diff.removed_edges.is_empty());
+    }
+
+    #[test]
+    fn weighted_edges_change_the_spectrum() {
+        use crate::hamiltonian::MetatronHamiltonian;
+        use crate::params::QSOParameters;
+
+        let params = QSOParameters::default();
+        let default_spectrum =
+            MetatronHamiltonian::new(&MetatronGraph::new(), &params).spectrum_info();
+
+        // Weighted star around the centre
+        let edges: Vec<(usize, usize, f64)> = (1..METATRON_DIMENSION)
+            .map(|node| (0, node, 0.5 * node as f64))
+            .collect();
+        let graph = MetatronGraph::from_weighted_edges(&edges).unwrap();
+        assert_eq!(graph.edges().len(), 12);
+        let laplacian = graph.laplacian_matrix();
+        assert_eq!(laplacian[(0, 3)], -1.5);
+        assert_eq!(laplacian[(0, 0)], 39.0);
+        assert!(laplacian.row_sum().iter().all(|s| s.abs() < 1e-12));
+
+        let spectrum = MetatronHamiltonian::new(&graph, &params).spectrum_info();
+        let shift = default_spectrum
+            .eigenvalues
+            .iter()
+            .zip(spectrum.eigenvalues.iter())
+            .map(|(a, b)| (a - b).abs())
+            .fold(0.0, f64::max);
+        assert!(shift > 1.0);
+
+        assert_eq!(
+            MetatronGraph::from_weighted_edges(&[(0, 1, 1.0), (1, 0, 2.0)]).unwrap_err(),
+            GraphError::DuplicateEdge { u: 0, v: 1 }
+        );
+        assert!(MetatronGraph::from_weighted_edges(&[(0, 13, 1.0)]).is_err());
+    }
 }
@@ -1,17 +1,21 @@
-use nalgebra::{SMatrix, SymmetricEigen};
+use nalgebra::{DMatrix, DVector, SMatrix, SymmetricEigen};
 use num_complex::Complex64;
-use serde::Serialize;
+use serde::de::Error as SerdeError;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::sync::OnceLock;
 
 use crate::graph::metatron::MetatronGraph;
 use crate::params::QSOParameters;
+use crate::quantum::density::DensityMatrix;
 use crate::quantum::operator::{OperatorMatrix, QuantumOperator};
+use crate::quantum::pauli::{self, PauliString};
 use crate::quantum::state::{METATRON_DIMENSION, QuantumState, StateVector};
 
 /// Real-valued Hamiltonian matrix type alias.
 pub type HamiltonianMatrix = SMatrix<f64, 13, 13>;
 
 /// Spectral summary of the Metatron Hamiltonian.
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SpectrumInfo {
     pub eigenvalues: Vec<f64>,
     pub ground_state_energy: f64,
@@ -20,26 +24,63 @@ pub struct SpectrumInfo {
     pub energy_spread: f64,
 }
 
-/// Tight-binding Hamiltonian on the Metatron Cube graph.
-pub struct MetatronHamiltonian {
-    matrix: HamiltonianMatrix,
-    eigenvalues: [f64; METATRON_DIMENSION],
-    eigenvectors: Vec<StateVector>,
-}
+/// Eigenvalue differences at or below this threshold count as degenerate.
+pub const DEGENERACY_TOLERANCE: f64 = 1e-9;
 
-impl MetatronHamiltonian {
-    /// Construct the Hamiltonian H = -J·L + diag(ε).
-    pub fn new(graph: &MetatronGraph, params: &QSOParameters) -> Self {
-        let laplacian = graph.laplacian_matrix();
-        let mut matrix = HamiltonianMatrix::zeros();
-        for i in 0..METATRON_DIMENSION {
-            for j in 0..METATRON_DIMENSION {
-                matrix[(i, j)] = -params.j * laplacian[(i, j)];
+impl SpectrumInfo {
+    /// Smallest gap between distinct consecutive eigenvalues.
+    ///
+    /// Differences within [`DEGENERACY_TOLERANCE`] are treated as degeneracies
+    /// and skipped. Returns 0.0 when the spectrum has a single distinct level.
+    pub fn spectral_gap(&self) -> f64 {
+        let mut sorted = self.eigenvalues.clone();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        sorted
+            .windows(2)
+            .map(|pair| pair[1] - pair[0])
+            .filter(|&gap| gap > DEGENERACY_TOLERANCE)
+            .fold(None, |min: Option<f64>, gap| {
+                Some(min.map_or(gap, |m| m.min(gap)))
+            })
+            .unwrap_or(0.0)
+    }
+
+    /// Group eigenvalues into (energy, multiplicity) pairs.
+    ///
+    /// Eigenvalues within `tol` of the first member of a level are merged into
+    /// it; the reported energy is the mean of the merged values. Levels are
+    /// returned in ascending order.
+    pub fn degeneracies(&self, tol: f64) -> Vec<(f64, usize)> {
+        let mut sorted = self.eigenvalues.clone();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        let mut levels: Vec<(f64, f64, usize)> = Vec::new();
+        for value in sorted {
+            match levels.last_mut() {
+                Some((first, sum, count)) if (value - *first).abs() <= tol => {
+                    *sum += value;
+                    *count += 1;
+                }
+                _ => levels.push((value, value, 1)),
             }
-            matrix[(i, i)] += params.epsilon[i];
         }
 
-        let eigen = SymmetricEigen::new(matrix);
+        levels
+            .into_iter()
+            .map(|(_, sum, count)| (sum / count as f64, count))
+            .collect()
+    }
+}
+
+/// Eigendecomposition sorted by ascending eigenvalue.
+struct Eigensystem {
+    eigenvalues: [f64; METATRON_DIMENSION],
+    eigenvectors: Vec<StateVector>,
+}
+
+impl Eigensystem {
+    fn new(matrix: &HamiltonianMatrix) -> Self {
+        let eigen = SymmetricEigen::new(*matrix);
         let eigenvalues_vec = eigen.eigenvalues.data.as_slice().to_vec();
         let eigenvectors_matrix = eigen.eigenvectors;
 
@@ -67,51 +108,55 @@ impl MetatronHamiltonian {
             .collect();
 
         Self {
-            matrix,
             eigenvalues,
             eigenvectors,
         }
     }
+}
 
-    /// Construct Hamiltonian directly from a matrix
-    pub fn from_matrix(matrix: HamiltonianMatrix) -> Self {
-        let eigen = SymmetricEigen::new(matrix);
-        let eigenvalues_vec = eigen.eigenvalues.data.as_slice().to_vec();
-        let eigenvectors_matrix = eigen.eigenvectors;
-
-        // Create indices sorted by eigenvalue (ascending order, so [0] is minimum/ground state)
-        let mut indices: Vec<usize> = (0..METATRON_DIMENSION).collect();
-        indices.sort_by(|&a, &b| eigenvalues_vec[a].partial_cmp(&eigenvalues_vec[b]).unwrap());
+/// Tight-binding Hamiltonian on the Metatron Cube graph.
+///
+/// The Hamiltonian is immutable, so its eigendecomposition and
+/// [`SpectrumInfo`] are computed on first use and cached for the lifetime of
+/// the value; repeated evolution calls reuse them.
+pub struct MetatronHamiltonian {
+    matrix: HamiltonianMatrix,
+    eigensystem: OnceLock<Eigensystem>,
+    spectrum: OnceLock<SpectrumInfo>,
+}
 
-        // Reorder eigenvalues and eigenvectors so that index 0 is the ground state
-        let eigenvalues: [f64; METATRON_DIMENSION] = indices
-            .iter()
-            .map(|&i| eigenvalues_vec[i])
-            .collect::<Vec<_>>()
-            .try_into()
-            .expect("expected 13 eigenvalues");
+impl MetatronHamiltonian {
+    /// Construct the Hamiltonian H = -J·L + diag(ε).
+    pub fn new(graph: &MetatronGraph, params: &QSOParameters) -> Self {
+        let laplacian = graph.laplacian_matrix();
+        let mut matrix = HamiltonianMatrix::zeros();
+        for i in 0..METATRON_DIMENSION {
+            for j in 0..METATRON_DIMENSION {
+                matrix[(i, j)] = -params.j * laplacian[(i, j)];
+            }
+            matrix[(i, i)] += params.epsilon[i];
+        }
 
-        let eigenvectors: Vec<StateVector> = indices
-            .iter()
-            .map(|&col| {
-                let mut vector = StateVector::zeros();
-                for row in 0..METATRON_DIMENSION {
-                    vector[row] = Complex64::new(eigenvectors_matrix[(row, col)], 0.0);
-                }
-                vector
-            })
-            .collect();
+        Self::from_matrix(matrix)
+    }
 
+    /// Construct Hamiltonian directly from a matrix
+    pub fn from_matrix(matrix: HamiltonianMatrix) -> Self {
         Self {
             matrix,
-            eigenvalues,
-            eigenvectors,
+            eigensystem: OnceLock::new(),
+            spectrum: OnceLock::new(),
         }
     }
 
+    fn eigensystem(&self) -> &Eigensystem {
+        self.eigensystem
+            .get_or_init(|| Eigensystem::new(&self.matrix))
+    }
+
     /// Get ground state energy
     pub fn ground_state_energy(&self) -> f64 {
-        self.eigenvalues[0]
+        self.eigenvalues()[0]
     }
 
     /// Access raw Hamiltonian matrix.
@@ -121,47 +166,68 @@ impl MetatronHamiltonian {
 
     /// Access eigenvalues.
     pub fn eigenvalues(&self) -> &[f64; METATRON_DIMENSION] {
-        &self.eigenvalues
+        &self.eigensystem().eigenvalues
     }
 
     /// Access eigenvectors as column-major state vectors.
     pub fn eigenvectors(&self) -> &[StateVector] {
-        &self.eigenvectors
+        &self.eigensystem().eigenvectors
     }
 
     /// Project an arbitrary state onto the Hamiltonian eigenbasis.
     pub fn project_onto_eigenbasis(&self, state: &QuantumState) -> Vec<Complex64> {
         let amplitudes = state.amplitudes();
-        self.eigenvectors
+        self.eigenvectors()
             .iter()
             .map(|vec| vec.dotc(amplitudes))
             .collect()
     }
 
+    /// Energy variance ⟨H²⟩ − ⟨H⟩² of `state` (normalised internally).
+    ///
+    /// It vanishes exactly for eigenstates, so it certifies a variational
+    /// state without knowing the true spectrum.
+    pub fn energy_variance(&self, state: &QuantumState) -> f64 {
+        let amplitudes = state.amplitudes();
+        let norm_sqr = amplitudes.norm_squared();
+        if norm_sqr == 0.0 {
+            return 0.0;
+        }
+        let h_psi = self.as_complex_operator() * amplitudes;
+        let mean = amplitudes.dotc(&h_psi).re / norm_sqr;
+        (h_psi.norm_squared() / norm_sqr - mean * mean).max(0.0)
+    }
+
     /// Return the Hamiltonian as a complex operator matrix.
     pub fn as_complex_operator(&self) -> OperatorMatrix {
         OperatorMatrix::from_fn(|i, j| Complex64::new(self.matrix[(i, j)], 0.0))
     }
 
+    /// Weighted Pauli-string terms of H on the 4-qubit, zero-padded
+    /// embedding (see [`crate::quantum::pauli`] for the layout).
+    pub fn pauli_decomposition(&self) -> Vec<(f64, PauliString)> {
+        pauli::pauli_decomposition(&self.as_complex_operator())
+    }
+
     /// Retrieve nth eigenstate as (energy, QuantumState).
     pub fn eigenstate(&self, index: usize) -> Option<(f64, QuantumState)> {
-        self.eigenvalues.get(index).map(|&energy| {
+        self.eigenvalues().get(index).map(|&energy| {
             (
                 energy,
-                QuantumState::from_vector(self.eigenvectors[index], false),
+                QuantumState::from_vector(self.eigenvectors()[index], false),
             )
         })
     }
 
     /// Ground-state wavefunction.
     pub fn ground_state(&self) -> QuantumState {
-        QuantumState::from_vector(self.eigenvectors[0], false)
+        QuantumState::from_vector(self.eigenvectors()[0], false)
     }
 
     /// Time-evolution operator U(t) = exp(-iHt).
     pub fn time_evolution_operator(&self, time: f64) -> QuantumOperator {
         let mut matrix = OperatorMatrix::zeros();
-        for (energy, eigenvector) in self.eigenvalues.iter().zip(self.eigenvectors.iter()) {
+        for (energy, eigenvector) in self.eigenvalues().iter().zip(self.eigenvectors()) {
             let phase = Complex64::from_polar(1.0, -energy * time);
             let projector = *eigenvector * eigenvector.adjoint();
             matrix += projector * phase;
@@ -175,26 +241,232 @@ impl MetatronHamiltonian {
         state.apply(&operator)
     }
 
-    /// Derive spectral diagnostics for reporting.
+    /// Partition function Z(β) = Σᵢ exp(−βEᵢ).
+    pub fn partition_function(&self, beta: f64) -> f64 {
+        self.eigenvalues()
+            .iter()
+            .map(|&energy| (-beta * energy).exp())
+            .sum()
+    }
+
+    /// Thermal density matrix ρ(β) = exp(−βH) / Z.
+    ///
+    /// Boltzmann weights are computed relative to the ground-state energy so
+    /// that large β does not overflow.
+    pub fn gibbs_state(&self, beta: f64) -> DensityMatrix {
+        let ground = self.eigenvalues()[0];
+        let weights: Vec<f64> = self
+            .eigenvalues()
+            .iter()
+            .map(|&energy| (-beta * (energy - ground)).exp())
+            .collect();
+        let norm: f64 = weights.iter().sum();
+
+        let mut rho = OperatorMatrix::zeros();
+        for (weight, eigenvector) in weights.iter().zip(self.eigenvectors()) {
+            let projector = *eigenvector * eigenvector.adjoint();
+            rho += projector * Complex64::new(weight / norm, 0.0);
+        }
+        DensityMatrix::from_matrix_unchecked(rho)
+    }
+
+    /// Cached spectral diagnostics.
+    pub fn spectrum(&self) -> &SpectrumInfo {
+        self.spectrum.get_or_init(|| {
+            let eigenvalues = self.eigenvalues().to_vec();
+            let ground_state_energy = eigenvalues[0];
+            let max_energy = *eigenvalues.last().unwrap();
+            let energy_gap = if eigenvalues.len() > 1 {
+                eigenvalues[1] - eigenvalues[0]
+            } else {
+                0.0
+            };
+            let energy_spread = max_energy - ground_state_energy;
+
+            SpectrumInfo {
+                eigenvalues,
+                ground_state_energy,
+                energy_gap,
+                max_energy,
+                energy_spread,
+            }
+        })
+    }
+
+    /// Derive spectral diagnostics for reporting (owned copy of [`Self::spectrum`]).
     pub fn spectrum_info(&self) -> SpectrumInfo {
-        let eigenvalues = self.eigenvalues.to_vec();
-        let ground_state_energy = eigenvalues[0];
-        let max_energy = *eigenvalues.last().unwrap();
-        let energy_gap = if eigenvalues.len() > 1 {
-            eigenvalues[1] - eigenvalues[0]
-        } else {
-            0.0
-        };
-        let energy_spread = max_energy - ground_state_energy;
+        self.spectrum().clone()
+    }
+}
 
-        SpectrumInfo {
-            eigenvalues,
-            ground_state_energy,
-            energy_gap,
-            max_energy,
-            energy_spread,
+/// On-disk form of a [`MetatronHamiltonian`]: row-major matrix plus the
+/// spectrum for readers that do not diagonalise it themselves.
+#[derive(Serialize, Deserialize)]
+struct HamiltonianRecord {
+    dimension: usize,
+    matrix: Vec<Vec<f64>>,
+    #[serde(default)]
+    spectrum: Option<SpectrumInfo>,
+}
+
+impl Serialize for MetatronHamiltonian {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        HamiltonianRecord {
+            dimension: METATRON_DIMENSION,
+            matrix: self
+                .matrix
+                .row_iter()
+                .map(|row| row.iter().copied().collect())
+                .collect(),
+            spectrum: Some(self.spectrum_info()),
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Only the matrix is read back; the spectrum is recomputed on demand.
+impl<'de> Deserialize<'de> for MetatronHamiltonian {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let record = HamiltonianRecord::deserialize(deserializer)?;
+        if record.dimension != METATRON_DIMENSION
+            || record.matrix.len() != METATRON_DIMENSION
+            || record
+                .matrix
+                .iter()
+                .any(|row| row.len() != METATRON_DIMENSION)
+        {
+            return Err(SerdeError::custom(format!(
+                "expected a {METATRON_DIMENSION}×{METATRON_DIMENSION} Hamiltonian matrix"
+            )));
+        }
+        let matrix = HamiltonianMatrix::from_fn(|i, j| record.matrix[i][j]);
+        Ok(Self::from_matrix(matrix))
+    }
+}
+
+/// Hamiltonian H(t) given by an arbitrary schedule `t -> H`.
+///
+/// The Schrödinger equation i d|ψ⟩/dt = H(t)|ψ⟩ is integrated with a fixed-step
+/// RK4 scheme. RK4 is not norm-preserving, so the state is renormalized every
+/// `renormalize_every` steps and the drift removed is reported.
+pub struct TimeDependentHamiltonian {
+    schedule: Box<dyn Fn(f64) -> DMatrix<Complex64> + Send + Sync>,
+    renormalize_every: usize,
+}
+
+/// Result of integrating a time-dependent Hamiltonian.
+#[derive(Clone, Debug)]
+pub struct TimeDependentEvolution {
+    /// Final (normalized) state.
+    pub state: QuantumState,
+    /// Number of RK4 steps taken.
+    pub steps: usize,
+    /// Sum of |‖ψ‖ − 1| removed by the periodic renormalizations.
+    pub accumulated_norm_error: f64,
+}
+
+impl TimeDependentHamiltonian {
+    /// Create from a schedule returning a 13×13 Hermitian matrix at time `t`.
+    pub fn new<F>(schedule: F) -> Self
+    where
+        F: Fn(f64) -> DMatrix<Complex64> + Send + Sync + 'static,
+    {
+        Self {
+            schedule: Box::new(schedule),
+            renormalize_every: 10,
+        }
+    }
+
+    /// H(t) = -J(t)·L + diag(ε) with a time-dependent edge coupling.
+    pub fn with_coupling_schedule<F>(
+        graph: &MetatronGraph,
+        params: &QSOParameters,
+        coupling: F,
+    ) -> Self
+    where
+        F: Fn(f64) -> f64 + Send + Sync + 'static,
+    {
+        let laplacian = graph.laplacian_matrix();
+        let epsilon = params.epsilon;
+        Self::new(move |t| {
+            let j = coupling(t);
+            DMatrix::from_fn(METATRON_DIMENSION, METATRON_DIMENSION, |row, col| {
+                let onsite = if row == col { epsilon[row] } else { 0.0 };
+                Complex64::new(-j * laplacian[(row, col)] + onsite, 0.0)
+            })
+        })
+    }
+
+    /// Renormalize the state every `steps` RK4 steps (0 disables it).
+    pub fn with_renormalize_every(mut self, steps: usize) -> Self {
+        self.renormalize_every = steps;
+        self
+    }
+
+    /// Hamiltonian matrix at time `t`.
+    pub fn matrix_at(&self, time: f64) -> DMatrix<Complex64> {
+        let matrix = (self.schedule)(time);
+        assert_eq!(
+            matrix.shape(),
+            (METATRON_DIMENSION, METATRON_DIMENSION),
+            "Hamiltonian schedule must return a 13×13 matrix"
+        );
+        matrix
+    }
+
+    /// Integrate from t = 0 to `t_final` with step size `dt`.
+    ///
+    /// The last step is shortened so that the integration ends exactly at `t_final`.
+    pub fn evolve_state(
+        &self,
+        state: &QuantumState,
+        t_final: f64,
+        dt: f64,
+    ) -> TimeDependentEvolution {
+        assert!(dt > 0.0, "time step must be positive");
+        let minus_i = Complex64::new(0.0, -1.0);
+        let derivative = |t: f64, psi: &DVector<Complex64>| (self.matrix_at(t) * psi) * minus_i;
+
+        let mut psi = DVector::from_column_slice(state.amplitudes().as_slice());
+        let steps = (t_final / dt - 1e-9).ceil().max(0.0) as usize;
+        let mut accumulated_norm_error = 0.0;
+
+        for step in 1..=steps {
+            let time = (step - 1) as f64 * dt;
+            let h = dt.min(t_final - time);
+            let k1 = derivative(time, &psi);
+            let k2 = derivative(time + 0.5 * h, &(&psi + &k1 * Complex64::new(0.5 * h, 0.0)));
+            let k3 = derivative(time + 0.5 * h, &(&psi + &k2 * Complex64::new(0.5 * h, 0.0)));
+            let k4 = derivative(time + h, &(&psi + &k3 * Complex64::new(h, 0.0)));
+            psi += (k1 + k2 * Complex64::new(2.0, 0.0) + k3 * Complex64::new(2.0, 0.0) + k4)
+                * Complex64::new(h / 6.0, 0.0);
+
+            if self.renormalize_every > 0 && step % self.renormalize_every == 0 {
+                accumulated_norm_error += renormalize(&mut psi);
+            }
         }
+        accumulated_norm_error += renormalize(&mut psi);
+
+        TimeDependentEvolution {
+            state: QuantumState::from_vector(StateVector::from_column_slice(psi.as_slice()), false),
+            steps,
+            accumulated_norm_error,
+        }
+    }
+}
+
+fn renormalize(psi: &mut DVector<Complex64>) -> f64 {
+    let norm = psi.norm();
+    if norm > 0.0 {
+        *psi /= Complex64::new(norm, 0.0);
     }
+    (norm - 1.0).abs()
 }
 
 #[cfg(test)]
@@ -202,6 +474,55 @@ mod tests {
     use super::*;
     use crate::params::QSOParameters;
 
+    #[test]
+    fn json_round_trip_is_exact() {
+        let params = QSOParameters {
+            epsilon: [
+                0.1,
+                -0.3,
+                0.7,
+                1.0 / 3.0,
+                0.0,
+                0.2,
+                0.9,
+                -0.1,
+                0.4,
+                0.5,
+                0.6,
+                0.8,
+                0.05,
+            ],
+            ..QSOParameters::default()
+        };
+        let hamiltonian = MetatronHamiltonian::new(&MetatronGraph::new(), &params);
+        let json = serde_json::to_string(&hamiltonian).unwrap();
+        let restored: MetatronHamiltonian = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.matrix(), hamiltonian.matrix());
+        assert_eq!(restored.eigenvalues(), hamiltonian.eigenvalues());
+
+        let state = hamiltonian.evolve_state(&QuantumState::basis_state(0).unwrap(), 0.37);
+        let json = serde_json::to_string(&state).unwrap();
+        let restored: QuantumState = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, state);
+
+        assert!(
+            serde_json::from_str::<MetatronHamiltonian>(
+                r#"{"dimension": 2, "matrix": [[0.0, 1.0], [1.0, 0.0]]}"#
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn spectrum_is_cached() {
+        let hamiltonian =
+            MetatronHamiltonian::new(&MetatronGraph::new(), &QSOParameters::default());
+        let first = hamiltonian.spectrum();
+        assert!(std::ptr::eq(first, hamiltonian.spectrum()));
+        assert_eq!(first.eigenvalues, hamiltonian.eigenvalues().to_vec());
+        assert!((first.ground_state_energy + 13.0).abs() < 1e-9);
+    }
+
     #[test]
     fn ground_state_is_normalized() {
         let graph = MetatronGraph::new();
@@ -210,4 +531,61 @@ mod tests {
         let ground = hamiltonian.ground_state();
         assert!(ground.is_normalized(1e-10));
     }
+
+    #[test]
+    fn degeneracies_account_for_every_eigenvalue() {
+        let graph = MetatronGraph::new();
+        let hamiltonian = MetatronHamiltonian::new(&graph, &QSOParameters::default());
+        let info = hamiltonian.spectrum_info();
+
+        let levels = info.degeneracies(1e-8);
+        let total: usize = levels.iter().map(|&(_, count)| count).sum();
+        assert_eq!(total, METATRON_DIMENSION);
+        assert!(levels.len() < METATRON_DIMENSION);
+
+        let gap = info.spectral_gap();
+        assert!(gap > DEGENERACY_TOLERANCE);
+        let min_level_gap = levels
+            .windows(2)
+            .map(|pair| pair[1].0 - pair[0].0)
+            .fold(f64::INFINITY, f64::min);
+        assert!((gap - min_level_gap).abs() < 1e-6);
+    }
+
+    #[test]
+    fn gibbs_state_interpolates_between_mixed_and_ground() {
+        let graph = MetatronGraph::new();
+        let hamiltonian = MetatronHamiltonian::new(&graph, &QSOParameters::default());
+
+        let hot = hamiltonian.gibbs_state(0.0);
+        let maximally_mixed = DensityMatrix::maximally_mixed();
+        assert!((hot.matrix() - maximally_mixed.matrix()).norm() < 1e-10);
+        assert!((hamiltonian.partition_function(0.0) - METATRON_DIMENSION as f64).abs() < 1e-12);
+
+        let cold = hamiltonian.gibbs_state(200.0);
+        assert!((cold.matrix().trace().re - 1.0).abs() < 1e-10);
+        let ground_level = hamiltonian.spectrum_info().degeneracies(1e-8)[0];
+        let ground_population: f64 = hamiltonian.eigenvectors()[..ground_level.1]
+            .iter()
+            .map(|v| cold.fidelity_with_pure(&QuantumState::from_vector(*v, false)))
+            .sum();
+        assert!((ground_population - 1.0).abs() < 1e-8);
+    }
+
+    #[test]
+    fn constant_schedule_matches_spectral_evolution() {
+        let graph = MetatronGraph::new();
+        let params = QSOParameters::default();
+        let hamiltonian = MetatronHamiltonian::new(&graph, &params);
+        let schedule = TimeDependentHamiltonian::with_coupling_schedule(&graph, &params, |_| 1.0);
+
+        let initial = QuantumState::basis_state(0).unwrap();
+        let result = schedule.evolve_state(&initial, 0.5, 1e-3);
+        let exact = hamiltonian.evolve_state(&initial, 0.5);
+
+        assert_eq!(result.steps, 500);
+        assert!(result.state.is_normalized(1e-12));
+        assert!(result.accumulated_norm_error < 1e-6);
+        assert!((result.state.amplitudes() - exact.amplitudes()).norm() < 1e-6);
+    }
 }
@@ -10,7 +10,7 @@
 //!
 //! - **Quantum Graph Dynamics**: State evolution on 13-node Metatron geometry
 //! - **Variational Quantum Algorithms**: VQE, QAOA, VQC with multiple ansätze
-//! - **Quantum Walks**: Continuous-time quantum walks with Krylov methods
+//! - **Quantum Walks**: Continuous-time and coined discrete-time quantum walks with Krylov methods
 //! - **Dynamic Tripolar Logic (DTL)**: 58.5% information advantage over binary
 //! - **Topological Codes**: Symmetry-protected quantum error correction
 //!
@@ -63,8 +63,9 @@
 //!
 //! Available features:
 //! - `walks` (default) - Quantum walk algorithms
-//! - `vqa` (default) - VQE, QAOA, VQC
+//! - `vqa` (default) - VQE, VQD, QAOA, VQC
 //! - `dtl` (default) - Dynamic Tripolar Logic
+//! - `triton` (default) - TRITON spiral-search optimizer (implies `vqa`)
 //! - `codes` - Topological codes
 //! - `advanced` - Advanced algorithms (Grover, Boson sampling)
 //!
@@ -136,14 +137,18 @@ pub mod quantum_walk_toolkit;
 
 // Core re-exports (always available)
 pub use crate::graph::metatron::MetatronGraph;
-pub use crate::hamiltonian::{MetatronHamiltonian, SpectrumInfo};
+pub use crate::hamiltonian::{MetatronHamiltonian, SpectrumInfo, TimeDependentHamiltonian};
 pub use crate::params::QSOParameters;
 pub use crate::qso::QuantumStateOperator;
 pub use crate::quantum::{METATRON_DIMENSION, operator::QuantumOperator, state::QuantumState};
 
 // Feature-gated re-exports
 #[cfg(feature = "dtl")]
-pub use crate::dtl::{network::DTLResonatorNetwork, operations::DTLOperations, state::DTLState};
+pub use crate::dtl::{
+    network::{DTLNetworkConfig, DTLResonatorNetwork, DTLResonatorNetworkBuilder},
+    operations::DTLOperations,
+    state::DTLState,
+};
 
 /// Prelude module for convenient imports.
 ///
@@ -153,8 +158,8 @@ pub use crate::dtl::{network::DTLResonatorNetwork, operations::DTLOperations, st
 /// ```
 pub mod prelude {
     // Core types (always available)
-    pub use crate::graph::metatron::MetatronGraph;
-    pub use crate::hamiltonian::{MetatronHamiltonian, SpectrumInfo};
+    pub use crate::graph::metatron::{MetatronGraph, PlatonicSolid};
+    pub use crate::hamiltonian::{MetatronHamiltonian, SpectrumInfo, TimeDependentHamiltonian};
     pub use crate::params::QSOParameters;
     pub use crate::qso::QuantumStateOperator;
     pub use crate::quantum::{METATRON_DIMENSION, operator::QuantumOperator, state::QuantumState};
@@ -162,14 +167,20 @@ pub mod prelude {
     // DTL (feature: dtl)
     #[cfg(feature = "dtl")]
     pub use crate::dtl::{
-        network::DTLResonatorNetwork, operations::DTLOperations, state::DTLState,
+        network::{DTLNetworkConfig, DTLResonatorNetwork, DTLResonatorNetworkBuilder},
+        operations::DTLOperations,
+        state::DTLState,
     };
 
     // Quantum Walks (feature: walks)
     #[cfg(feature = "walks")]
     pub use crate::quantum_walk::{
         BenchmarkMetadata, QuantumWalkBenchmarkSuite, QuantumWalkBenchmarker,
-        continuous::{ContinuousTimeQuantumWalk, SpectralPropagator},
+        continuous::{
+            ContinuousTimeQuantumWalk, PropagatorKind, SpectralPropagator, TrotterOrder,
+            TrotterPropagator,
+        },
+        discrete::{CoinType, CoinedQuantumWalk, CoinedWalkState},
         krylov::{KrylovEvolution, KrylovProjection, LanczosResult},
         scattering::{DensityOfStates, ScatteringAnalysis, ScatteringChannel},
     };
@@ -182,12 +193,13 @@ pub mod prelude {
         optimizer::{OptimizationResult, Optimizer, OptimizerConfig, OptimizerType},
         qaoa::{QAOA, QAOABuilder, QAOAConfig, QAOAResult},
         vqc::{VQC, VQCBuilder, VQCConfig, VQCResult},
+        vqd::{VQD, VQDBuilder, VQDConfig},
         vqe::{VQE, VQEBuilder, VQEConfig, VQEResult},
     };
 
     // Symmetry Codes (feature: codes)
     #[cfg(feature = "codes")]
-    pub use crate::symmetry_codes::MetatronCode;
+    pub use crate::symmetry_codes::{ErrorDiagnosis, MetatronCode};
 
     // Advanced Algorithms (feature: advanced)
     #[cfg(feature = "advanced")]
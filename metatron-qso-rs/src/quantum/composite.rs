@@ -0,0 +1,193 @@
+//! Tensor-product states and operators on composite Hilbert spaces.
+//!
+//! [`QuantumState`] and [`QuantumOperator`] are fixed to the 13-dimensional
+//! Metatron space, so Kronecker products live in dynamically sized types that
+//! record the dimension of every factor. `dims = [13, 2]` describes a
+//! Metatron ⊗ qubit space of total dimension 26; factors are ordered so the
+//! left operand of `tensor` comes first (row-major, like `numpy.kron`). Any
+//! factor dimension is allowed, which is how non-13 spaces such as a 2×2 coin
+//! enter a product via [`CompositeOperator::from_matrix`].
+
+use nalgebra::{DMatrix, DVector};
+use num_complex::Complex64;
+
+use crate::quantum::operator::QuantumOperator;
+use crate::quantum::state::{METATRON_DIMENSION, QuantumState, QuantumStateError};
+
+/// State vector on a tensor-product space.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CompositeState {
+    amplitudes: DVector<Complex64>,
+    dims: Vec<usize>,
+}
+
+impl CompositeState {
+    /// Single-factor state from raw amplitudes (not normalised).
+    pub fn from_vector(amplitudes: DVector<Complex64>) -> Self {
+        let dims = vec![amplitudes.len()];
+        Self { amplitudes, dims }
+    }
+
+    /// Kronecker product `self ⊗ other`.
+    pub fn tensor(&self, other: &Self) -> Self {
+        let amplitudes = self.amplitudes.kronecker(&other.amplitudes);
+        let dims = self.dims.iter().chain(&other.dims).copied().collect();
+        Self { amplitudes, dims }
+    }
+
+    /// Dimension of each tensor factor.
+    pub fn dims(&self) -> &[usize] {
+        &self.dims
+    }
+
+    /// Total Hilbert-space dimension (product of `dims`).
+    pub fn dimension(&self) -> usize {
+        self.amplitudes.len()
+    }
+
+    /// Raw amplitudes.
+    pub fn amplitudes(&self) -> &DVector<Complex64> {
+        &self.amplitudes
+    }
+
+    /// Euclidean norm.
+    pub fn norm(&self) -> f64 {
+        self.amplitudes.norm()
+    }
+}
+
+impl From<&QuantumState> for CompositeState {
+    fn from(state: &QuantumState) -> Self {
+        Self {
+            amplitudes: DVector::from_column_slice(state.amplitudes().as_slice()),
+            dims: vec![METATRON_DIMENSION],
+        }
+    }
+}
+
+/// Linear operator on a tensor-product space.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CompositeOperator {
+    matrix: DMatrix<Complex64>,
+    dims: Vec<usize>,
+}
+
+impl CompositeOperator {
+    /// Single-factor operator from a square matrix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `matrix` is not square.
+    pub fn from_matrix(matrix: DMatrix<Complex64>) -> Self {
+        assert!(matrix.is_square(), "composite operators must be square");
+        let dims = vec![matrix.nrows()];
+        Self { matrix, dims }
+    }
+
+    /// Kronecker product `self ⊗ other`.
+    pub fn tensor(&self, other: &Self) -> Self {
+        let matrix = self.matrix.kronecker(&other.matrix);
+        let dims = self.dims.iter().chain(&other.dims).copied().collect();
+        Self { matrix, dims }
+    }
+
+    /// Apply the operator to a state with matching factor dimensions.
+    pub fn apply(&self, state: &CompositeState) -> Result<CompositeState, QuantumStateError> {
+        if self.dims != state.dims {
+            return Err(QuantumStateError::DimensionMismatch {
+                expected: self.matrix.nrows(),
+                actual: state.dimension(),
+            });
+        }
+        Ok(CompositeState {
+            amplitudes: &self.matrix * &state.amplitudes,
+            dims: self.dims.clone(),
+        })
+    }
+
+    /// Dimension of each tensor factor.
+    pub fn dims(&self) -> &[usize] {
+        &self.dims
+    }
+
+    /// Total Hilbert-space dimension (product of `dims`).
+    pub fn dimension(&self) -> usize {
+        self.matrix.nrows()
+    }
+
+    /// Dense matrix access.
+    pub fn matrix(&self) -> &DMatrix<Complex64> {
+        &self.matrix
+    }
+}
+
+impl From<&QuantumOperator> for CompositeOperator {
+    fn from(operator: &QuantumOperator) -> Self {
+        Self {
+            matrix: DMatrix::from_column_slice(
+                METATRON_DIMENSION,
+                METATRON_DIMENSION,
+                operator.matrix().as_slice(),
+            ),
+            dims: vec![METATRON_DIMENSION],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn c(re: f64, im: f64) -> Complex64 {
+        Complex64::new(re, im)
+    }
+
+    #[test]
+    fn kronecker_product_acts_factorwise() {
+        let a = CompositeOperator::from_matrix(DMatrix::from_row_slice(
+            2,
+            2,
+            &[c(0.0, 0.0), c(1.0, 0.0), c(1.0, 0.0), c(0.0, 0.0)],
+        ));
+        let b = CompositeOperator::from_matrix(DMatrix::from_row_slice(
+            2,
+            2,
+            &[c(1.0, 0.0), c(0.0, 2.0), c(0.5, 0.0), c(-1.0, 0.0)],
+        ));
+        let u = CompositeState::from_vector(DVector::from_vec(vec![c(0.6, 0.0), c(0.0, 0.8)]));
+        let v = CompositeState::from_vector(DVector::from_vec(vec![c(1.0, 0.0), c(2.0, -1.0)]));
+
+        let ab = a.tensor(&b);
+        let uv = u.tensor(&v);
+        assert_eq!(ab.dims(), &[2, 2]);
+        assert_eq!(uv.dimension(), 4);
+
+        let lhs = ab.apply(&uv).unwrap();
+        let rhs = a.apply(&u).unwrap().tensor(&b.apply(&v).unwrap());
+        assert!((lhs.amplitudes() - rhs.amplitudes()).norm() < 1e-12);
+
+        // |0⟩⊗|1⟩ is basis index 1 (left factor most significant).
+        let zero = CompositeState::from_vector(DVector::from_vec(vec![c(1.0, 0.0), c(0.0, 0.0)]));
+        let one = CompositeState::from_vector(DVector::from_vec(vec![c(0.0, 0.0), c(1.0, 0.0)]));
+        assert_eq!(zero.tensor(&one).amplitudes()[1], c(1.0, 0.0));
+
+        assert!(ab.apply(&u).is_err());
+    }
+
+    #[test]
+    fn metatron_factors_track_dimensions() {
+        let state = QuantumState::basis_state(3).unwrap();
+        let coin = CompositeState::from_vector(DVector::from_vec(vec![c(1.0, 0.0), c(0.0, 0.0)]));
+        let joint = state.tensor(&QuantumState::uniform_superposition());
+        assert_eq!(joint.dims(), &[13, 13]);
+        assert!((joint.norm() - 1.0).abs() < 1e-12);
+
+        let composite = CompositeState::from(&state).tensor(&coin);
+        assert_eq!(composite.dims(), &[13, 2]);
+        assert_eq!(composite.amplitudes()[3 * 2], c(1.0, 0.0));
+
+        let identity = QuantumOperator::identity().tensor(&QuantumOperator::identity());
+        assert_eq!(identity.dimension(), 169);
+        assert_eq!(identity.apply(&joint).unwrap(), joint);
+    }
+}
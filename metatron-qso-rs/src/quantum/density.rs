@@ -0,0 +1,223 @@
+//! Mixed states on the 13D Metatron Hilbert space.
+//!
+//! [`QuantumState`] is a pure statevector; thermal states and noisy or open
+//! evolutions need a density matrix ρ instead. [`DensityMatrix`] shares the
+//! static 13×13 layout of [`QuantumOperator`] and is kept Hermitian with unit
+//! trace by every constructor.
+
+use nalgebra::SymmetricEigen;
+use num_complex::Complex64;
+use thiserror::Error;
+
+use super::operator::{OperatorMatrix, QuantumOperator};
+use super::state::{METATRON_DIMENSION, QuantumState};
+
+/// Errors raised when validating a density matrix.
+#[derive(Debug, Error, PartialEq)]
+pub enum DensityMatrixError {
+    /// The matrix differs from its adjoint.
+    #[error("density matrix is not Hermitian")]
+    NotHermitian,
+
+    /// The trace is not one.
+    #[error("density matrix has trace {trace}, expected 1")]
+    InvalidTrace { trace: f64 },
+
+    /// An eigenvalue is negative beyond the tolerance.
+    #[error("density matrix has negative eigenvalue {eigenvalue}")]
+    NotPositive { eigenvalue: f64 },
+
+    /// An evolution operator is not unitary, so it would not preserve the
+    /// trace.
+    #[error("evolution operator is not unitary")]
+    NotUnitary,
+}
+
+/// Density matrix ρ of a (possibly mixed) state.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DensityMatrix {
+    matrix: OperatorMatrix,
+}
+
+impl DensityMatrix {
+    /// Validate `matrix` as a density matrix: Hermitian, unit trace and
+    /// positive semidefinite, each within `tol`.
+    pub fn from_matrix(matrix: OperatorMatrix, tol: f64) -> Result<Self, DensityMatrixError> {
+        if (matrix - matrix.adjoint()).norm() > tol {
+            return Err(DensityMatrixError::NotHermitian);
+        }
+        let trace = matrix.trace().re;
+        if (trace - 1.0).abs() > tol {
+            return Err(DensityMatrixError::InvalidTrace { trace });
+        }
+        let eigenvalue = SymmetricEigen::new(matrix).eigenvalues.min();
+        if eigenvalue < -tol {
+            return Err(DensityMatrixError::NotPositive { eigenvalue });
+        }
+        Ok(Self { matrix })
+    }
+
+    /// Pure state ρ = |ψ⟩⟨ψ| (normalised first).
+    pub fn from_pure(state: &QuantumState) -> Self {
+        let psi = QuantumState::from_vector(*state.amplitudes(), true).into_vector();
+        Self {
+            matrix: psi * psi.adjoint(),
+        }
+    }
+
+    /// Maximally mixed state I / 13.
+    pub fn maximally_mixed() -> Self {
+        Self {
+            matrix: OperatorMatrix::identity() / Complex64::new(METATRON_DIMENSION as f64, 0.0),
+        }
+    }
+
+    /// Mixture Σᵢ pᵢ |ψᵢ⟩⟨ψᵢ| of pure states; weights are normalised to sum
+    /// to one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a weight is negative or all weights are zero.
+    pub fn mixture(ensemble: &[(f64, QuantumState)]) -> Self {
+        assert!(
+            ensemble.iter().all(|(weight, _)| *weight >= 0.0),
+            "mixture weights must be non-negative"
+        );
+        let total: f64 = ensemble.iter().map(|(weight, _)| weight).sum();
+        assert!(total > 0.0, "mixture weights must not all be zero");
+
+        let matrix = ensemble
+            .iter()
+            .fold(OperatorMatrix::zeros(), |acc, (weight, state)| {
+                acc + Self::from_pure(state).matrix * Complex64::new(weight / total, 0.0)
+            });
+        Self { matrix }
+    }
+
+    /// Populations ρᵢᵢ, the probability of finding the walker on node `i`.
+    pub fn probabilities(&self) -> [f64; METATRON_DIMENSION] {
+        std::array::from_fn(|i| self.matrix[(i, i)].re)
+    }
+
+    /// Expectation value tr(ρO).
+    pub fn expectation_value(&self, operator: &QuantumOperator) -> Complex64 {
+        (self.matrix * operator.matrix()).trace()
+    }
+
+    /// Purity tr(ρ²): 1 for pure states, 1/13 for the maximally mixed one.
+    pub fn purity(&self) -> f64 {
+        (self.matrix * self.matrix).trace().re
+    }
+
+    /// Evolve under a unitary `operator`: ρ → UρU†.
+    ///
+    /// Fails unless U†U = I within `tol`; projectors and other non-unitary
+    /// maps would leave a trace other than one.
+    pub fn apply(&self, operator: &QuantumOperator, tol: f64) -> Result<Self, DensityMatrixError> {
+        if !operator.is_unitary(tol) {
+            return Err(DensityMatrixError::NotUnitary);
+        }
+        Ok(Self {
+            matrix: operator.matrix() * self.matrix * operator.matrix().adjoint(),
+        })
+    }
+
+    /// Fidelity ⟨ψ|ρ|ψ⟩ with a pure state (normalised first).
+    pub fn fidelity_with_pure(&self, state: &QuantumState) -> f64 {
+        let psi = QuantumState::from_vector(*state.amplitudes(), true).into_vector();
+        psi.dotc(&(self.matrix * psi)).re
+    }
+
+    /// Dense matrix access.
+    pub fn matrix(&self) -> &OperatorMatrix {
+        &self.matrix
+    }
+
+    /// Wrap a matrix already known to be a density matrix.
+    pub(crate) fn from_matrix_unchecked(matrix: OperatorMatrix) -> Self {
+        Self { matrix }
+    }
+}
+
+impl From<&QuantumState> for DensityMatrix {
+    fn from(state: &QuantumState) -> Self {
+        Self::from_pure(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn purity_separates_pure_and_mixed_states() {
+        let psi = QuantumState::random(Some(5));
+        let pure = DensityMatrix::from_pure(&psi);
+        assert_relative_eq!(pure.purity(), 1.0, epsilon = 1e-12);
+        assert_relative_eq!(pure.fidelity_with_pure(&psi), 1.0, epsilon = 1e-12);
+        let mixed = DensityMatrix::maximally_mixed();
+        assert_relative_eq!(mixed.purity(), 1.0 / 13.0, epsilon = 1e-12);
+
+        let number = QuantumOperator::number_operator();
+        let (mean, _) = psi.measure_observable(&number);
+        assert_relative_eq!(pure.expectation_value(&number).re, mean, epsilon = 1e-12);
+        for (p, q) in pure.probabilities().iter().zip(psi.probabilities()) {
+            assert_relative_eq!(*p, q, epsilon = 1e-12);
+        }
+
+        // An equal mixture of two basis states has purity 1/2
+        let half = DensityMatrix::mixture(&[
+            (1.0, QuantumState::basis_state(0).unwrap()),
+            (1.0, QuantumState::basis_state(3).unwrap()),
+        ]);
+        assert_relative_eq!(half.purity(), 0.5, epsilon = 1e-12);
+        assert_relative_eq!(half.probabilities()[3], 0.5, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn unitary_evolution_matches_pure_state() {
+        let perm: Vec<_> = (0..METATRON_DIMENSION)
+            .map(|i| (i + 1) % METATRON_DIMENSION)
+            .collect();
+        let shift = QuantumOperator::from_permutation(&perm).unwrap();
+        let psi = QuantumState::random(Some(9));
+        let evolved = DensityMatrix::from_pure(&psi).apply(&shift, 1e-12).unwrap();
+        let expected = DensityMatrix::from_pure(&psi.apply(&shift));
+        assert!((evolved.matrix() - expected.matrix()).norm() < 1e-12);
+        assert_relative_eq!(evolved.purity(), 1.0, epsilon = 1e-12);
+
+        let projector = QuantumOperator::node_projector(3).unwrap();
+        assert_eq!(
+            DensityMatrix::maximally_mixed().apply(&projector, 1e-12),
+            Err(DensityMatrixError::NotUnitary)
+        );
+    }
+
+    #[test]
+    fn from_matrix_validates_density_matrices() {
+        let valid = *DensityMatrix::maximally_mixed().matrix();
+        assert!(DensityMatrix::from_matrix(valid, 1e-12).is_ok());
+
+        let doubled = valid * Complex64::new(2.0, 0.0);
+        assert!(matches!(
+            DensityMatrix::from_matrix(doubled, 1e-12),
+            Err(DensityMatrixError::InvalidTrace { .. })
+        ));
+
+        let mut skew = valid;
+        skew[(0, 1)] = Complex64::new(0.0, 0.1);
+        assert_eq!(
+            DensityMatrix::from_matrix(skew, 1e-12),
+            Err(DensityMatrixError::NotHermitian)
+        );
+
+        let mut negative = OperatorMatrix::zeros();
+        negative[(0, 0)] = Complex64::new(1.5, 0.0);
+        negative[(1, 1)] = Complex64::new(-0.5, 0.0);
+        assert!(matches!(
+            DensityMatrix::from_matrix(negative, 1e-12),
+            Err(DensityMatrixError::NotPositive { .. })
+        ));
+    }
+}
@@ -1,6 +1,9 @@
 //! Quantum information primitives: states and operators on the 13D Metatron Hilbert space.
 
+pub mod composite;
+pub mod density;
 pub mod operator;
+pub mod pauli;
 pub mod state;
 
 pub use state::METATRON_DIMENSION;
@@ -2,6 +2,7 @@ use nalgebra::SMatrix;
 use num_complex::Complex64;
 use thiserror::Error;
 
+use super::composite::CompositeOperator;
 use super::state::{METATRON_DIMENSION, StateVector};
 
 /// Static 13×13 matrix type alias.
@@ -17,6 +18,10 @@ pub enum QuantumOperatorError {
     /// Permutation indices exceeded the supported dimension.
     #[error("permutation index {index} exceeds dimension {dimension}")]
     PermutationIndexOutOfRange { index: usize, dimension: usize },
+
+    /// Node index exceeded the supported dimension.
+    #[error("node index {index} exceeds dimension {dimension}")]
+    NodeIndexOutOfRange { index: usize, dimension: usize },
 }
 
 /// Unitary or general linear operator on the Metatron Hilbert space.
@@ -38,6 +43,34 @@ impl QuantumOperator {
         }
     }
 
+    /// Projector |n⟩⟨n| onto node `node`; its expectation is the node's
+    /// occupation probability.
+    pub fn node_projector(node: usize) -> Result<Self, QuantumOperatorError> {
+        if node >= METATRON_DIMENSION {
+            return Err(QuantumOperatorError::NodeIndexOutOfRange {
+                index: node,
+                dimension: METATRON_DIMENSION,
+            });
+        }
+        let mut matrix = OperatorMatrix::zeros();
+        matrix[(node, node)] = Complex64::new(1.0, 0.0);
+        Ok(Self { matrix })
+    }
+
+    /// Excitation-number operator, diagonal with `popcount(k)` on |k⟩.
+    ///
+    /// Reads the bits of a basis index as qubit occupations, the sectors
+    /// that [`ParticleConservingAnsatz`] preserves.
+    ///
+    /// [`ParticleConservingAnsatz`]: crate::vqa::ansatz::ParticleConservingAnsatz
+    pub fn number_operator() -> Self {
+        let mut matrix = OperatorMatrix::zeros();
+        for k in 0..METATRON_DIMENSION {
+            matrix[(k, k)] = Complex64::new(f64::from(k.count_ones()), 0.0);
+        }
+        Self { matrix }
+    }
+
     /// Build unitary operator from a permutation (0-based indices).
     pub fn from_permutation(permutation: &[usize]) -> Result<Self, QuantumOperatorError> {
         if permutation.len() != METATRON_DIMENSION {
@@ -75,6 +108,15 @@ impl QuantumOperator {
         })
     }
 
+    /// Check Hermiticity (A = A†) within tolerance.
+    pub fn is_hermitian(&self, tol: f64) -> bool {
+        let adjoint = self.matrix.adjoint();
+        self.matrix
+            .iter()
+            .zip(adjoint.iter())
+            .all(|(value, conjugate)| (value - conjugate).norm() < tol)
+    }
+
     /// Compose operators: `self` ∘ `other`.
     pub fn compose(&self, other: &Self) -> Self {
         Self {
@@ -89,6 +131,32 @@ impl QuantumOperator {
         }
     }
 
+    /// Hermitian conjugate A†; alias for [`QuantumOperator::adjoint`].
+    pub fn dagger(&self) -> Self {
+        self.adjoint()
+    }
+
+    /// Commutator [A, B] = AB − BA.
+    pub fn commutator(&self, other: &Self) -> Self {
+        Self {
+            matrix: self.matrix * other.matrix - other.matrix * self.matrix,
+        }
+    }
+
+    /// Anticommutator {A, B} = AB + BA.
+    pub fn anticommutator(&self, other: &Self) -> Self {
+        Self {
+            matrix: self.matrix * other.matrix + other.matrix * self.matrix,
+        }
+    }
+
+    /// Kronecker product `self ⊗ other` on the 169-dimensional space.
+    ///
+    /// See [`CompositeOperator`] for how factor dimensions are tracked.
+    pub fn tensor(&self, other: &Self) -> CompositeOperator {
+        CompositeOperator::from(self).tensor(&CompositeOperator::from(other))
+    }
+
     /// Apply operator to state vector.
     pub fn apply(&self, state: &StateVector) -> StateVector {
         self.matrix * state
@@ -122,4 +190,46 @@ mod tests {
         let composed = op.compose(&op);
         assert!(composed.is_unitary(1e-12));
     }
+
+    #[test]
+    fn hermiticity_and_unitarity_checks() {
+        let mut matrix = OperatorMatrix::identity();
+        matrix[(0, 1)] = Complex64::new(0.0, 1.0);
+        let op = QuantumOperator::from_matrix(matrix);
+        assert!(!op.is_hermitian(1e-12));
+        assert!(!op.is_unitary(1e-12));
+
+        matrix[(1, 0)] = Complex64::new(0.0, -1.0);
+        let op = QuantumOperator::from_matrix(matrix);
+        assert!(op.is_hermitian(1e-12));
+
+        let perm: Vec<_> = (0..METATRON_DIMENSION)
+            .map(|i| (i + 1) % METATRON_DIMENSION)
+            .collect();
+        let shift = QuantumOperator::from_permutation(&perm).unwrap();
+        assert!(shift.is_unitary(1e-12));
+        assert!(!shift.is_hermitian(1e-12));
+    }
+
+    #[test]
+    fn commutator_algebra() {
+        use crate::graph::metatron::MetatronGraph;
+        use crate::hamiltonian::MetatronHamiltonian;
+        use crate::params::QSOParameters;
+
+        let hamiltonian =
+            MetatronHamiltonian::new(&MetatronGraph::new(), &QSOParameters::default());
+        let h = QuantumOperator::from_matrix(hamiltonian.as_complex_operator());
+        assert!(h.commutator(&h).matrix().norm() < 1e-12);
+        assert!((h.dagger().matrix() - h.matrix()).norm() < 1e-12);
+
+        let perm: Vec<_> = (0..METATRON_DIMENSION)
+            .map(|i| (i + 1) % METATRON_DIMENSION)
+            .collect();
+        let shift = QuantumOperator::from_permutation(&perm).unwrap();
+        let anti = h.anticommutator(&shift);
+        let expected = h.compose(&shift).matrix() + shift.compose(&h).matrix();
+        assert!((anti.matrix() - expected).norm() < 1e-12);
+        assert!(shift.commutator(&shift.dagger()).matrix().norm() < 1e-12);
+    }
 }
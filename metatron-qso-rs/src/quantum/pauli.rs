@@ -0,0 +1,170 @@
+//! Pauli-string decomposition of operators on the 13D Metatron Hilbert space.
+//!
+//! Qubit hardware needs operators on 2ⁿ dimensions, so the 13 basis states are
+//! embedded into ⌈log₂ 13⌉ = 4 qubits: node `i` maps to the computational basis
+//! state whose binary expansion is `i` (qubit 0 is the most significant bit).
+//! The remaining states |13⟩, |14⟩, |15⟩ are padded with zero rows and
+//! columns, so they are decoupled eigenstates with eigenvalue 0. If the
+//! physical spectrum lies above zero these padding states become spurious low
+//! energy states; shift the operator or penalise the padding subspace before
+//! running a variational search on the qubit model.
+
+use nalgebra::DMatrix;
+use num_complex::Complex64;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::quantum::operator::OperatorMatrix;
+use crate::quantum::state::METATRON_DIMENSION;
+
+/// Number of qubits used to embed the 13-dimensional space.
+pub const PAULI_QUBITS: usize = 4;
+
+/// Dimension of the qubit embedding, 2^[`PAULI_QUBITS`].
+pub const EMBEDDING_DIMENSION: usize = 1 << PAULI_QUBITS;
+
+/// Coefficients with magnitude below this are dropped from decompositions.
+pub const PAULI_COEFFICIENT_TOLERANCE: f64 = 1e-12;
+
+/// Single-qubit Pauli operator.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Pauli {
+    I,
+    X,
+    Y,
+    Z,
+}
+
+impl Pauli {
+    /// All four Paulis in the order I, X, Y, Z.
+    pub const ALL: [Pauli; 4] = [Pauli::I, Pauli::X, Pauli::Y, Pauli::Z];
+
+    /// 2×2 matrix of the operator.
+    pub fn matrix(self) -> [[Complex64; 2]; 2] {
+        let zero = Complex64::new(0.0, 0.0);
+        let one = Complex64::new(1.0, 0.0);
+        let i = Complex64::new(0.0, 1.0);
+        match self {
+            Pauli::I => [[one, zero], [zero, one]],
+            Pauli::X => [[zero, one], [one, zero]],
+            Pauli::Y => [[zero, -i], [i, zero]],
+            Pauli::Z => [[one, zero], [zero, -one]],
+        }
+    }
+}
+
+/// Tensor product P₀ ⊗ P₁ ⊗ P₂ ⊗ P₃ over the four embedding qubits.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PauliString {
+    paulis: [Pauli; PAULI_QUBITS],
+}
+
+impl PauliString {
+    /// Pauli string with `paulis[q]` acting on qubit `q`.
+    pub fn new(paulis: [Pauli; PAULI_QUBITS]) -> Self {
+        Self { paulis }
+    }
+
+    /// The identity string IIII.
+    pub fn identity() -> Self {
+        Self::new([Pauli::I; PAULI_QUBITS])
+    }
+
+    /// Per-qubit factors.
+    pub fn paulis(&self) -> &[Pauli; PAULI_QUBITS] {
+        &self.paulis
+    }
+
+    /// Number of non-identity factors.
+    pub fn weight(&self) -> usize {
+        self.paulis.iter().filter(|&&p| p != Pauli::I).count()
+    }
+
+    /// All 4ⁿ Pauli strings in lexicographic I < X < Y < Z order.
+    pub fn all() -> impl Iterator<Item = PauliString> {
+        (0..EMBEDDING_DIMENSION * EMBEDDING_DIMENSION).map(|mut index| {
+            let mut paulis = [Pauli::I; PAULI_QUBITS];
+            for slot in paulis.iter_mut().rev() {
+                *slot = Pauli::ALL[index % 4];
+                index /= 4;
+            }
+            PauliString::new(paulis)
+        })
+    }
+
+    /// Dense 16×16 matrix of the tensor product.
+    pub fn matrix(&self) -> DMatrix<Complex64> {
+        DMatrix::from_fn(EMBEDDING_DIMENSION, EMBEDDING_DIMENSION, |row, col| {
+            self.paulis
+                .iter()
+                .enumerate()
+                .map(|(qubit, pauli)| {
+                    let shift = PAULI_QUBITS - 1 - qubit;
+                    pauli.matrix()[(row >> shift) & 1][(col >> shift) & 1]
+                })
+                .product()
+        })
+    }
+}
+
+impl fmt::Display for PauliString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for pauli in &self.paulis {
+            write!(f, "{:?}", pauli)?;
+        }
+        Ok(())
+    }
+}
+
+/// Zero-pad a 13×13 operator to the 16×16 qubit embedding.
+pub fn embed_operator(matrix: &OperatorMatrix) -> DMatrix<Complex64> {
+    let mut embedded = DMatrix::zeros(EMBEDDING_DIMENSION, EMBEDDING_DIMENSION);
+    embedded
+        .view_mut((0, 0), (METATRON_DIMENSION, METATRON_DIMENSION))
+        .copy_from(matrix);
+    embedded
+}
+
+/// Decompose a Hermitian 13×13 operator as Σ c_P P over 4-qubit Pauli strings.
+///
+/// The operator is zero-padded via [`embed_operator`] and each coefficient is
+/// c_P = Tr(P·H)/16, which is real for Hermitian H. Terms with
+/// |c_P| < [`PAULI_COEFFICIENT_TOLERANCE`] are omitted.
+pub fn pauli_decomposition(matrix: &OperatorMatrix) -> Vec<(f64, PauliString)> {
+    let embedded = embed_operator(matrix);
+    PauliString::all()
+        .filter_map(|pauli| {
+            let coefficient = (pauli.matrix() * &embedded).trace().re / EMBEDDING_DIMENSION as f64;
+            (coefficient.abs() >= PAULI_COEFFICIENT_TOLERANCE).then_some((coefficient, pauli))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::metatron::MetatronGraph;
+    use crate::hamiltonian::MetatronHamiltonian;
+    use crate::params::QSOParameters;
+
+    #[test]
+    fn decomposition_reconstructs_padded_hamiltonian() {
+        let graph = MetatronGraph::new();
+        let hamiltonian = MetatronHamiltonian::new(&graph, &QSOParameters::default());
+        let terms = hamiltonian.pauli_decomposition();
+        assert!(!terms.is_empty());
+
+        let mut reconstructed = DMatrix::zeros(EMBEDDING_DIMENSION, EMBEDDING_DIMENSION);
+        for (coefficient, pauli) in &terms {
+            reconstructed += pauli.matrix() * Complex64::new(*coefficient, 0.0);
+        }
+        let expected = embed_operator(&hamiltonian.as_complex_operator());
+        assert!((reconstructed - &expected).norm() < 1e-10);
+
+        // Padding rows and columns stay empty
+        for k in METATRON_DIMENSION..EMBEDDING_DIMENSION {
+            assert!(expected.row(k).norm() == 0.0 && expected.column(k).norm() == 0.0);
+        }
+        assert_eq!(PauliString::identity().to_string(), "IIII");
+    }
+}
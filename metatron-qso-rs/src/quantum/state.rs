@@ -1,6 +1,7 @@
+use std::collections::HashMap;
 use std::fmt;
 
-use nalgebra::SVector;
+use nalgebra::{DMatrix, SVector, SymmetricEigen};
 use num_complex::Complex64;
 use rand::distributions::{Distribution, WeightedIndex};
 use rand::rngs::SmallRng;
@@ -10,9 +11,14 @@ use serde::de::{Error as SerdeError, SeqAccess, Visitor};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use thiserror::Error;
 
+use super::composite::CompositeState;
+
 /// Dimension of the Metatron Cube Hilbert space.
 pub const METATRON_DIMENSION: usize = 13;
 
+/// Eigenvalues below this threshold are ignored in entropy calculations.
+pub const ENTROPY_EIGENVALUE_EPSILON: f64 = 1e-12;
+
 /// Static state vector type alias.
 pub type StateVector = SVector<Complex64, 13>;
 
@@ -26,6 +32,18 @@ pub enum QuantumStateError {
     /// Attempted to sample from a state with zero norm.
     #[error("state has zero norm and cannot be measured")]
     ZeroNorm,
+
+    /// Node index exceeded the Hilbert space dimension.
+    #[error("node index {index} exceeds dimension {dimension}")]
+    NodeIndexOutOfRange { index: usize, dimension: usize },
+
+    /// Node index appeared more than once in a subsystem partition.
+    #[error("node index {index} appears more than once in partition")]
+    DuplicateNodeIndex { index: usize },
+
+    /// Rényi entropy order must be non-negative and finite.
+    #[error("invalid Rényi order α = {alpha}")]
+    InvalidRenyiOrder { alpha: f64 },
 }
 
 /// Quantum state on the 13-dimensional Metatron Hilbert space.
@@ -164,6 +182,20 @@ impl QuantumState {
         Ok(idx)
     }
 
+    /// Measure `shots` independent copies in the node basis, returning counts
+    /// per observed basis index. The state itself is left untouched; a
+    /// zero-norm state yields no counts.
+    pub fn sample<R: Rng + ?Sized>(&self, shots: usize, rng: &mut R) -> HashMap<usize, usize> {
+        let mut counts = HashMap::new();
+        let Ok(dist) = WeightedIndex::new(self.probabilities()) else {
+            return counts;
+        };
+        for _ in 0..shots {
+            *counts.entry(dist.sample(rng)).or_insert(0) += 1;
+        }
+        counts
+    }
+
     /// Apply a quantum operator to this state.
     pub fn apply(&self, operator: &crate::quantum::operator::QuantumOperator) -> Self {
         let new_vec = operator.matrix() * self.amplitudes;
@@ -181,6 +213,24 @@ impl QuantumState {
         self.amplitudes.dotc(&temp)
     }
 
+    /// Mean and variance of a Hermitian observable, ⟨O⟩ and ⟨O²⟩ − ⟨O⟩².
+    ///
+    /// The variance vanishes exactly when the state is an eigenstate of
+    /// `operator`; its square root is the spread of single-shot outcomes.
+    pub fn measure_observable(
+        &self,
+        operator: &crate::quantum::operator::QuantumOperator,
+    ) -> (f64, f64) {
+        let norm_sqr = self.amplitudes.norm_squared();
+        if norm_sqr == 0.0 {
+            return (0.0, 0.0);
+        }
+        let o_psi = operator.matrix() * self.amplitudes;
+        let mean = self.amplitudes.dotc(&o_psi).re / norm_sqr;
+        let variance = (o_psi.norm_squared() / norm_sqr - mean * mean).max(0.0);
+        (mean, variance)
+    }
+
     /// Access raw amplitudes.
     pub fn amplitudes(&self) -> &StateVector {
         &self.amplitudes
@@ -190,6 +240,129 @@ impl QuantumState {
     pub fn into_vector(self) -> StateVector {
         self.amplitudes
     }
+
+    /// Kronecker product `self ⊗ other` on the 169-dimensional space.
+    ///
+    /// See [`CompositeState`] for how factor dimensions are tracked.
+    pub fn tensor(&self, other: &Self) -> CompositeState {
+        CompositeState::from(self).tensor(&CompositeState::from(other))
+    }
+
+    /// Full density matrix ρ = |ψ⟩⟨ψ|.
+    pub fn density_matrix(&self) -> DMatrix<Complex64> {
+        let psi = &self.amplitudes;
+        DMatrix::from_fn(METATRON_DIMENSION, METATRON_DIMENSION, |i, j| {
+            psi[i] * psi[j].conj()
+        })
+    }
+
+    /// Reduced density matrix of the node subset `keep`.
+    ///
+    /// The state is treated as a single excitation shared between the node
+    /// modes, so tracing out the complementary nodes leaves the block
+    /// ρ[keep, keep] plus a "vacuum" component carrying the probability that
+    /// the excitation sits outside `keep`. The returned matrix has dimension
+    /// `keep.len() + 1`: rows/columns follow the order of `keep`, and the last
+    /// row/column is the vacuum. Its trace equals ⟨ψ|ψ⟩.
+    pub fn reduced_density_matrix(
+        &self,
+        keep: &[usize],
+    ) -> Result<DMatrix<Complex64>, QuantumStateError> {
+        validate_partition(keep)?;
+
+        let psi = &self.amplitudes;
+        let dim = keep.len() + 1;
+        let mut rho = DMatrix::zeros(dim, dim);
+        for (a, &i) in keep.iter().enumerate() {
+            for (b, &j) in keep.iter().enumerate() {
+                rho[(a, b)] = psi[i] * psi[j].conj();
+            }
+        }
+
+        let outside: f64 = (0..METATRON_DIMENSION)
+            .filter(|idx| !keep.contains(idx))
+            .map(|idx| psi[idx].norm_sqr())
+            .sum();
+        rho[(dim - 1, dim - 1)] = Complex64::new(outside, 0.0);
+
+        Ok(rho)
+    }
+
+    /// Fidelity F = |⟨ψ|φ⟩|² between two pure states.
+    ///
+    /// Both states are normalized before comparison (using the same rule as
+    /// [`QuantumState::from_vector`]), so the result always lies in [0, 1].
+    pub fn fidelity(&self, other: &Self) -> f64 {
+        let lhs = Self::from_vector(self.amplitudes, true);
+        let rhs = Self::from_vector(other.amplitudes, true);
+        lhs.inner_product(&rhs).norm_sqr().min(1.0)
+    }
+
+    /// Trace distance D = ½‖ρ − σ‖₁ between the density matrices of two states.
+    ///
+    /// Inputs are normalized first, as in [`QuantumState::fidelity`]. For pure
+    /// states this equals √(1 − F).
+    pub fn trace_distance(&self, other: &Self) -> f64 {
+        let lhs = Self::from_vector(self.amplitudes, true);
+        let rhs = Self::from_vector(other.amplitudes, true);
+        let difference = lhs.density_matrix() - rhs.density_matrix();
+        let eigenvalues = SymmetricEigen::new(difference).eigenvalues;
+        0.5 * eigenvalues.iter().map(|lambda| lambda.abs()).sum::<f64>()
+    }
+
+    /// Von Neumann entropy S = -Σ λᵢ ln λᵢ of the subsystem `partition`.
+    ///
+    /// Uses the reduced density matrix from
+    /// [`QuantumState::reduced_density_matrix`]; eigenvalues below
+    /// [`ENTROPY_EIGENVALUE_EPSILON`] are dropped so that ln(0) never occurs.
+    pub fn entanglement_entropy(&self, partition: &[usize]) -> Result<f64, QuantumStateError> {
+        let spectrum = self.reduced_spectrum(partition)?;
+        Ok(-spectrum.iter().map(|&p| p * p.ln()).sum::<f64>())
+    }
+
+    /// Rényi entropy S_α = ln(Σ λᵢ^α) / (1 − α) of the subsystem `partition`.
+    ///
+    /// α = 1 falls back to the von Neumann entropy.
+    pub fn renyi_entropy(&self, partition: &[usize], alpha: f64) -> Result<f64, QuantumStateError> {
+        if !alpha.is_finite() || alpha < 0.0 {
+            return Err(QuantumStateError::InvalidRenyiOrder { alpha });
+        }
+        if (alpha - 1.0).abs() < 1e-12 {
+            return self.entanglement_entropy(partition);
+        }
+        let spectrum = self.reduced_spectrum(partition)?;
+        let moment: f64 = spectrum.iter().map(|&p| p.powf(alpha)).sum();
+        Ok(moment.ln() / (1.0 - alpha))
+    }
+
+    /// Non-negligible eigenvalues of the normalized reduced density matrix.
+    fn reduced_spectrum(&self, partition: &[usize]) -> Result<Vec<f64>, QuantumStateError> {
+        let normalized = Self::from_vector(self.amplitudes, true);
+        let rho = normalized.reduced_density_matrix(partition)?;
+        let eigenvalues = SymmetricEigen::new(rho).eigenvalues;
+        Ok(eigenvalues
+            .iter()
+            .copied()
+            .filter(|&lambda| lambda > ENTROPY_EIGENVALUE_EPSILON)
+            .collect())
+    }
+}
+
+fn validate_partition(keep: &[usize]) -> Result<(), QuantumStateError> {
+    let mut seen = [false; METATRON_DIMENSION];
+    for &index in keep {
+        if index >= METATRON_DIMENSION {
+            return Err(QuantumStateError::NodeIndexOutOfRange {
+                index,
+                dimension: METATRON_DIMENSION,
+            });
+        }
+        if seen[index] {
+            return Err(QuantumStateError::DuplicateNodeIndex { index });
+        }
+        seen[index] = true;
+    }
+    Ok(())
 }
 
 fn normalize_vector(vector: &mut StateVector) {
@@ -235,8 +408,14 @@ impl<'de> Deserialize<'de> for QuantumState {
                 while let Some(value) = seq.next_element::<Complex64>()? {
                     data.push(value);
                 }
-                QuantumState::try_new(&data, true)
-                    .map_err(|err| SerdeError::custom(err.to_string()))
+                // Leave normalised input untouched so round trips are exact.
+                let state = QuantumState::try_new(&data, false)
+                    .map_err(|err| SerdeError::custom(err.to_string()))?;
+                if state.is_normalized(1e-12) {
+                    Ok(state)
+                } else {
+                    Ok(QuantumState::from_vector(state.into_vector(), true))
+                }
             }
         }
 
@@ -264,4 +443,105 @@ mod tests {
             assert_relative_eq!(*p, 1.0 / METATRON_DIMENSION as f64, epsilon = 1e-12);
         }
     }
+
+    #[test]
+    fn sampling_is_reproducible_for_identical_seeds() {
+        let state = QuantumState::random(Some(3));
+        let first = state.sample(500, &mut SmallRng::seed_from_u64(17));
+        let second = state.sample(500, &mut SmallRng::seed_from_u64(17));
+        assert_eq!(first, second);
+        assert_eq!(first.values().sum::<usize>(), 500);
+    }
+
+    #[test]
+    fn observables_on_basis_states() {
+        use crate::quantum::operator::{QuantumOperator, QuantumOperatorError};
+
+        let number = QuantumOperator::number_operator();
+        let seven = QuantumState::basis_state(7).unwrap();
+        assert_eq!(seven.measure_observable(&number), (3.0, 0.0));
+        let projector = QuantumOperator::node_projector(7).unwrap();
+        assert_eq!(seven.measure_observable(&projector), (1.0, 0.0));
+        assert_eq!(
+            QuantumState::basis_state(2)
+                .unwrap()
+                .measure_observable(&projector),
+            (0.0, 0.0)
+        );
+
+        // Equal superposition of |1⟩ (one excitation) and |3⟩ (two)
+        let amp = Complex64::new(1.0 / 2.0_f64.sqrt(), 0.0);
+        let mut amps = vec![Complex64::new(0.0, 0.0); METATRON_DIMENSION];
+        amps[1] = amp;
+        amps[3] = amp;
+        let state = QuantumState::try_new(&amps, false).unwrap();
+        let (mean, variance) = state.measure_observable(&number);
+        assert_relative_eq!(mean, 1.5, epsilon = 1e-12);
+        assert_relative_eq!(variance, 0.25, epsilon = 1e-12);
+        let (p, bernoulli) = state.measure_observable(&QuantumOperator::node_projector(3).unwrap());
+        assert_relative_eq!(p, 0.5, epsilon = 1e-12);
+        assert_relative_eq!(bernoulli, 0.25, epsilon = 1e-12);
+
+        assert_eq!(
+            QuantumOperator::node_projector(13),
+            Err(QuantumOperatorError::NodeIndexOutOfRange {
+                index: 13,
+                dimension: METATRON_DIMENSION,
+            })
+        );
+    }
+
+    #[test]
+    fn fidelity_and_trace_distance_of_basis_states() {
+        let zero = QuantumState::basis_state(0).unwrap();
+        let one = QuantumState::basis_state(1).unwrap();
+        assert_relative_eq!(zero.fidelity(&zero), 1.0, epsilon = 1e-12);
+        assert_relative_eq!(zero.fidelity(&one), 0.0, epsilon = 1e-12);
+        assert_relative_eq!(zero.trace_distance(&zero), 0.0, epsilon = 1e-12);
+        assert_relative_eq!(zero.trace_distance(&one), 1.0, epsilon = 1e-12);
+
+        let psi = QuantumState::random(Some(3));
+        let phi = QuantumState::random(Some(4));
+        let expected = (1.0 - psi.fidelity(&phi)).sqrt();
+        assert_relative_eq!(psi.trace_distance(&phi), expected, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn entropy_of_split_excitation_is_ln_two() {
+        let amp = Complex64::new(1.0 / 2.0_f64.sqrt(), 0.0);
+        let mut amps = vec![Complex64::new(0.0, 0.0); METATRON_DIMENSION];
+        amps[1] = amp;
+        amps[7] = amp;
+        let state = QuantumState::try_new(&amps, false).unwrap();
+        let hexagon: Vec<usize> = (1..=6).collect();
+
+        let entropy = state.entanglement_entropy(&hexagon).unwrap();
+        assert_relative_eq!(entropy, std::f64::consts::LN_2, epsilon = 1e-10);
+        let renyi = state.renyi_entropy(&hexagon, 2.0).unwrap();
+        assert_relative_eq!(renyi, std::f64::consts::LN_2, epsilon = 1e-10);
+
+        let basis = QuantumState::basis_state(1).unwrap();
+        assert_relative_eq!(basis.entanglement_entropy(&hexagon).unwrap(), 0.0);
+    }
+
+    #[test]
+    fn reduced_density_matrix_preserves_trace() {
+        let state = QuantumState::random(Some(7));
+        let hexagon: Vec<usize> = (1..=6).collect();
+        let rho = state.reduced_density_matrix(&hexagon).unwrap();
+        assert_eq!(rho.nrows(), hexagon.len() + 1);
+        assert_relative_eq!(rho.trace().re, 1.0, epsilon = 1e-12);
+
+        assert_eq!(
+            state.reduced_density_matrix(&[1, 1]),
+            Err(QuantumStateError::DuplicateNodeIndex { index: 1 })
+        );
+        assert_eq!(
+            state.reduced_density_matrix(&[13]),
+            Err(QuantumStateError::NodeIndexOutOfRange {
+                index: 13,
+                dimension: METATRON_DIMENSION
+            })
+        );
+    }
 }
@@ -1,13 +1,89 @@
 use nalgebra::{DMatrix, DVector};
+use num_complex::Complex64;
 use serde::{Deserialize, Serialize};
 
 use crate::graph::metatron::{AdjacencyMatrix, MetatronGraph};
 use crate::hamiltonian::MetatronHamiltonian;
 use crate::qso::QuantumStateOperator;
-use crate::quantum::state::{METATRON_DIMENSION, QuantumState};
+use crate::quantum::operator::OperatorMatrix;
+use crate::quantum::state::{METATRON_DIMENSION, QuantumState, StateVector};
 
 use super::continuous::{ContinuousTimeQuantumWalk, SpectralPropagator};
 
+/// Resolution of the time scan in [`mixing_time`].
+const MIXING_SCAN_STEP: f64 = 0.01;
+
+/// Latest time [`mixing_time`] looks at before giving up.
+const MIXING_HORIZON: f64 = 100.0;
+
+/// Distance between two node distributions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DistributionMetric {
+    /// Total variation ½ Σᵢ |pᵢ − qᵢ|, in [0, 1].
+    TotalVariation,
+    /// Euclidean distance (Σᵢ (pᵢ − qᵢ)²)^½.
+    L2,
+    /// Largest single-node deviation maxᵢ |pᵢ − qᵢ|.
+    MaxDeviation,
+}
+
+impl DistributionMetric {
+    /// Distance between `p` and `q` under this metric.
+    pub fn distance(&self, p: &[f64], q: &[f64]) -> f64 {
+        let differences = p.iter().zip(q).map(|(a, b)| (a - b).abs());
+        match self {
+            DistributionMetric::TotalVariation => 0.5 * differences.sum::<f64>(),
+            DistributionMetric::L2 => differences.map(|d| d * d).sum::<f64>().sqrt(),
+            DistributionMetric::MaxDeviation => differences.fold(0.0, f64::max),
+        }
+    }
+}
+
+/// First time a walk from `source` comes within `epsilon` of `target_dist`.
+///
+/// The node distribution of the coherent walk is scanned on a grid of step
+/// 0.01 up to t = 100; the first grid interval that ends below `epsilon` is
+/// then bisected, so the returned time is the first crossing to within
+/// 1e-10 (crossings shorter than the grid step can be missed). Returns
+/// `None` if the distance never drops below `epsilon` within the horizon;
+/// coherent walks oscillate, so a target such as the uniform distribution
+/// may never be reached.
+///
+/// # Panics
+///
+/// Panics if `source` is not a valid node index.
+pub fn mixing_time(
+    hamiltonian: &MetatronHamiltonian,
+    source: usize,
+    target_dist: &[f64; METATRON_DIMENSION],
+    metric: DistributionMetric,
+    epsilon: f64,
+) -> Option<f64> {
+    let initial = QuantumState::basis_state(source).expect("source node out of range");
+    let propagator = ContinuousTimeQuantumWalk::new(hamiltonian).propagator(&initial);
+    let distance = |t: f64| metric.distance(&propagator.probabilities_at(t), target_dist);
+
+    if distance(0.0) < epsilon {
+        return Some(0.0);
+    }
+    let steps = (MIXING_HORIZON / MIXING_SCAN_STEP).ceil() as usize;
+    let step = (1..=steps).find(|&k| distance(k as f64 * MIXING_SCAN_STEP) < epsilon)?;
+
+    let (mut above, mut below) = (
+        (step - 1) as f64 * MIXING_SCAN_STEP,
+        step as f64 * MIXING_SCAN_STEP,
+    );
+    while below - above > 1e-10 {
+        let mid = 0.5 * (above + below);
+        if distance(mid) < epsilon {
+            below = mid;
+        } else {
+            above = mid;
+        }
+    }
+    Some(below)
+}
+
 /// Time-series diagnostics for the mixing behaviour of a quantum walk.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct MixingTimeResult {
@@ -215,6 +291,68 @@ impl<'a> QuantumWalkBenchmarker<'a> {
     }
 }
 
+/// Absorption rate γ of the sink placed on the target node.
+pub const ABSORPTION_RATE: f64 = 1.0;
+
+/// Survival probability below which the absorbing walk counts as converged.
+pub const SURVIVAL_TOLERANCE: f64 = 1e-8;
+
+/// Step cap for [`exact_hitting_time`]; reaching it yields `f64::INFINITY`.
+const MAX_ABSORPTION_STEPS: usize = 200_000;
+
+/// Mean absorption time of a walk from `source` into a sink at `target`.
+///
+/// The target is made absorbing through the non-Hermitian Hamiltonian
+/// `H_eff = H − iγ|target⟩⟨target|` with γ = [`ABSORPTION_RATE`]. The survival
+/// probability `S(t) = ‖e^{−iH_eff·t}|source⟩‖²` decays as amplitude leaks
+/// into the sink and the hitting time is `T = ∫₀^∞ S(t) dt`.
+///
+/// Discretization: the exact one-step propagator `e^{−iH_eff·Δt}` is applied
+/// repeatedly with `Δt = 0.05 / max(|λ|_max, γ)`, so the walk itself carries
+/// no time-stepping error, and `S` is integrated with the trapezoidal rule.
+/// Integration stops once `S` falls below [`SURVIVAL_TOLERANCE`]. If it never
+/// does within the step cap, `f64::INFINITY` is returned: part of the source
+/// state lies in a dark subspace that never reaches the target (disconnected
+/// nodes, or degenerate eigenspaces of highly symmetric graphs), or absorption
+/// is too slow to resolve within the cap.
+///
+/// # Panics
+///
+/// Panics if `source` or `target` is not a valid node index.
+pub fn exact_hitting_time(hamiltonian: &MetatronHamiltonian, source: usize, target: usize) -> f64 {
+    assert!(
+        source < METATRON_DIMENSION && target < METATRON_DIMENSION,
+        "node index out of range"
+    );
+
+    let mut effective = hamiltonian.as_complex_operator();
+    effective[(target, target)] -= Complex64::new(0.0, ABSORPTION_RATE);
+
+    let scale = hamiltonian
+        .eigenvalues()
+        .iter()
+        .fold(ABSORPTION_RATE, |acc, e| acc.max(e.abs()));
+    let dt = 0.05 / scale;
+    let step: OperatorMatrix = (effective * Complex64::new(0.0, -dt)).exp();
+
+    let mut state = StateVector::zeros();
+    state[source] = Complex64::new(1.0, 0.0);
+    let mut survival = 1.0;
+    let mut integral = 0.0;
+
+    for _ in 0..MAX_ABSORPTION_STEPS {
+        state = step * state;
+        let next = state.norm_squared();
+        integral += 0.5 * (survival + next) * dt;
+        survival = next;
+        if survival < SURVIVAL_TOLERANCE {
+            return integral;
+        }
+    }
+
+    f64::INFINITY
+}
+
 fn quantum_hitting(
     start: usize,
     propagator: &SpectralPropagator,
@@ -344,6 +482,43 @@ mod tests {
         assert!(result.stationary_distribution.iter().all(|p| *p >= 0.0));
     }
 
+    #[test]
+    fn mixing_time_on_a_single_edge() {
+        // One edge 0–1: p₁(t) = sin²t, so the distance to the even split is
+        // |cos 2t| / 2 (total variation) or |cos 2t| / √2 (L2).
+        let edge = MetatronGraph::from_weighted_edges(&[(0, 1, 1.0)]).unwrap();
+        let hamiltonian = MetatronHamiltonian::new(&edge, &QSOParameters::default());
+        let mut even = [0.0; METATRON_DIMENSION];
+        even[0] = 0.5;
+        even[1] = 0.5;
+
+        let epsilon: f64 = 0.1;
+        let tv = mixing_time(
+            &hamiltonian,
+            0,
+            &even,
+            DistributionMetric::TotalVariation,
+            epsilon,
+        );
+        assert!((tv.unwrap() - (2.0 * epsilon).acos() / 2.0).abs() < 1e-8);
+        let l2 = mixing_time(&hamiltonian, 0, &even, DistributionMetric::L2, epsilon);
+        assert!((l2.unwrap() - (2.0_f64.sqrt() * epsilon).acos() / 2.0).abs() < 1e-8);
+
+        // Node 5 is isolated, so no mass ever reaches it
+        let mut unreachable = [0.0; METATRON_DIMENSION];
+        unreachable[5] = 1.0;
+        assert_eq!(
+            mixing_time(
+                &hamiltonian,
+                0,
+                &unreachable,
+                DistributionMetric::MaxDeviation,
+                0.5
+            ),
+            None
+        );
+    }
+
     #[test]
     fn hitting_time_benchmark_runs() {
         let params = QSOParameters::default();
@@ -353,4 +528,22 @@ mod tests {
         assert!(!report.quantum_results.is_empty());
         assert!(report.speedup_factor.is_finite());
     }
+
+    #[test]
+    fn exact_hitting_time_detects_dark_states() {
+        let params = QSOParameters::default();
+        // Path 0-1-2 with the remaining nodes isolated.
+        let path = MetatronGraph::from_weighted_edges(&[(0, 1, 1.0), (1, 2, 1.0)]).unwrap();
+        let hamiltonian = MetatronHamiltonian::new(&path, &params);
+
+        // Reference values from the Lyapunov equation A†X + XA = −I, A = −iH_eff.
+        // Interference makes the far end hit faster than the middle node.
+        assert!((exact_hitting_time(&hamiltonian, 1, 2) - 3.5).abs() < 1e-6);
+        assert!((exact_hitting_time(&hamiltonian, 0, 2) - 3.0).abs() < 1e-6);
+        assert!(exact_hitting_time(&hamiltonian, 5, 2).is_infinite());
+
+        // On the complete graph most of a basis state never couples to the sink.
+        let complete = MetatronHamiltonian::new(&MetatronGraph::new(), &params);
+        assert!(exact_hitting_time(&complete, 0, 5).is_infinite());
+    }
 }
@@ -1,20 +1,48 @@
 use num_complex::Complex64;
+use serde::{Deserialize, Serialize};
 
 use crate::hamiltonian::MetatronHamiltonian;
 use crate::quantum::state::{METATRON_DIMENSION, QuantumState, StateVector};
+use crate::quantum_walk::krylov::krylov_projection;
+
+/// Breakdown tolerance of the Lanczos recursion behind [`PropagatorKind::Krylov`].
+const KRYLOV_TOLERANCE: f64 = 1e-12;
+
+/// Time-evolution method used by [`ContinuousTimeQuantumWalk::evolve`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PropagatorKind {
+    /// Exact evolution in the cached eigenbasis of the Hamiltonian.
+    #[default]
+    Spectral,
+    /// Lanczos projection onto a Krylov space of dimension `dim`.
+    ///
+    /// Cheaper than the spectral method for short times; the error grows
+    /// with ‖H‖·t and shrinks rapidly as `dim` increases.
+    Krylov { dim: usize },
+}
 
 /// Continuous-time quantum walk engine backed by the Metatron Hamiltonian.
 pub struct ContinuousTimeQuantumWalk<'a> {
     hamiltonian: &'a MetatronHamiltonian,
     dephasing_rate: f64,
+    propagator: PropagatorKind,
 }
 
 impl<'a> ContinuousTimeQuantumWalk<'a> {
     /// Create a new continuous-time quantum walk wrapper.
     pub fn new(hamiltonian: &'a MetatronHamiltonian) -> Self {
+        Self::new_with_propagator(hamiltonian, PropagatorKind::Spectral)
+    }
+
+    /// Create a quantum walk that evolves states with the given propagator.
+    pub fn new_with_propagator(
+        hamiltonian: &'a MetatronHamiltonian,
+        propagator: PropagatorKind,
+    ) -> Self {
         Self {
             hamiltonian,
             dephasing_rate: 0.0,
+            propagator,
         }
     }
 
@@ -23,9 +51,15 @@ impl<'a> ContinuousTimeQuantumWalk<'a> {
         Self {
             hamiltonian,
             dephasing_rate,
+            propagator: PropagatorKind::Spectral,
         }
     }
 
+    /// Propagator used by [`Self::evolve`].
+    pub fn propagator_kind(&self) -> PropagatorKind {
+        self.propagator
+    }
+
     /// Construct a spectral propagator for a specific initial state.
     pub fn propagator(&self, initial: &QuantumState) -> SpectralPropagator<'a> {
         let overlaps = self.hamiltonian.project_onto_eigenbasis(initial);
@@ -36,9 +70,125 @@ impl<'a> ContinuousTimeQuantumWalk<'a> {
         }
     }
 
-    /// Exact evolution using the stored Hamiltonian exponentiation.
+    /// Evolve `initial` to time `t` with the configured [`PropagatorKind`].
     pub fn evolve(&self, initial: &QuantumState, time: f64) -> QuantumState {
-        self.hamiltonian.evolve_state(initial, time)
+        match self.propagator {
+            PropagatorKind::Spectral => self.hamiltonian.evolve_state(initial, time),
+            PropagatorKind::Krylov { dim } => {
+                krylov_projection(self.hamiltonian, initial, dim, KRYLOV_TOLERANCE)
+                    .evolve(time)
+                    .state
+            }
+        }
+    }
+
+    /// Approximate evolution via a second-order Trotter product with `steps` slices.
+    pub fn evolve_trotter(&self, initial: &QuantumState, time: f64, steps: usize) -> QuantumState {
+        TrotterPropagator::new(self.hamiltonian, TrotterOrder::Second, steps).evolve(initial, time)
+    }
+}
+
+/// Order of the Trotter–Suzuki product formula.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TrotterOrder {
+    /// Lie–Trotter splitting, error O(t²/n).
+    First,
+    /// Symmetric Strang splitting, error O(t³/n²).
+    Second,
+}
+
+/// Trotterized propagator that never diagonalises the Hamiltonian.
+///
+/// H is split into its diagonal (on-site) part and one two-level hopping term
+/// per non-zero off-diagonal entry. Each term is exponentiated in closed form,
+/// so a slice costs O(edges) instead of an O(N³) eigendecomposition.
+pub struct TrotterPropagator<'a> {
+    hamiltonian: &'a MetatronHamiltonian,
+    order: TrotterOrder,
+    steps: usize,
+    hoppings: Vec<(usize, usize, f64)>,
+}
+
+impl<'a> TrotterPropagator<'a> {
+    /// Create a propagator with the given order and number of time slices.
+    pub fn new(hamiltonian: &'a MetatronHamiltonian, order: TrotterOrder, steps: usize) -> Self {
+        let matrix = hamiltonian.matrix();
+        let mut hoppings = Vec::new();
+        for i in 0..METATRON_DIMENSION {
+            for j in (i + 1)..METATRON_DIMENSION {
+                let value = matrix[(i, j)];
+                if value != 0.0 {
+                    hoppings.push((i, j, value));
+                }
+            }
+        }
+        Self {
+            hamiltonian,
+            order,
+            steps: steps.max(1),
+            hoppings,
+        }
+    }
+
+    /// Order of the product formula.
+    pub fn order(&self) -> TrotterOrder {
+        self.order
+    }
+
+    /// Number of Trotter slices.
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    /// Evolve `initial` to time `t`.
+    pub fn evolve(&self, initial: &QuantumState, time: f64) -> QuantumState {
+        let dt = time / self.steps as f64;
+        let mut vector = *initial.amplitudes();
+        for _ in 0..self.steps {
+            match self.order {
+                TrotterOrder::First => {
+                    self.apply_hoppings(&mut vector, dt, false);
+                    self.apply_diagonal(&mut vector, dt);
+                }
+                TrotterOrder::Second => {
+                    self.apply_diagonal(&mut vector, 0.5 * dt);
+                    self.apply_hoppings(&mut vector, 0.5 * dt, false);
+                    self.apply_hoppings(&mut vector, 0.5 * dt, true);
+                    self.apply_diagonal(&mut vector, 0.5 * dt);
+                }
+            }
+        }
+        QuantumState::from_vector(vector, false)
+    }
+
+    /// L2 distance between the Trotter result and exact spectral evolution.
+    pub fn spectral_error(&self, initial: &QuantumState, time: f64) -> f64 {
+        let approx = self.evolve(initial, time);
+        let exact = self.hamiltonian.evolve_state(initial, time);
+        (approx.amplitudes() - exact.amplitudes()).norm()
+    }
+
+    fn apply_diagonal(&self, vector: &mut StateVector, dt: f64) {
+        let matrix = self.hamiltonian.matrix();
+        for i in 0..METATRON_DIMENSION {
+            vector[i] *= Complex64::from_polar(1.0, -matrix[(i, i)] * dt);
+        }
+    }
+
+    fn apply_hoppings(&self, vector: &mut StateVector, dt: f64, reversed: bool) {
+        let mut apply = |&(i, j, value): &(usize, usize, f64)| {
+            // exp(-i·h·dt·σx) on the {i, j} subspace
+            let cos = Complex64::new((value * dt).cos(), 0.0);
+            let sin = Complex64::new(0.0, -(value * dt).sin());
+            let (a, b) = (vector[i], vector[j]);
+            vector[i] = cos * a + sin * b;
+            vector[j] = sin * a + cos * b;
+        };
+        if reversed {
+            self.hoppings.iter().rev().for_each(&mut apply);
+        } else {
+            self.hoppings.iter().for_each(&mut apply);
+        }
     }
 }
 
@@ -115,3 +265,61 @@ impl<'a> SpectralPropagator<'a> {
         &self.overlaps
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::metatron::MetatronGraph;
+    use crate::hamiltonian::HamiltonianMatrix;
+    use crate::params::QSOParameters;
+
+    #[test]
+    fn trotter_error_decreases_with_order_and_steps() {
+        let graph = MetatronGraph::new();
+        let hamiltonian = MetatronHamiltonian::new(&graph, &QSOParameters::default());
+        let initial = QuantumState::basis_state(0).unwrap();
+        let time = 0.5;
+
+        let first = TrotterPropagator::new(&hamiltonian, TrotterOrder::First, 50);
+        let second = TrotterPropagator::new(&hamiltonian, TrotterOrder::Second, 50);
+        let second_fine = TrotterPropagator::new(&hamiltonian, TrotterOrder::Second, 200);
+
+        let first_error = first.spectral_error(&initial, time);
+        let second_error = second.spectral_error(&initial, time);
+        let fine_error = second_fine.spectral_error(&initial, time);
+        assert!(second_error < first_error);
+        assert!(fine_error < second_error);
+        assert!(fine_error < 1e-3);
+
+        let evolved =
+            ContinuousTimeQuantumWalk::new(&hamiltonian).evolve_trotter(&initial, time, 200);
+        assert!(evolved.is_normalized(1e-10));
+    }
+
+    #[test]
+    fn krylov_propagator_matches_spectral() {
+        // Path graph: unlike K13, a basis state spans a 13-dimensional Krylov space.
+        let mut matrix = HamiltonianMatrix::zeros();
+        for i in 0..METATRON_DIMENSION - 1 {
+            matrix[(i, i + 1)] = 1.0;
+            matrix[(i + 1, i)] = 1.0;
+        }
+        let hamiltonian = MetatronHamiltonian::from_matrix(matrix);
+        let initial = QuantumState::basis_state(6).unwrap();
+        let time = 1.5;
+
+        let spectral = ContinuousTimeQuantumWalk::new(&hamiltonian);
+        assert_eq!(spectral.propagator_kind(), PropagatorKind::Spectral);
+        let exact = spectral.evolve(&initial, time);
+
+        let error = |dim| {
+            let walk = ContinuousTimeQuantumWalk::new_with_propagator(
+                &hamiltonian,
+                PropagatorKind::Krylov { dim },
+            );
+            (walk.evolve(&initial, time).amplitudes() - exact.amplitudes()).norm()
+        };
+        assert!(error(10) < error(4));
+        assert!(error(10) < 1e-6);
+    }
+}
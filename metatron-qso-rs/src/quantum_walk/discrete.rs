@@ -0,0 +1,213 @@
+use nalgebra::{DMatrix, DVector};
+use num_complex::Complex64;
+use serde::{Deserialize, Serialize};
+
+use crate::graph::metatron::MetatronGraph;
+use crate::quantum::state::{METATRON_DIMENSION, QuantumStateError};
+
+/// Coin operator applied at every node before the shift.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CoinType {
+    /// Grover diffusion coin C = 2|s⟩⟨s| − I on the local edge space.
+    Grover,
+    /// Generalised Hadamard (discrete Fourier) coin, F_jk = ω^{jk}/√d.
+    Hadamard,
+}
+
+/// State of a coined walk over the directed arcs (u → v) of the graph.
+///
+/// Every undirected edge contributes two arcs, so the Metatron Cube state
+/// lives in a 156-dimensional position⊗coin space.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CoinedWalkState {
+    amplitudes: DVector<Complex64>,
+    arc_sources: Vec<usize>,
+}
+
+impl CoinedWalkState {
+    /// Access arc amplitudes (ordered by source node, then target).
+    pub fn amplitudes(&self) -> &DVector<Complex64> {
+        &self.amplitudes
+    }
+
+    /// Norm of the arc amplitude vector.
+    pub fn norm(&self) -> f64 {
+        self.amplitudes.norm()
+    }
+
+    /// Position distribution obtained by tracing out the coin.
+    pub fn probabilities(&self) -> [f64; METATRON_DIMENSION] {
+        let mut probs = [0.0; METATRON_DIMENSION];
+        for (amp, &source) in self.amplitudes.iter().zip(self.arc_sources.iter()) {
+            probs[source] += amp.norm_sqr();
+        }
+        probs
+    }
+}
+
+/// Discrete-time coined quantum walk on the Metatron Cube.
+///
+/// Each step applies a degree-dependent coin at every node followed by the
+/// flip-flop shift S|u → v⟩ = |v → u⟩.
+pub struct CoinedQuantumWalk {
+    coin_type: CoinType,
+    arcs: Vec<(usize, usize)>,
+    offsets: Vec<usize>,
+    reverse: Vec<usize>,
+    coins: Vec<DMatrix<Complex64>>,
+}
+
+impl CoinedQuantumWalk {
+    /// Build the walk for a graph with the given coin.
+    pub fn new(graph: &MetatronGraph, coin_type: CoinType) -> Self {
+        let mut arcs: Vec<(usize, usize)> = graph
+            .edges()
+            .iter()
+            .flat_map(|&(u, v)| [(u, v), (v, u)])
+            .collect();
+        arcs.sort_unstable();
+
+        let mut offsets = vec![0usize; METATRON_DIMENSION + 1];
+        for &(source, _) in &arcs {
+            offsets[source + 1] += 1;
+        }
+        for node in 0..METATRON_DIMENSION {
+            offsets[node + 1] += offsets[node];
+        }
+
+        let reverse = arcs
+            .iter()
+            .map(|&(u, v)| {
+                arcs.binary_search(&(v, u))
+                    .expect("undirected edge must have a reverse arc")
+            })
+            .collect();
+
+        let coins = (0..METATRON_DIMENSION)
+            .map(|node| build_coin(coin_type, offsets[node + 1] - offsets[node]))
+            .collect();
+
+        Self {
+            coin_type,
+            arcs,
+            offsets,
+            reverse,
+            coins,
+        }
+    }
+
+    /// Coin used by this walk.
+    pub fn coin_type(&self) -> CoinType {
+        self.coin_type
+    }
+
+    /// Dimension of the position⊗coin space (number of directed arcs).
+    pub fn dimension(&self) -> usize {
+        self.arcs.len()
+    }
+
+    /// Coin dimension (degree) of a node.
+    pub fn coin_dimension(&self, node: usize) -> usize {
+        self.offsets[node + 1] - self.offsets[node]
+    }
+
+    /// Walker localised on `node` with a uniform coin superposition.
+    pub fn initial_state(&self, node: usize) -> Result<CoinedWalkState, QuantumStateError> {
+        if node >= METATRON_DIMENSION {
+            return Err(QuantumStateError::NodeIndexOutOfRange {
+                index: node,
+                dimension: METATRON_DIMENSION,
+            });
+        }
+        let degree = self.coin_dimension(node);
+        let mut amplitudes = DVector::zeros(self.dimension());
+        if degree > 0 {
+            let amp = Complex64::new(1.0 / (degree as f64).sqrt(), 0.0);
+            for idx in self.offsets[node]..self.offsets[node + 1] {
+                amplitudes[idx] = amp;
+            }
+        }
+        Ok(self.wrap(amplitudes))
+    }
+
+    /// Apply one coin + shift step.
+    pub fn step(&self, state: &CoinedWalkState) -> CoinedWalkState {
+        let mut coined = DVector::zeros(self.dimension());
+        for (node, coin) in self.coins.iter().enumerate() {
+            let (start, end) = (self.offsets[node], self.offsets[node + 1]);
+            if start == end {
+                continue;
+            }
+            let local = coin * state.amplitudes.rows(start, end - start);
+            coined.rows_mut(start, end - start).copy_from(&local);
+        }
+
+        let mut shifted = DVector::zeros(self.dimension());
+        for (idx, &target) in self.reverse.iter().enumerate() {
+            shifted[target] = coined[idx];
+        }
+        self.wrap(shifted)
+    }
+
+    /// Run `steps` iterations, returning the trajectory including the initial state.
+    pub fn walk(&self, initial: &CoinedWalkState, steps: usize) -> Vec<CoinedWalkState> {
+        let mut trajectory = Vec::with_capacity(steps + 1);
+        trajectory.push(initial.clone());
+        for _ in 0..steps {
+            let next = self.step(trajectory.last().unwrap());
+            trajectory.push(next);
+        }
+        trajectory
+    }
+
+    fn wrap(&self, amplitudes: DVector<Complex64>) -> CoinedWalkState {
+        CoinedWalkState {
+            amplitudes,
+            arc_sources: self.arcs.iter().map(|&(source, _)| source).collect(),
+        }
+    }
+}
+
+fn build_coin(coin_type: CoinType, degree: usize) -> DMatrix<Complex64> {
+    if degree == 0 {
+        return DMatrix::zeros(0, 0);
+    }
+    let d = degree as f64;
+    match coin_type {
+        CoinType::Grover => DMatrix::from_fn(degree, degree, |i, j| {
+            let diagonal = if i == j { 1.0 } else { 0.0 };
+            Complex64::new(2.0 / d - diagonal, 0.0)
+        }),
+        CoinType::Hadamard => DMatrix::from_fn(degree, degree, |i, j| {
+            let angle = 2.0 * std::f64::consts::PI * (i * j) as f64 / d;
+            Complex64::from_polar(1.0 / d.sqrt(), angle)
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn coined_walk_preserves_norm() {
+        let graph = MetatronGraph::new();
+        for coin in [CoinType::Grover, CoinType::Hadamard] {
+            let walk = CoinedQuantumWalk::new(&graph, coin);
+            assert_eq!(walk.dimension(), 156);
+            assert_eq!(walk.coin_dimension(0), graph.neighbours(0).len());
+
+            let initial = walk.initial_state(0).unwrap();
+            let trajectory = walk.walk(&initial, 10);
+            assert_eq!(trajectory.len(), 11);
+            let last = trajectory.last().unwrap();
+            assert_relative_eq!(last.norm(), 1.0, epsilon = 1e-10);
+            assert_relative_eq!(
+                last.probabilities().iter().sum::<f64>(),
+                1.0,
+                epsilon = 1e-10
+            );
+        }
+    }
+}
@@ -18,18 +18,45 @@ impl LanczosResult {
     }
 
     pub fn tridiagonal(&self) -> DMatrix<f64> {
-        let dim = self.dimension();
-        let mut matrix = DMatrix::<f64>::zeros(dim, dim);
+        tridiagonal_matrix(&self.alpha, &self.beta)
+    }
+}
+
+/// Symmetric tridiagonal matrix with diagonal `alpha` and off-diagonal `beta`.
+pub(crate) fn tridiagonal_matrix(alpha: &[f64], beta: &[f64]) -> DMatrix<f64> {
+    let dim = alpha.len();
+    let mut matrix = DMatrix::<f64>::zeros(dim, dim);
+    for i in 0..dim {
+        matrix[(i, i)] = alpha[i];
+    }
+    for i in 0..(dim.saturating_sub(1)) {
+        let value = beta[i];
+        matrix[(i, i + 1)] = value;
+        matrix[(i + 1, i)] = value;
+    }
+    matrix
+}
+
+/// Coefficients of e^{-iTt}·e₁ in the Lanczos basis, for T = tridiag(alpha, beta).
+pub(crate) fn tridiagonal_evolution(alpha: &[f64], beta: &[f64], time: f64) -> Vec<Complex64> {
+    let dim = alpha.len();
+    let eigen = SymmetricEigen::new(tridiagonal_matrix(alpha, beta));
+    let eigenvalues = eigen.eigenvalues;
+    let eigenvectors = eigen.eigenvectors;
+
+    let mut e1 = DVector::<f64>::zeros(dim);
+    e1[0] = 1.0;
+    let coefficients = eigenvectors.transpose() * e1;
+
+    let mut rotated = vec![Complex64::new(0.0, 0.0); dim];
+    for k in 0..dim {
+        let phase = Complex64::from_polar(1.0, -eigenvalues[k] * time);
+        let weight = Complex64::new(coefficients[k], 0.0) * phase;
         for i in 0..dim {
-            matrix[(i, i)] = self.alpha[i];
+            rotated[i] += weight * eigenvectors[(i, k)];
         }
-        for i in 0..(dim.saturating_sub(1)) {
-            let value = self.beta[i];
-            matrix[(i, i + 1)] = value;
-            matrix[(i + 1, i)] = value;
-        }
-        matrix
     }
+    rotated
 }
 
 /// Krylov projection container storing Lanczos data and initial coefficients.
@@ -109,24 +136,7 @@ pub fn krylov_projection(
 
 impl KrylovProjection {
     pub fn evolve(&self, time: f64) -> KrylovEvolution {
-        let dim = self.lanczos.dimension();
-        let tridiagonal = self.lanczos.tridiagonal();
-        let eigen = SymmetricEigen::new(tridiagonal);
-        let eigenvalues = eigen.eigenvalues;
-        let eigenvectors = eigen.eigenvectors;
-
-        let mut e1 = DVector::<f64>::zeros(dim);
-        e1[0] = 1.0;
-        let coefficients = eigenvectors.transpose() * e1;
-
-        let mut rotated = vec![Complex64::new(0.0, 0.0); dim];
-        for k in 0..dim {
-            let phase = Complex64::from_polar(1.0, -eigenvalues[k] * time);
-            let weight = Complex64::new(coefficients[k], 0.0) * phase;
-            for i in 0..dim {
-                rotated[i] += weight * eigenvectors[(i, k)];
-            }
-        }
+        let rotated = tridiagonal_evolution(&self.lanczos.alpha, &self.lanczos.beta, time);
 
         let mut vector = StateVector::zeros();
         for (coeff, basis_vector) in rotated.iter().zip(self.lanczos.basis.iter()) {
@@ -2,13 +2,22 @@
 
 pub mod analysis;
 pub mod continuous;
+pub mod discrete;
 pub mod krylov;
+pub mod open;
 pub mod scattering;
+pub mod sparse;
 
 pub use analysis::{
-    BenchmarkMetadata, ClassicalHittingMatrix, HittingTimeBenchmark, MixingTimeResult,
-    QuantumHittingResult, QuantumWalkBenchmarkSuite, QuantumWalkBenchmarker,
+    BenchmarkMetadata, ClassicalHittingMatrix, DistributionMetric, HittingTimeBenchmark,
+    MixingTimeResult, QuantumHittingResult, QuantumWalkBenchmarkSuite, QuantumWalkBenchmarker,
+    exact_hitting_time, mixing_time,
 };
-pub use continuous::{ContinuousTimeQuantumWalk, SpectralPropagator};
+pub use continuous::{
+    ContinuousTimeQuantumWalk, PropagatorKind, SpectralPropagator, TrotterOrder, TrotterPropagator,
+};
+pub use discrete::{CoinType, CoinedQuantumWalk, CoinedWalkState};
 pub use krylov::{KrylovEvolution, KrylovProjection, LanczosResult};
+pub use open::LindbladWalk;
 pub use scattering::{DensityOfStates, ScatteringAnalysis, ScatteringChannel};
+pub use sparse::{SparseHamiltonian, SparseHamiltonianError};
@@ -7,12 +7,56 @@ use crate::hamiltonian::MetatronHamiltonian;
 use crate::quantum::operator::OperatorMatrix;
 use crate::quantum::state::{METATRON_DIMENSION, StateVector};
 
+/// Number of broadening widths the smoothed DOS window extends past the
+/// outermost eigenvalues, so their tails are not truncated.
+const DOS_WINDOW_PADDING: f64 = 5.0;
+
 /// Gaussian-smoothed density of states around an energy level.
 #[derive(Clone, Copy, Debug, Serialize)]
 pub struct DensityOfStates {
     pub energy: f64,
     pub sigma: f64,
     pub value: f64,
+    /// Spectrum the density was evaluated from.
+    pub eigenvalues: [f64; METATRON_DIMENSION],
+}
+
+impl DensityOfStates {
+    /// Gaussian-broadened DOS sampled on `num_bins` evenly spaced energies.
+    ///
+    /// The window spans the spectrum padded by five `sigma` on each side, so
+    /// every broadened peak decays fully inside it and the curve integrates to
+    /// the number of states. Returns `(energy, density)` pairs in ascending
+    /// energy order; a single bin is placed at the window centre.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `sigma` is not positive.
+    pub fn smoothed(&self, sigma: f64, num_bins: usize) -> Vec<(f64, f64)> {
+        assert!(sigma > 0.0, "DOS broadening width must be positive");
+
+        let (min, max) = self
+            .eigenvalues
+            .iter()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), &e| {
+                (lo.min(e), hi.max(e))
+            });
+        let start = min - DOS_WINDOW_PADDING * sigma;
+        let end = max + DOS_WINDOW_PADDING * sigma;
+
+        if num_bins == 1 {
+            let centre = 0.5 * (start + end);
+            return vec![(centre, gaussian_density(&self.eigenvalues, centre, sigma))];
+        }
+
+        let step = (end - start) / (num_bins.saturating_sub(1)) as f64;
+        (0..num_bins)
+            .map(|i| {
+                let energy = start + step * i as f64;
+                (energy, gaussian_density(&self.eigenvalues, energy, sigma))
+            })
+            .collect()
+    }
 }
 
 /// Coupling channel utilised in the scattering-matrix construction.
@@ -48,21 +92,26 @@ impl ScatteringAnalysis {
     }
 }
 
-fn density_of_states(
-    eigenvalues: &[f64; METATRON_DIMENSION],
-    energy: f64,
-    sigma: f64,
-) -> DensityOfStates {
+fn gaussian_density(eigenvalues: &[f64; METATRON_DIMENSION], energy: f64, sigma: f64) -> f64 {
     let norm = 1.0 / (sigma * (2.0 * PI).sqrt());
     let mut value = 0.0;
     for &lambda in eigenvalues.iter() {
         let diff = energy - lambda;
         value += norm * (-0.5 * (diff / sigma).powi(2)).exp();
     }
+    value
+}
+
+fn density_of_states(
+    eigenvalues: &[f64; METATRON_DIMENSION],
+    energy: f64,
+    sigma: f64,
+) -> DensityOfStates {
     DensityOfStates {
         energy,
         sigma,
-        value,
+        value: gaussian_density(eigenvalues, energy, sigma),
+        eigenvalues: *eigenvalues,
     }
 }
 
@@ -125,4 +174,23 @@ mod tests {
         let trace = analysis.matrix.trace();
         assert!(trace.im.abs() <= 13.0);
     }
+
+    #[test]
+    fn smoothed_dos_integrates_to_state_count() {
+        let params = QSOParameters::default();
+        let graph = MetatronGraph::new();
+        let hamiltonian = MetatronHamiltonian::new(&graph, &params);
+        let analysis = scattering_matrix(&hamiltonian, &[], 0.0, 0.05, 0.1);
+
+        let curve = analysis.density.smoothed(0.2, 2001);
+        assert_eq!(curve.len(), 2001);
+        assert!(curve.windows(2).all(|pair| pair[0].0 < pair[1].0));
+
+        let integral: f64 = curve
+            .windows(2)
+            .map(|pair| 0.5 * (pair[0].1 + pair[1].1) * (pair[1].0 - pair[0].0))
+            .sum();
+        assert!((integral - METATRON_DIMENSION as f64).abs() < 1e-3);
+        assert!(curve[0].1 < 1e-4 && curve[curve.len() - 1].1 < 1e-4);
+    }
 }
@@ -6,6 +6,8 @@
 //! - Node centrality/ranking via quantum walk statistics
 //! - Anomaly detection in graph structure
 //! - Connectivity analysis and resilience metrics
+//! - Localization diagnostics (return probability, Loschmidt echo)
+//! - Classical random walk baseline for quantum-vs-classical comparisons
 //!
 //! ## Use Cases
 //! - Social network analysis (influence ranking)
@@ -15,8 +17,11 @@
 use crate::graph::metatron::MetatronGraph;
 use crate::hamiltonian::MetatronHamiltonian;
 use crate::params::QSOParameters;
-use crate::quantum::state::QuantumState;
+use crate::quantum::state::{METATRON_DIMENSION, QuantumState};
+use crate::quantum_walk::analysis::DistributionMetric;
 use crate::quantum_walk::continuous::ContinuousTimeQuantumWalk;
+use nalgebra::{SVector, SymmetricEigen};
+use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 
 /// Parameters for quantum walk toolkit operations
@@ -40,10 +45,37 @@ impl Default for QuantumWalkParams {
     }
 }
 
+impl QuantumWalkParams {
+    /// Number of `dt` steps needed to reach `t_max`
+    ///
+    /// # Panics
+    /// If `dt` is not a positive finite number or `t_max` is negative or
+    /// not finite, since the step count would be unbounded.
+    fn num_steps(&self) -> usize {
+        assert!(
+            self.dt > 0.0 && self.dt.is_finite(),
+            "dt must be positive and finite, got {}",
+            self.dt
+        );
+        assert!(
+            self.t_max >= 0.0 && self.t_max.is_finite(),
+            "t_max must be non-negative and finite, got {}",
+            self.t_max
+        );
+        (self.t_max / self.dt).ceil() as usize
+    }
+}
+
 /// Connectivity metrics from quantum walk analysis
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ConnectivityMetrics {
-    /// Mixing time (time to reach near-uniform distribution)
+    /// First sampled time (a multiple of `dt`) at which no node deviates from
+    /// the uniform probability 1/13 by 0.1 or more, i.e. the
+    /// [`DistributionMetric::MaxDeviation`] distance to uniform is below 0.1;
+    /// `t_max` if that never happens. [`mixing_time`] computes the same
+    /// crossing for a single source with any metric and threshold.
+    ///
+    /// [`mixing_time`]: crate::quantum_walk::analysis::mixing_time
     pub mixing_time: f64,
     /// Average hitting probabilities from source nodes
     pub hitting_probabilities: Vec<f64>,
@@ -53,6 +85,47 @@ pub struct ConnectivityMetrics {
     pub effective_diameter: f64,
 }
 
+/// Centrality aggregated over an ensemble of source configurations
+///
+/// Returned by [`ensemble_centrality`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnsembleCentrality {
+    /// Time-averaged node distribution of the walk from each source set,
+    /// in input order
+    pub per_source_set: Vec<Vec<f64>>,
+    /// Mean of `per_source_set`, rescaled so the largest score is 1
+    pub centrality: Vec<f64>,
+}
+
+/// Explanation of one node's anomaly
+///
+/// Returned by [`quantum_walk_anomaly_detail`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnomalyDetail {
+    /// Node index
+    pub node: usize,
+    /// Time-averaged change of the node's return probability, current minus
+    /// base (positive = walkers now stay on the node longer)
+    pub probability_shift: f64,
+    /// Sampled time range `(start, end)` around the largest change in return
+    /// probability, covering the contiguous samples with at least half that
+    /// change; `None` if the return probability is unchanged
+    pub time_window: Option<(f64, f64)>,
+    /// Incident edges that were added, removed or reweighted
+    pub changed_edges: Vec<EdgeChange>,
+}
+
+/// Change of a single edge between the base and current graph
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EdgeChange {
+    /// Node at the other end of the edge
+    pub neighbor: usize,
+    /// Edge weight in the base graph (`None` if absent)
+    pub base_weight: Option<f64>,
+    /// Edge weight in the current graph (`None` if absent)
+    pub current_weight: Option<f64>,
+}
+
 /// Compute quantum walk centrality for each node
 ///
 /// Returns a centrality score for each node based on quantum walk dynamics.
@@ -82,7 +155,7 @@ pub fn quantum_walk_centrality(graph: &MetatronGraph, params: &QuantumWalkParams
         let initial_state = QuantumState::basis_state(start_node).unwrap();
 
         // Sample at different times and accumulate probabilities
-        let num_steps = (params.t_max / params.dt).ceil() as usize;
+        let num_steps = params.num_steps();
         for step in 1..=num_steps {
             let t = (step as f64) * params.dt;
             let evolved = qw.evolve(&initial_state, t);
@@ -96,7 +169,7 @@ pub fn quantum_walk_centrality(graph: &MetatronGraph, params: &QuantumWalkParams
     }
 
     // Normalize by number of steps and nodes
-    let norm_factor = (n * ((params.t_max / params.dt).ceil() as usize)) as f64;
+    let norm_factor = (n * params.num_steps()) as f64;
     for score in &mut centrality {
         *score /= norm_factor;
     }
@@ -112,6 +185,75 @@ pub fn quantum_walk_centrality(graph: &MetatronGraph, params: &QuantumWalkParams
     centrality
 }
 
+/// Evaluate quantum walk centrality over many source configurations at once
+///
+/// Each source set starts a walk in the equal superposition of its nodes
+/// (as in [`quantum_walk_connectivity`]); its distribution is averaged over
+/// the times dt, 2·dt, … used by [`quantum_walk_centrality`]. The Hamiltonian
+/// is diagonalised once and the source sets are evolved in parallel with
+/// rayon through its spectral propagator, so a sweep over all 13 single-node
+/// sets reproduces [`quantum_walk_centrality`] at a fraction of the cost
+/// (see the `ensemble_centrality` benchmark).
+///
+/// # Returns
+/// Per-set distributions and their aggregate; the aggregate is all zeros if
+/// `source_sets` is empty
+///
+/// # Panics
+/// Panics if a source set is empty or names a node outside the graph.
+pub fn ensemble_centrality(
+    graph: &MetatronGraph,
+    source_sets: &[Vec<usize>],
+    params: &QuantumWalkParams,
+) -> EnsembleCentrality {
+    let n = graph.nodes().len();
+    let qso_params = QSOParameters::default();
+    let hamiltonian = MetatronHamiltonian::new(graph, &qso_params);
+    let qw = ContinuousTimeQuantumWalk::new(&hamiltonian);
+    let num_steps = params.num_steps();
+
+    let per_source_set: Vec<Vec<f64>> = source_sets
+        .par_iter()
+        .map(|sources| {
+            assert!(!sources.is_empty(), "source set must not be empty");
+            let mut amplitudes = vec![num_complex::Complex64::new(0.0, 0.0); n];
+            let amplitude = num_complex::Complex64::new(1.0 / (sources.len() as f64).sqrt(), 0.0);
+            for &node in sources {
+                amplitudes[node] = amplitude;
+            }
+            let initial_state = QuantumState::from_amplitudes(amplitudes).unwrap();
+            let propagator = qw.propagator(&initial_state);
+
+            let mut visitation = vec![0.0; n];
+            for step in 1..=num_steps {
+                let t = (step as f64) * params.dt;
+                for (total, prob) in visitation.iter_mut().zip(propagator.probabilities_at(t)) {
+                    *total += prob / num_steps as f64;
+                }
+            }
+            visitation
+        })
+        .collect();
+
+    let mut centrality = vec![0.0; n];
+    for visitation in &per_source_set {
+        for (score, prob) in centrality.iter_mut().zip(visitation) {
+            *score += prob / per_source_set.len() as f64;
+        }
+    }
+    let max_score = centrality.iter().cloned().fold(0.0, f64::max);
+    if max_score > 0.0 {
+        for score in &mut centrality {
+            *score /= max_score;
+        }
+    }
+
+    EnsembleCentrality {
+        per_source_set,
+        centrality,
+    }
+}
+
 /// Compute anomaly scores by comparing base graph to current graph
 ///
 /// Detects structural changes between a baseline graph and current graph
@@ -145,6 +287,60 @@ pub fn quantum_walk_anomaly_score(
         .collect()
 }
 
+/// Explain anomalies by comparing base graph to current graph
+///
+/// Complements [`quantum_walk_anomaly_score`], which ranks nodes, with the
+/// reasons behind a change: how the [`return_probability`] of a walk started
+/// at the node moved, when during the walk the change shows up, and which
+/// incident edges differ between the graphs. The return probability is used
+/// because it reacts to local rewiring; visitation averaged over all start
+/// nodes stays at 1/13 for any graph, since the walk is unitary.
+///
+/// # Arguments
+/// * `base_graph` - Baseline/reference graph
+/// * `current_graph` - Current graph to analyze
+/// * `params` - Quantum walk parameters
+///
+/// # Returns
+/// One detail per node, in node order
+pub fn quantum_walk_anomaly_detail(
+    base_graph: &MetatronGraph,
+    current_graph: &MetatronGraph,
+    params: &QuantumWalkParams,
+) -> Vec<AnomalyDetail> {
+    (0..METATRON_DIMENSION)
+        .map(|node| {
+            let base = return_probability(base_graph, node, params);
+            let current = return_probability(current_graph, node, params);
+            let shifts: Vec<f64> = base
+                .iter()
+                .zip(&current)
+                .map(|((_, base_p), (_, current_p))| current_p - base_p)
+                .collect();
+            let changed_edges = (0..METATRON_DIMENSION)
+                .filter(|&neighbor| neighbor != node)
+                .filter_map(|neighbor| {
+                    let base_weight = base_graph.edge_weight(node, neighbor);
+                    let current_weight = current_graph.edge_weight(node, neighbor);
+                    (base_weight != current_weight).then_some(EdgeChange {
+                        neighbor,
+                        base_weight,
+                        current_weight,
+                    })
+                })
+                .collect();
+
+            AnomalyDetail {
+                node,
+                probability_shift: shifts.iter().sum::<f64>() / shifts.len() as f64,
+                time_window: peak_window(&shifts)
+                    .map(|(first, last)| (base[first].0, base[last].0)),
+                changed_edges,
+            }
+        })
+        .collect()
+}
+
 /// Analyze connectivity using quantum walks from source nodes
 ///
 /// Computes various connectivity metrics based on quantum walk dynamics
@@ -176,10 +372,10 @@ pub fn quantum_walk_connectivity(
     let initial_state = QuantumState::from_amplitudes(amplitudes).unwrap();
 
     // Evolve and track metrics
-    let num_steps = (params.t_max / params.dt).ceil() as usize;
+    let num_steps = params.num_steps();
     let mut mixing_time = params.t_max;
     let mut final_probs = vec![0.0; n];
-    let uniform_prob = 1.0 / n as f64;
+    let uniform = vec![1.0 / n as f64; n];
     let mixing_threshold = 0.1; // 10% deviation from uniform
 
     for step in 1..=num_steps {
@@ -188,10 +384,7 @@ pub fn quantum_walk_connectivity(
         let probs = evolved.probabilities();
 
         // Check if mixed (close to uniform distribution)
-        let max_deviation = probs
-            .iter()
-            .map(|&p| (p - uniform_prob).abs())
-            .fold(0.0, f64::max);
+        let max_deviation = DistributionMetric::MaxDeviation.distance(&probs, &uniform);
 
         if max_deviation < mixing_threshold && mixing_time == params.t_max {
             mixing_time = t;
@@ -221,10 +414,169 @@ pub fn quantum_walk_connectivity(
     }
 }
 
+/// Return probability |⟨ψ₀|ψ(t)⟩|² of a walk started at `source`
+///
+/// Samples t = 0, dt, 2·dt, … up to `t_max` (the last sample is clamped to
+/// `t_max`). Slow decay or strong revivals indicate localization around the
+/// source node.
+///
+/// # Arguments
+/// * `graph` - The graph to analyze
+/// * `source` - Starting node of the walk
+/// * `params` - Quantum walk parameters (`t_max`, `dt`)
+///
+/// # Returns
+/// `(time, probability)` pairs, starting with `(0.0, 1.0)`
+///
+/// # Panics
+/// Panics if `source` is not a node of the graph.
+pub fn return_probability(
+    graph: &MetatronGraph,
+    source: usize,
+    params: &QuantumWalkParams,
+) -> Vec<(f64, f64)> {
+    let qso_params = QSOParameters::default();
+    let hamiltonian = MetatronHamiltonian::new(graph, &qso_params);
+    let initial_state = QuantumState::basis_state(source).unwrap();
+    let propagator = ContinuousTimeQuantumWalk::new(&hamiltonian).propagator(&initial_state);
+
+    sample_times(params)
+        .map(|t| {
+            let overlap = initial_state.inner_product(&propagator.state_at(t));
+            (t, overlap.norm_sqr())
+        })
+        .collect()
+}
+
+/// Loschmidt echo |⟨ψ₀|e^{iH'·t} e^{-iH·t}|ψ₀⟩|² between two Hamiltonians
+///
+/// Measures how quickly evolutions under `hamiltonian` and `perturbed` from
+/// the same basis state `source` diverge. Perturbations can come from the
+/// graph (e.g. a removed edge) or from the parameters (on-site potentials).
+/// Sampling times follow [`return_probability`].
+///
+/// # Returns
+/// `(time, echo)` pairs, starting with `(0.0, 1.0)`
+///
+/// # Panics
+/// Panics if `source` is out of range.
+pub fn loschmidt_echo(
+    hamiltonian: &MetatronHamiltonian,
+    perturbed: &MetatronHamiltonian,
+    source: usize,
+    params: &QuantumWalkParams,
+) -> Vec<(f64, f64)> {
+    let initial_state = QuantumState::basis_state(source).unwrap();
+    let forward = ContinuousTimeQuantumWalk::new(hamiltonian).propagator(&initial_state);
+    let backward = ContinuousTimeQuantumWalk::new(perturbed).propagator(&initial_state);
+
+    sample_times(params)
+        .map(|t| {
+            let overlap = backward.state_at(t).inner_product(&forward.state_at(t));
+            (t, overlap.norm_sqr())
+        })
+        .collect()
+}
+
+/// Node distribution of a quantum walk started at `source`
+///
+/// Sampling times follow [`return_probability`]; [`classical_walk`] returns
+/// the classical counterpart in the same shape.
+///
+/// # Returns
+/// `(time, distribution)` pairs, starting with the point mass on `source`
+///
+/// # Panics
+/// Panics if `source` is not a node of the graph.
+pub fn quantum_walk_distribution(
+    graph: &MetatronGraph,
+    source: usize,
+    params: &QuantumWalkParams,
+) -> Vec<(f64, Vec<f64>)> {
+    let qso_params = QSOParameters::default();
+    let hamiltonian = MetatronHamiltonian::new(graph, &qso_params);
+    let initial_state = QuantumState::basis_state(source).unwrap();
+    let propagator = ContinuousTimeQuantumWalk::new(&hamiltonian).propagator(&initial_state);
+
+    sample_times(params)
+        .map(|t| (t, propagator.probabilities_at(t).to_vec()))
+        .collect()
+}
+
+/// Continuous-time classical random walk started at `source`
+///
+/// The distribution is the heat kernel p(t) = e^{-tL} δ_source of the
+/// (weighted) graph Laplacian, i.e. every edge is hopped at a rate equal to
+/// its weight, matching the coupling of the quantum walk Hamiltonian. On a
+/// connected graph it relaxes to the uniform distribution. Sampling times
+/// and output shape follow [`quantum_walk_distribution`].
+///
+/// # Panics
+/// Panics if `source` is not a node of the graph.
+pub fn classical_walk(
+    graph: &MetatronGraph,
+    source: usize,
+    params: &QuantumWalkParams,
+) -> Vec<(f64, Vec<f64>)> {
+    assert!(source < METATRON_DIMENSION, "source node out of range");
+    let eigen = SymmetricEigen::new(graph.laplacian_matrix());
+    // Overlap of each Laplacian eigenvector with δ_source
+    let overlaps: SVector<f64, METATRON_DIMENSION> = eigen.eigenvectors.row(source).transpose();
+
+    sample_times(params)
+        .map(|t| {
+            let decayed = overlaps.zip_map(&eigen.eigenvalues, |c, lambda| c * (-lambda * t).exp());
+            let distribution = eigen.eigenvectors * decayed;
+            (t, distribution.iter().map(|p| p.max(0.0)).collect())
+        })
+        .collect()
+}
+
+/// Index range of the contiguous run around the largest `|shift|` where the
+/// shift stays at or above half its peak; `None` if all shifts vanish
+fn peak_window(shifts: &[f64]) -> Option<(usize, usize)> {
+    let (peak, peak_shift) = shifts
+        .iter()
+        .map(|shift| shift.abs())
+        .enumerate()
+        .max_by(|a, b| a.1.total_cmp(&b.1))?;
+    if peak_shift < 1e-12 {
+        return None;
+    }
+    let significant = |i: &usize| shifts[*i].abs() >= 0.5 * peak_shift;
+    let first = (0..peak)
+        .rev()
+        .take_while(significant)
+        .last()
+        .unwrap_or(peak);
+    let last = (peak + 1..shifts.len())
+        .take_while(significant)
+        .last()
+        .unwrap_or(peak);
+    Some((first, last))
+}
+
+/// Sampling times 0, dt, 2·dt, … clamped to `t_max`
+fn sample_times(params: &QuantumWalkParams) -> impl Iterator<Item = f64> + '_ {
+    let num_steps = params.num_steps();
+    (0..=num_steps).map(move |step| ((step as f64) * params.dt).min(params.t_max))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    #[should_panic(expected = "dt must be positive and finite")]
+    fn test_non_positive_dt_is_rejected() {
+        let params = QuantumWalkParams {
+            t_max: 5.0,
+            dt: 0.0,
+            samples: 16,
+        };
+        return_probability(&MetatronGraph::new(), 0, &params);
+    }
+
     #[test]
     fn test_quantum_walk_centrality() {
         let graph = MetatronGraph::new();
@@ -248,6 +600,68 @@ mod tests {
         assert!(centrality[0] > 0.5);
     }
 
+    #[test]
+    fn test_ensemble_centrality_matches_single_source_sweep() {
+        let graph = MetatronGraph::new();
+        let params = QuantumWalkParams {
+            t_max: 3.0,
+            dt: 0.25,
+            samples: 1,
+        };
+
+        let singles: Vec<Vec<usize>> = (0..13).map(|node| vec![node]).collect();
+        let ensemble = ensemble_centrality(&graph, &singles, &params);
+        let serial = quantum_walk_centrality(&graph, &params);
+        for (a, b) in ensemble.centrality.iter().zip(&serial) {
+            assert!((a - b).abs() < 1e-9);
+        }
+
+        // Per-set results follow the input order and are distributions
+        let mixed = ensemble_centrality(&graph, &[vec![0], vec![1, 2, 3]], &params);
+        assert_eq!(mixed.per_source_set.len(), 2);
+        assert_eq!(mixed.per_source_set[0], ensemble.per_source_set[0]);
+        for visitation in &mixed.per_source_set {
+            assert!((visitation.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_anomaly_detail_explains_removed_edge() {
+        let base = MetatronGraph::new();
+        let edges: Vec<_> = base
+            .weighted_edges()
+            .filter(|&(u, v, _)| (u, v) != (0, 1))
+            .collect();
+        let current = MetatronGraph::from_weighted_edges(&edges).unwrap();
+        let params = QuantumWalkParams {
+            t_max: 2.0,
+            dt: 0.05,
+            samples: 1,
+        };
+
+        let details = quantum_walk_anomaly_detail(&base, &current, &params);
+        assert_eq!(details.len(), 13);
+
+        // Only the endpoints of the removed edge list a changed edge
+        assert_eq!(
+            details[0].changed_edges,
+            vec![EdgeChange {
+                neighbor: 1,
+                base_weight: Some(1.0),
+                current_weight: None,
+            }]
+        );
+        assert_eq!(details[1].changed_edges[0].neighbor, 0);
+        assert!(details[2..].iter().all(|d| d.changed_edges.is_empty()));
+
+        // The endpoint's return probability moves within the sampled range
+        assert!(details[1].probability_shift.abs() > 1e-6);
+        let (start, end) = details[1].time_window.unwrap();
+        assert!(0.0 < start && start <= end && end <= params.t_max);
+        let unchanged = quantum_walk_anomaly_detail(&base, &base, &params);
+        assert!(unchanged.iter().all(|d| d.time_window.is_none()));
+    }
+
     #[test]
     fn test_quantum_walk_connectivity() {
         let graph = MetatronGraph::new();
@@ -270,4 +684,70 @@ mod tests {
         assert!(metrics.distribution_variance.is_finite());
         assert!(metrics.distribution_variance >= 0.0);
     }
+
+    #[test]
+    fn test_classical_and_quantum_walks_settle() {
+        let graph = MetatronGraph::new();
+        let params = QuantumWalkParams {
+            t_max: 200.0,
+            dt: 0.05,
+            samples: 1,
+        };
+        let classical = classical_walk(&graph, 1, &params);
+        let quantum = quantum_walk_distribution(&graph, 1, &params);
+        assert_eq!(classical.len(), quantum.len());
+        assert!((classical[0].1[1] - 1.0).abs() < 1e-12);
+        assert!((quantum[0].1[1] - 1.0).abs() < 1e-12);
+
+        // The classical walk relaxes to the uniform distribution
+        let (_, last) = classical.last().unwrap();
+        assert!(last.iter().all(|p| (p - 1.0 / 13.0).abs() < 1e-9));
+
+        // The quantum walk keeps oscillating, but its running average settles:
+        // the averages over the two halves of the window agree
+        let half = quantum.len() / 2;
+        let mean = |samples: &[(f64, Vec<f64>)]| -> Vec<f64> {
+            (0..13)
+                .map(|i| samples.iter().map(|(_, p)| p[i]).sum::<f64>() / samples.len() as f64)
+                .collect()
+        };
+        let (early, late) = (mean(&quantum[..half]), mean(&quantum[half..]));
+        let drift: f64 = early.iter().zip(&late).map(|(a, b)| (a - b).abs()).sum();
+        assert!(
+            drift < 0.02,
+            "time-averaged distribution drifted by {drift}"
+        );
+    }
+
+    #[test]
+    fn test_return_probability_and_loschmidt_echo() {
+        let graph = MetatronGraph::new();
+        let params = QuantumWalkParams {
+            t_max: 1.0,
+            dt: 0.25,
+            samples: 1,
+        };
+
+        let series = return_probability(&graph, 0, &params);
+        assert_eq!(series.len(), 5);
+        assert_eq!(series[0].0, 0.0);
+        assert!((series[4].0 - 1.0).abs() < 1e-12);
+
+        // K13: ⟨0|e^{-iHt}|0⟩ = (1 + 12·e^{13it}) / 13 with H = -L
+        for &(t, p) in &series {
+            let amplitude = (num_complex::Complex64::new(0.0, 13.0 * t).exp() * 12.0 + 1.0) / 13.0;
+            assert!((p - amplitude.norm_sqr()).abs() < 1e-9);
+        }
+
+        let qso_params = QSOParameters::default();
+        let hamiltonian = MetatronHamiltonian::new(&graph, &qso_params);
+        let identical = loschmidt_echo(&hamiltonian, &hamiltonian, 3, &params);
+        assert!(identical.iter().all(|&(_, echo)| (echo - 1.0).abs() < 1e-9));
+
+        let mut perturbed_params = QSOParameters::default();
+        perturbed_params.epsilon[3] = 2.0;
+        let perturbed = MetatronHamiltonian::new(&graph, &perturbed_params);
+        let echo = loschmidt_echo(&hamiltonian, &perturbed, 3, &params);
+        assert!(echo[4].1 < 1.0 - 1e-3);
+    }
 }
@@ -7,21 +7,68 @@
 //!
 //! Symmetry-protected codes exploit the fact that errors which commute
 //! with the symmetry group can be detected and corrected. For the Metatron
-//! Cube, the automorphism group G_M provides natural error syndrome detection.
+//! Cube, the automorphism group G_M provides natural error detection.
 //!
 //! ## Key Concepts
 //!
 //! - **Logical Qubits**: Encoded in symmetric subspaces of the 13-dimensional Hilbert space
 //! - **Stabilizers**: Constructed from group elements of G_M
 //! - **Code Distance**: Determined by minimum weight of logical operators
-//! - **Error Detection**: Symmetry-breaking errors produce detectable syndromes
+//! - **Error Detection**: Symmetry-breaking errors leave the code space
+//!
+//! ## Encoding
+//!
+//! The physical space is one 13-level system, one level per node. States
+//! invariant under every stabilizer are exactly the superpositions of
+//! uniform orbit states |O⟩ = |O|^{-1/2} Σ_{i∈O} |i⟩, where O ranges over the
+//! node orbits of the stabilizer group. Logical basis state |j⟩ is mapped to
+//! the j-th largest orbit; for one logical qubit these are the hexagon and
+//! cube shells. Logical states are passed around as [`QuantumState`]s whose
+//! amplitudes live on the first 2^k levels.
+//!
+//! The centre node forms an orbit of its own that the code leaves empty.
+//!
+//! ## Error Correction
+//!
+//! The correctable errors are single-node flips: a phase flip Zᵢ negates the
+//! amplitude of node i, and a bit flip Xᵢ swaps node i with the empty centre
+//! level. Either one breaks the orbit symmetry of a codeword (or populates
+//! the centre). [`MetatronCode::diagnose_error`] identifies the node by
+//! trying every candidate flip on the simulated state vector and checking
+//! which one restores a codeword ([`MetatronCode::is_codeword`]). This is a
+//! classical diagnosis with full access to the amplitudes, not a stabilizer
+//! syndrome measurement, so it does not disturb the state.
+//!
+//! The stabilizer eigenvalues alone cannot locate a flip: the stabilizers
+//! permute nodes within an orbit, so a flip on several different nodes
+//! violates the same set of them. The older `measure_syndrome` /
+//! `correct_errors` pair, which reads a node index off those bits, is kept
+//! only for compatibility and is deprecated.
+//!
+//! The weight of an operator is the number of nodes it acts on. Any operator
+//! that leaves at least one node of every logical orbit untouched acts
+//! trivially on the code space, so the distance is the size of the smallest
+//! logical orbit (six for one logical qubit).
 
 use crate::graph::metatron::MetatronGraph;
 use crate::quantum::operator::QuantumOperator;
-use crate::quantum::state::{METATRON_DIMENSION, QuantumState};
+use crate::quantum::state::{METATRON_DIMENSION, QuantumState, StateVector};
 use nalgebra::SMatrix;
 use num_complex::Complex64 as Complex;
 
+/// Single-node error found by [`MetatronCode::diagnose_error`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorDiagnosis {
+    /// The state is a codeword
+    NoError,
+    /// Phase flip Z on the given node
+    PhaseFlip { node: usize },
+    /// Bit flip X on the given node (amplitude swapped into the centre)
+    BitFlip { node: usize },
+    /// The state is outside the code space, but no single-node flip explains it
+    Uncorrectable,
+}
+
 /// A symmetry-protected quantum code based on Metatron geometry
 #[derive(Clone, Debug)]
 pub struct MetatronCode {
@@ -33,6 +80,8 @@ pub struct MetatronCode {
     stabilizers: Vec<QuantumOperator>,
     /// Logical operators (X and Z for each logical qubit)
     _logical_operators: Vec<(QuantumOperator, QuantumOperator)>,
+    /// Node orbits spanning the code space, one per logical basis state
+    logical_orbits: Vec<Vec<usize>>,
     /// Number of logical qubits encoded
     k_logical: usize,
     /// Code distance (minimum weight of non-trivial logical operator)
@@ -50,12 +99,30 @@ impl MetatronCode {
     /// - 13 physical qubits (Metatron nodes)
     /// - k logical qubits
     /// - d minimum distance
+    ///
+    /// # Panics
+    ///
+    /// Panics if the stabilizer group has fewer than 2^k node orbits, i.e.
+    /// the code space is too small for `k_logical` qubits.
     pub fn new(k_logical: usize) -> Self {
         let graph = MetatronGraph::new();
         let automorphisms = graph.enumerate_automorphisms();
 
         println!("Metatron Code: Found {} automorphisms", automorphisms.len());
 
+        let mut orbits = Self::stabilizer_orbits(
+            &automorphisms[..automorphisms
+                .len()
+                .min(METATRON_DIMENSION.saturating_sub(k_logical))],
+        );
+        assert!(
+            k_logical < usize::BITS as usize && 1 << k_logical <= orbits.len(),
+            "stabilizer group has {} orbits, too few for {} logical qubits",
+            orbits.len(),
+            k_logical
+        );
+        orbits.truncate(1 << k_logical);
+
         // Generate stabilizers from automorphism group
         let stabilizers = Self::generate_stabilizers(&graph, &automorphisms, k_logical);
 
@@ -63,13 +130,14 @@ impl MetatronCode {
         let logical_operators = Self::generate_logical_operators(&graph, k_logical);
 
         // Compute code distance
-        let distance = Self::compute_code_distance(&logical_operators);
+        let distance = Self::compute_code_distance(&orbits);
 
         Self {
             _graph: graph,
             automorphisms,
             stabilizers,
             _logical_operators: logical_operators,
+            logical_orbits: orbits,
             k_logical,
             distance,
         }
@@ -101,6 +169,37 @@ impl MetatronCode {
         stabilizers
     }
 
+    /// Node orbits of the group generated by `permutations`, largest first
+    /// (ties broken by lowest node index).
+    fn stabilizer_orbits(permutations: &[Vec<usize>]) -> Vec<Vec<usize>> {
+        let mut visited = [false; METATRON_DIMENSION];
+        let mut orbits = Vec::new();
+
+        for start in 0..METATRON_DIMENSION {
+            if visited[start] {
+                continue;
+            }
+            visited[start] = true;
+            let mut orbit = vec![start];
+            let mut cursor = 0;
+            while cursor < orbit.len() {
+                for perm in permutations {
+                    let image = perm[orbit[cursor]];
+                    if !visited[image] {
+                        visited[image] = true;
+                        orbit.push(image);
+                    }
+                }
+                cursor += 1;
+            }
+            orbit.sort_unstable();
+            orbits.push(orbit);
+        }
+
+        orbits.sort_by(|a, b| b.len().cmp(&a.len()).then(a[0].cmp(&b[0])));
+        orbits
+    }
+
     /// Convert a permutation to a unitary operator matrix
     fn permutation_to_operator(perm: &[usize]) -> SMatrix<Complex, 13, 13> {
         let mut matrix = SMatrix::<Complex, 13, 13>::zeros();
@@ -153,23 +252,56 @@ impl MetatronCode {
     }
 
     /// Compute code distance
-    fn compute_code_distance(_logical_operators: &[(QuantumOperator, QuantumOperator)]) -> usize {
-        // Code distance is the minimum weight of non-trivial logical operators
-        // For simplicity, return a conservative estimate based on Metatron structure
-        // Actual distance computation requires checking all logical operator combinations
-
-        // With 13 nodes and strong connectivity, expect distance ≥ 3
-        3
+    ///
+    /// A non-trivial logical operator has to touch every node of at least
+    /// one logical orbit (see the module docs), and a relative phase on the
+    /// smallest orbit achieves that bound.
+    fn compute_code_distance(logical_orbits: &[Vec<usize>]) -> usize {
+        logical_orbits.iter().map(Vec::len).min().unwrap_or(0)
     }
 
     /// Encode a logical state into the code subspace
     ///
+    /// The first 2^k amplitudes of `logical` are the logical amplitudes; any
+    /// weight on higher levels is discarded before renormalising.
+    pub fn encode(&self, logical: &QuantumState) -> QuantumState {
+        let amplitudes = &logical.amplitudes().as_slice()[..self.logical_orbits.len()];
+        self.encode_amplitudes(amplitudes)
+            .expect("logical dimension matches the code")
+    }
+
+    /// Recover the logical state from a physical state
+    ///
+    /// Projects onto the code space and returns the normalised orbit
+    /// amplitudes on the first 2^k levels. Errors should be corrected
+    /// first; components outside the code space are simply dropped.
+    pub fn decode(&self, physical: &QuantumState) -> QuantumState {
+        let amplitudes = physical.amplitudes();
+        let mut logical = StateVector::zeros();
+        for (j, orbit) in self.logical_orbits.iter().enumerate() {
+            let overlap: Complex = orbit.iter().map(|&node| amplitudes[node]).sum();
+            logical[j] = overlap / (orbit.len() as f64).sqrt();
+        }
+        QuantumState::from_vector(logical, true)
+    }
+
+    /// Code distance d: the minimum number of nodes a non-trivial logical
+    /// operator acts on
+    pub fn code_distance(&self) -> usize {
+        self.distance
+    }
+
+    /// Encode raw logical amplitudes into the code subspace
+    ///
     /// # Arguments
-    /// * `logical_state` - Vector of k_logical qubit amplitudes
+    /// * `logical_amplitudes` - Vector of 2^k_logical amplitudes
     ///
     /// # Returns
-    /// Encoded 13-qubit state in the code subspace
-    pub fn encode(&self, logical_amplitudes: &[Complex]) -> Result<QuantumState, String> {
+    /// Encoded state in the 13-dimensional physical space
+    pub fn encode_amplitudes(
+        &self,
+        logical_amplitudes: &[Complex],
+    ) -> Result<QuantumState, String> {
         if logical_amplitudes.len() != (1 << self.k_logical) {
             return Err(format!(
                 "Expected {} logical amplitudes for {} qubits, got {}",
@@ -179,30 +311,75 @@ impl MetatronCode {
             ));
         }
 
-        // For a [[13, k, d]] code, encoding maps 2^k dimensional logical space
-        // to 2^k dimensional code subspace of the 13-dimensional physical space
+        // Logical |j⟩ becomes the uniform superposition over the j-th orbit,
+        // which every stabilizer maps to itself
+        let mut physical = StateVector::zeros();
+        for (orbit, &amp) in self.logical_orbits.iter().zip(logical_amplitudes) {
+            let amplitude = amp / (orbit.len() as f64).sqrt();
+            for &node in orbit {
+                physical[node] = amplitude;
+            }
+        }
+
+        Ok(QuantumState::from_vector(physical, true))
+    }
 
-        // Simplified encoding: embed logical state in symmetric subspace
-        let mut physical_amplitudes = vec![Complex::new(0.0, 0.0); METATRON_DIMENSION];
+    /// Phase flip Zᵢ: negates the amplitude of `node`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `node` is not a Metatron node index.
+    pub fn phase_flip(node: usize) -> QuantumOperator {
+        assert!(node < METATRON_DIMENSION, "node {node} out of range");
+        let mut matrix = SMatrix::<Complex, 13, 13>::identity();
+        matrix[(node, node)] = Complex::new(-1.0, 0.0);
+        QuantumOperator::from_matrix(matrix)
+    }
 
-        // Use center node and first few hexagon nodes for logical basis
-        for (idx, &amp) in logical_amplitudes.iter().enumerate() {
-            if idx < METATRON_DIMENSION {
-                physical_amplitudes[idx] = amp;
-            }
+    /// Bit flip Xᵢ: swaps `node` with the centre node
+    ///
+    /// # Panics
+    ///
+    /// Panics if `node` is not a Metatron node index.
+    pub fn bit_flip(node: usize) -> QuantumOperator {
+        assert!(node < METATRON_DIMENSION, "node {node} out of range");
+        let mut perm: Vec<usize> = (0..METATRON_DIMENSION).collect();
+        perm.swap(0, node);
+        QuantumOperator::from_matrix(Self::permutation_to_operator(&perm))
+    }
+
+    /// Find the single-node flip that corrupted a physical state
+    ///
+    /// Applies each candidate flip to the state vector and returns the one
+    /// that brings it back into the code space; both flips are their own
+    /// inverse. See the module docs for how this differs from measuring
+    /// stabilizer syndromes.
+    pub fn diagnose_error(&self, physical: &QuantumState) -> ErrorDiagnosis {
+        if self.is_codeword(physical) {
+            return ErrorDiagnosis::NoError;
         }
 
-        // Normalize
-        let norm_sq: f64 = physical_amplitudes.iter().map(|z| z.norm_sqr()).sum();
-        if norm_sq > 1e-10 {
-            let norm = norm_sq.sqrt();
-            for amp in &mut physical_amplitudes {
-                *amp /= norm;
+        for node in 1..METATRON_DIMENSION {
+            if self.is_codeword(&physical.apply(&Self::phase_flip(node))) {
+                return ErrorDiagnosis::PhaseFlip { node };
+            }
+            if self.is_codeword(&physical.apply(&Self::bit_flip(node))) {
+                return ErrorDiagnosis::BitFlip { node };
             }
         }
 
-        QuantumState::from_amplitudes(physical_amplitudes)
-            .map_err(|e| format!("Failed to create encoded state: {}", e))
+        ErrorDiagnosis::Uncorrectable
+    }
+
+    /// Undo the error identified by `diagnosis`
+    ///
+    /// States without a correctable error are returned unchanged.
+    pub fn correct(&self, physical: &QuantumState, diagnosis: ErrorDiagnosis) -> QuantumState {
+        match diagnosis {
+            ErrorDiagnosis::PhaseFlip { node } => physical.apply(&Self::phase_flip(node)),
+            ErrorDiagnosis::BitFlip { node } => physical.apply(&Self::bit_flip(node)),
+            ErrorDiagnosis::NoError | ErrorDiagnosis::Uncorrectable => physical.clone(),
+        }
     }
 
     /// Measure error syndrome by checking stabilizer eigenvalues
@@ -212,6 +389,9 @@ impl MetatronCode {
     ///
     /// # Returns
     /// Syndrome vector (one bit per stabilizer)
+    #[deprecated(
+        note = "the stabilizer bits do not locate single-node flips; use `diagnose_error`"
+    )]
     pub fn measure_syndrome(&self, state: &QuantumState) -> Vec<bool> {
         let mut syndrome = Vec::with_capacity(self.stabilizers.len());
 
@@ -236,6 +416,7 @@ impl MetatronCode {
     ///
     /// # Returns
     /// Corrected state (if correction is possible)
+    #[deprecated(note = "use `diagnose_error` followed by `correct`")]
     pub fn correct_errors(
         &self,
         state: &QuantumState,
@@ -251,11 +432,8 @@ impl MetatronCode {
 
         println!("Error detected at location: {}", error_location);
 
-        // Apply correction operator (bit flip at error location)
-        let correction_op = self.create_correction_operator(error_location);
-        let corrected_state = state.apply(&correction_op);
-
-        Ok(corrected_state)
+        // Apply correction operator (phase flip at error location)
+        Ok(state.apply(&Self::phase_flip(error_location)))
     }
 
     /// Map syndrome to most likely error location
@@ -273,18 +451,6 @@ impl MetatronCode {
         Ok(syndrome_int % METATRON_DIMENSION)
     }
 
-    /// Create operator to correct error at given location
-    fn create_correction_operator(&self, location: usize) -> QuantumOperator {
-        // Pauli X operator at error location (bit flip correction)
-        let mut matrix = SMatrix::<Complex, 13, 13>::identity();
-
-        // Swap basis states |0⟩ ↔ |1⟩ at location (simplified for graph codes)
-        // Here we just apply a phase correction
-        matrix[(location, location)] = Complex::new(-1.0, 0.0);
-
-        QuantumOperator::from_matrix(matrix)
-    }
-
     /// Get code parameters [[n, k, d]]
     pub fn parameters(&self) -> (usize, usize, usize) {
         (METATRON_DIMENSION, self.k_logical, self.distance)
@@ -335,7 +501,9 @@ mod tests {
 
         // Encode logical |0⟩ state
         let logical_zero = vec![Complex::new(1.0, 0.0), Complex::new(0.0, 0.0)];
-        let encoded = code.encode(&logical_zero).expect("Encoding failed");
+        let encoded = code
+            .encode_amplitudes(&logical_zero)
+            .expect("Encoding failed");
 
         assert!(encoded.is_normalized(1e-10));
         assert!(code.is_codeword(&encoded));
@@ -343,15 +511,42 @@ mod tests {
         println!("Encoded logical |0⟩ successfully");
     }
 
+    #[test]
+    fn test_encode_decode_round_trip() {
+        let code = MetatronCode::new(1);
+        assert_eq!(code.code_distance(), 6);
+
+        let mut amplitudes = vec![Complex::new(0.0, 0.0); METATRON_DIMENSION];
+        amplitudes[0] = Complex::new(0.6, 0.0);
+        amplitudes[1] = Complex::new(0.0, 0.8);
+        let logical = QuantumState::from_amplitudes(amplitudes).unwrap();
+
+        let physical = code.encode(&logical);
+        assert!(physical.is_normalized(1e-12));
+        assert!(code.is_codeword(&physical));
+        assert!((code.decode(&physical).fidelity(&logical) - 1.0).abs() < 1e-12);
+
+        // Logical |1⟩ is spread evenly over a six-node orbit.
+        let one = code.encode(&QuantumState::basis_state(1).unwrap());
+        let support = one.probabilities().iter().filter(|&&p| p > 1e-12).count();
+        assert_eq!(support, 6);
+    }
+
     #[test]
     fn test_error_detection() {
         let code = MetatronCode::new(1);
 
         // Encode logical state
         let logical_zero = vec![Complex::new(1.0, 0.0), Complex::new(0.0, 0.0)];
-        let encoded = code.encode(&logical_zero).expect("Encoding failed");
+        let encoded = code
+            .encode_amplitudes(&logical_zero)
+            .expect("Encoding failed");
+
+        // A clean codeword carries no error
+        assert_eq!(code.diagnose_error(&encoded), ErrorDiagnosis::NoError);
 
         // Measure syndrome (should be all-zero for clean codeword)
+        #[allow(deprecated)]
         let syndrome = code.measure_syndrome(&encoded);
         assert!(
             syndrome.iter().all(|&b| !b),
@@ -360,4 +555,42 @@ mod tests {
 
         println!("Error detection test passed");
     }
+
+    #[test]
+    fn test_single_node_errors_are_corrected() {
+        let code = MetatronCode::new(1);
+        let logical = QuantumState::from_amplitudes(
+            (0..METATRON_DIMENSION)
+                .map(|i| match i {
+                    0 => Complex::new(0.8, 0.0),
+                    1 => Complex::new(0.0, -0.6),
+                    _ => Complex::new(0.0, 0.0),
+                })
+                .collect(),
+        )
+        .unwrap();
+        let physical = code.encode(&logical);
+
+        for node in 1..METATRON_DIMENSION {
+            for (error, expected) in [
+                (
+                    MetatronCode::phase_flip(node),
+                    ErrorDiagnosis::PhaseFlip { node },
+                ),
+                (
+                    MetatronCode::bit_flip(node),
+                    ErrorDiagnosis::BitFlip { node },
+                ),
+            ] {
+                let corrupted = physical.apply(&error);
+                assert!(corrupted.fidelity(&physical) < 0.99);
+
+                let diagnosis = code.diagnose_error(&corrupted);
+                assert_eq!(diagnosis, expected);
+                let corrected = code.correct(&corrupted, diagnosis);
+                assert!((corrected.fidelity(&physical) - 1.0).abs() < 1e-12);
+                assert!((code.decode(&corrected).fidelity(&logical) - 1.0).abs() < 1e-12);
+            }
+        }
+    }
 }
@@ -4,11 +4,15 @@
 //! - Hardware-Efficient: Alternating rotations and entanglers for NISQ devices
 //! - EfficientSU2: Qiskit-inspired structure with full SU(2) rotations
 //! - Metatron: Optimized for 13-dimensional Metatron Cube structure
+//! - ParticleConserving: Givens rotations that keep the excitation number
 
 use crate::quantum::operator::{OperatorMatrix, QuantumOperator};
 use crate::quantum::state::{METATRON_DIMENSION, QuantumState};
 use num_complex::Complex64;
+use rand::Rng;
+use rand_distr::{Distribution, Normal};
 use std::f64::consts::PI;
+use std::sync::Arc;
 
 /// Ansatz type variants
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -16,6 +20,44 @@ pub enum AnsatzType {
     HardwareEfficient,
     EfficientSU2,
     Metatron,
+    ParticleConserving,
+}
+
+/// One gate of an ansatz circuit together with its parameter derivatives.
+#[derive(Clone, Debug)]
+pub struct AnsatzGate {
+    /// Unitary applied to the state
+    pub matrix: OperatorMatrix,
+    /// `(parameter index, ∂U/∂θ)` for every parameter the gate depends on
+    pub derivatives: Vec<(usize, OperatorMatrix)>,
+}
+
+impl AnsatzGate {
+    fn fixed(matrix: OperatorMatrix) -> Self {
+        Self {
+            matrix,
+            derivatives: Vec::new(),
+        }
+    }
+}
+
+/// exp(−iθX) acting on the (i, j) plane, and its derivative in θ.
+fn plane_rotation(i: usize, j: usize, angle: f64) -> (OperatorMatrix, OperatorMatrix) {
+    let mut gate = OperatorMatrix::identity();
+    let mut derivative = OperatorMatrix::zeros();
+    let (sin_val, cos_val) = angle.sin_cos();
+
+    gate[(i, i)] = Complex64::new(cos_val, 0.0);
+    gate[(i, j)] = Complex64::new(0.0, -sin_val);
+    gate[(j, i)] = Complex64::new(0.0, -sin_val);
+    gate[(j, j)] = Complex64::new(cos_val, 0.0);
+
+    derivative[(i, i)] = Complex64::new(-sin_val, 0.0);
+    derivative[(i, j)] = Complex64::new(0.0, -cos_val);
+    derivative[(j, i)] = Complex64::new(0.0, -cos_val);
+    derivative[(j, j)] = Complex64::new(-sin_val, 0.0);
+
+    (gate, derivative)
 }
 
 /// Trait for parametrized quantum circuits
@@ -26,8 +68,10 @@ pub trait Ansatz: Send + Sync {
     /// Get the total number of parameters
     fn num_parameters(&self) -> usize;
 
-    /// Get the ansatz type
-    fn ansatz_type(&self) -> AnsatzType;
+    /// Built-in type of this ansatz; `None` for user-defined circuits
+    fn ansatz_type(&self) -> Option<AnsatzType> {
+        None
+    }
 
     /// Get the number of qubits (for Metatron: effectively log2(13) ≈ 3.7, but we work in 13-dim)
     fn num_qubits(&self) -> usize {
@@ -49,6 +93,34 @@ pub trait Ansatz: Send + Sync {
             Ok(())
         }
     }
+
+    /// Gate-by-gate decomposition of the circuit, used for adjoint
+    /// differentiation.
+    ///
+    /// Applying the gates in order must reproduce [`Ansatz::apply`], and
+    /// every gate must be unitary. Ansätze returning `None` (the default)
+    /// fall back to parameter shifts for [`GradientMethod::Adjoint`].
+    ///
+    /// [`GradientMethod::Adjoint`]: crate::vqa::GradientMethod::Adjoint
+    fn gates(&self, _parameters: &[f64]) -> Option<Vec<AnsatzGate>> {
+        None
+    }
+
+    /// Total number of gates (rotations and entanglers) in the circuit.
+    ///
+    /// Defaults to the length of [`Ansatz::gates`] at zero angles, or 0 if
+    /// the ansatz has no gate sequence.
+    fn gate_count(&self) -> usize {
+        self.gates(&vec![0.0; self.num_parameters()])
+            .map_or(0, |gates| gates.len())
+    }
+
+    /// Number of entangling gates, the dominant cost on NISQ hardware.
+    ///
+    /// Defaults to 0; ansätze that entangle should override it.
+    fn two_qubit_gate_count(&self) -> usize {
+        0
+    }
 }
 
 /// Hardware-Efficient Ansatz
@@ -113,6 +185,37 @@ impl HardwareEfficientAnsatz {
         matrix
     }
 
+    /// ∂/∂θ of [`Self::ry_rotation_matrix`]
+    fn ry_rotation_derivative(&self, qubit: usize, angle: f64) -> OperatorMatrix {
+        let mut matrix = OperatorMatrix::zeros();
+
+        if qubit < METATRON_DIMENSION - 1 {
+            let cos_half = 0.5 * (angle / 2.0).cos();
+            let sin_half = 0.5 * (angle / 2.0).sin();
+            matrix[(qubit, qubit)] = Complex64::new(-sin_half, 0.0);
+            matrix[(qubit, qubit + 1)] = Complex64::new(-cos_half, 0.0);
+            matrix[(qubit + 1, qubit)] = Complex64::new(cos_half, 0.0);
+            matrix[(qubit + 1, qubit + 1)] = Complex64::new(-sin_half, 0.0);
+        }
+
+        matrix
+    }
+
+    /// ∂/∂θ of [`Self::rz_rotation_matrix`]
+    fn rz_rotation_derivative(&self, qubit: usize, angle: f64) -> OperatorMatrix {
+        let mut matrix = OperatorMatrix::zeros();
+
+        if qubit < METATRON_DIMENSION {
+            let half_i = Complex64::new(0.0, 0.5);
+            matrix[(qubit, qubit)] = -half_i * Complex64::from_polar(1.0, -angle / 2.0);
+            if qubit + 1 < METATRON_DIMENSION {
+                matrix[(qubit + 1, qubit + 1)] = half_i * Complex64::from_polar(1.0, angle / 2.0);
+            }
+        }
+
+        matrix
+    }
+
     /// Create entangling gate between neighboring qubits
     fn entangling_gate(&self, qubit1: usize, qubit2: usize, angle: f64) -> OperatorMatrix {
         let mut matrix = OperatorMatrix::identity();
@@ -180,8 +283,57 @@ impl Ansatz for HardwareEfficientAnsatz {
         2 * self.num_qubits * self.depth
     }
 
-    fn ansatz_type(&self) -> AnsatzType {
-        AnsatzType::HardwareEfficient
+    /// Ry and Rz on every qubit plus the chain entanglers, per layer
+    fn gate_count(&self) -> usize {
+        (2 * self.num_qubits + self.num_qubits - 1) * self.depth
+    }
+
+    fn two_qubit_gate_count(&self) -> usize {
+        (self.num_qubits - 1) * self.depth
+    }
+
+    fn gates(&self, parameters: &[f64]) -> Option<Vec<AnsatzGate>> {
+        self.validate_parameters(parameters)
+            .expect("Invalid parameters");
+
+        let params_per_layer = 2 * self.num_qubits;
+        let mut gates = Vec::with_capacity(self.depth * (3 * self.num_qubits - 1));
+
+        for layer in 0..self.depth {
+            let layer_offset = layer * params_per_layer;
+
+            for qubit in 0..self.num_qubits {
+                let idx = layer_offset + qubit;
+                gates.push(AnsatzGate {
+                    matrix: self.ry_rotation_matrix(qubit, parameters[idx]),
+                    derivatives: vec![(idx, self.ry_rotation_derivative(qubit, parameters[idx]))],
+                });
+            }
+
+            for qubit in 0..self.num_qubits {
+                let idx = layer_offset + self.num_qubits + qubit;
+                gates.push(AnsatzGate {
+                    matrix: self.rz_rotation_matrix(qubit, parameters[idx]),
+                    derivatives: vec![(idx, self.rz_rotation_derivative(qubit, parameters[idx]))],
+                });
+            }
+
+            // The entanglers reuse the Ry angles at half strength.
+            for qubit in 0..self.num_qubits - 1 {
+                let idx = layer_offset + qubit % params_per_layer;
+                let (matrix, derivative) = plane_rotation(qubit, qubit + 1, parameters[idx] * 0.5);
+                gates.push(AnsatzGate {
+                    matrix,
+                    derivatives: vec![(idx, derivative * Complex64::new(0.5, 0.0))],
+                });
+            }
+        }
+
+        Some(gates)
+    }
+
+    fn ansatz_type(&self) -> Option<AnsatzType> {
+        Some(AnsatzType::HardwareEfficient)
     }
 
     fn depth(&self) -> usize {
@@ -233,6 +385,38 @@ impl EfficientSU2Ansatz {
 
         matrix
     }
+
+    /// Partial derivatives of [`Self::su2_rotation`] in (θ1, θ2, θ3)
+    fn su2_rotation_derivatives(
+        &self,
+        qubit: usize,
+        theta1: f64,
+        theta2: f64,
+        theta3: f64,
+    ) -> [OperatorMatrix; 3] {
+        let mut derivatives = [OperatorMatrix::zeros(); 3];
+
+        if qubit < METATRON_DIMENSION - 1 {
+            let rotation = self.su2_rotation(qubit, theta1, theta2, theta3);
+            // Ry(θ2 + π) carries (−sin, cos) of the half angle, i.e. twice
+            // the θ2-derivative of the original block.
+            let shifted = self.su2_rotation(qubit, theta1, theta2 + PI, theta3);
+            let half_i = Complex64::new(0.0, 0.5);
+            let block = [
+                (qubit, qubit, 1.0, 1.0),
+                (qubit, qubit + 1, 1.0, -1.0),
+                (qubit + 1, qubit, -1.0, 1.0),
+                (qubit + 1, qubit + 1, -1.0, -1.0),
+            ];
+            for (row, col, sign1, sign3) in block {
+                derivatives[0][(row, col)] = half_i * sign1 * rotation[(row, col)];
+                derivatives[1][(row, col)] = 0.5 * shifted[(row, col)];
+                derivatives[2][(row, col)] = half_i * sign3 * rotation[(row, col)];
+            }
+        }
+
+        derivatives
+    }
 }
 
 impl Ansatz for EfficientSU2Ansatz {
@@ -285,8 +469,48 @@ impl Ansatz for EfficientSU2Ansatz {
         3 * self.num_qubits * self.depth
     }
 
-    fn ansatz_type(&self) -> AnsatzType {
-        AnsatzType::EfficientSU2
+    /// Rz, Ry and Rz on every qubit plus the fixed chain entanglers, per
+    /// layer
+    fn gate_count(&self) -> usize {
+        (3 * self.num_qubits + self.num_qubits - 1) * self.depth
+    }
+
+    fn two_qubit_gate_count(&self) -> usize {
+        (self.num_qubits - 1) * self.depth
+    }
+
+    fn gates(&self, parameters: &[f64]) -> Option<Vec<AnsatzGate>> {
+        self.validate_parameters(parameters)
+            .expect("Invalid parameters");
+
+        let params_per_layer = 3 * self.num_qubits;
+        let mut gates = Vec::with_capacity(self.depth * (2 * self.num_qubits - 1));
+
+        for layer in 0..self.depth {
+            let layer_offset = layer * params_per_layer;
+
+            for qubit in 0..self.num_qubits {
+                let idx = layer_offset + qubit * 3;
+                let (theta1, theta2, theta3) =
+                    (parameters[idx], parameters[idx + 1], parameters[idx + 2]);
+                let derivatives = self.su2_rotation_derivatives(qubit, theta1, theta2, theta3);
+                gates.push(AnsatzGate {
+                    matrix: self.su2_rotation(qubit, theta1, theta2, theta3),
+                    derivatives: (idx..).zip(derivatives).collect(),
+                });
+            }
+
+            for qubit in 0..self.num_qubits - 1 {
+                let (gate, _) = plane_rotation(qubit, qubit + 1, PI / 4.0);
+                gates.push(AnsatzGate::fixed(gate));
+            }
+        }
+
+        Some(gates)
+    }
+
+    fn ansatz_type(&self) -> Option<AnsatzType> {
+        Some(AnsatzType::EfficientSU2)
     }
 
     fn depth(&self) -> usize {
@@ -373,6 +597,22 @@ impl MetatronAnsatz {
 
         matrix
     }
+
+    /// ∂/∂θ of [`Self::metatron_rotation`]
+    fn metatron_rotation_derivative(&self, node: usize, angle: f64) -> OperatorMatrix {
+        if node == 0 {
+            let mut matrix = OperatorMatrix::zeros();
+            for i in 0..METATRON_DIMENSION {
+                let phase = 2.0 * PI * i as f64 / METATRON_DIMENSION as f64;
+                matrix[(i, i)] =
+                    Complex64::new(0.0, phase) * Complex64::from_polar(1.0, angle * phase);
+            }
+            matrix
+        } else {
+            let next = (node + 1) % METATRON_DIMENSION;
+            plane_rotation(node, next, angle / 2.0).1 * Complex64::new(0.5, 0.0)
+        }
+    }
 }
 
 impl Ansatz for MetatronAnsatz {
@@ -455,8 +695,167 @@ impl Ansatz for MetatronAnsatz {
         (METATRON_DIMENSION + self.num_entangling_gates()) * self.depth
     }
 
-    fn ansatz_type(&self) -> AnsatzType {
-        AnsatzType::Metatron
+    /// One gate per parameter: node rotations plus entanglers
+    fn gate_count(&self) -> usize {
+        self.num_parameters()
+    }
+
+    fn two_qubit_gate_count(&self) -> usize {
+        self.num_entangling_gates() * self.depth
+    }
+
+    fn gates(&self, parameters: &[f64]) -> Option<Vec<AnsatzGate>> {
+        self.validate_parameters(parameters)
+            .expect("Invalid parameters");
+
+        let params_per_layer = METATRON_DIMENSION + self.num_entangling_gates();
+        let pairs: Vec<(usize, usize)> = match self.entanglement_strategy {
+            EntanglementStrategy::Ring => (0..METATRON_DIMENSION)
+                .map(|i| (i, (i + 1) % METATRON_DIMENSION))
+                .collect(),
+            EntanglementStrategy::Full => (0..METATRON_DIMENSION)
+                .flat_map(|i| ((i + 1)..METATRON_DIMENSION).map(move |j| (i, j)))
+                .collect(),
+        };
+        let mut gates = Vec::with_capacity(self.num_parameters());
+
+        for layer in 0..self.depth {
+            let layer_offset = layer * params_per_layer;
+
+            for node in 0..METATRON_DIMENSION {
+                let idx = layer_offset + node;
+                gates.push(AnsatzGate {
+                    matrix: self.metatron_rotation(node, parameters[idx]),
+                    derivatives: vec![(
+                        idx,
+                        self.metatron_rotation_derivative(node, parameters[idx]),
+                    )],
+                });
+            }
+
+            for (gate_idx, &(i, j)) in pairs.iter().enumerate() {
+                let idx = layer_offset + METATRON_DIMENSION + gate_idx;
+                let (matrix, derivative) = plane_rotation(i, j, parameters[idx]);
+                gates.push(AnsatzGate {
+                    matrix,
+                    derivatives: vec![(idx, derivative)],
+                });
+            }
+        }
+
+        Some(gates)
+    }
+
+    fn ansatz_type(&self) -> Option<AnsatzType> {
+        Some(AnsatzType::Metatron)
+    }
+
+    fn depth(&self) -> usize {
+        self.depth
+    }
+}
+
+/// Particle-Conserving Ansatz
+///
+/// Reads basis state |k⟩ as the occupations of four qubits, the bits of
+/// `k`, so it carries excitation number `popcount(k)`. Each layer applies
+/// a real Givens rotation
+///
+/// G(θ) = [[cos θ, −sin θ], [sin θ, cos θ]]
+///
+/// to every pair of basis states with the same excitation number that
+/// differ by a single hop (one excitation moving to another qubit). Every
+/// gate commutes with the number operator, so the probability in each
+/// fixed-excitation sector (sizes 1, 4, 6, 2 for 0–3 excitations) is
+/// preserved.
+///
+/// Parameters: 19 * depth (6 one-excitation, 12 two-excitation and 1
+/// three-excitation pair per layer)
+#[derive(Clone, Debug)]
+pub struct ParticleConservingAnsatz {
+    depth: usize,
+    pairs: Vec<(usize, usize)>,
+}
+
+impl ParticleConservingAnsatz {
+    pub fn new(depth: usize) -> Self {
+        let pairs = (0..METATRON_DIMENSION)
+            .flat_map(|i| ((i + 1)..METATRON_DIMENSION).map(move |j| (i, j)))
+            .filter(|&(i, j)| i.count_ones() == j.count_ones() && (i ^ j).count_ones() == 2)
+            .collect();
+        Self { depth, pairs }
+    }
+
+    /// Givens rotation on the (i, j) plane, and its derivative in θ.
+    fn givens_rotation(i: usize, j: usize, angle: f64) -> (OperatorMatrix, OperatorMatrix) {
+        let mut gate = OperatorMatrix::identity();
+        let mut derivative = OperatorMatrix::zeros();
+        let (sin_val, cos_val) = angle.sin_cos();
+
+        gate[(i, i)] = Complex64::new(cos_val, 0.0);
+        gate[(i, j)] = Complex64::new(-sin_val, 0.0);
+        gate[(j, i)] = Complex64::new(sin_val, 0.0);
+        gate[(j, j)] = Complex64::new(cos_val, 0.0);
+
+        derivative[(i, i)] = Complex64::new(-sin_val, 0.0);
+        derivative[(i, j)] = Complex64::new(-cos_val, 0.0);
+        derivative[(j, i)] = Complex64::new(cos_val, 0.0);
+        derivative[(j, j)] = Complex64::new(-sin_val, 0.0);
+
+        (gate, derivative)
+    }
+}
+
+impl Ansatz for ParticleConservingAnsatz {
+    fn apply(&self, state: &QuantumState, parameters: &[f64]) -> QuantumState {
+        let gates = self
+            .gates(parameters)
+            .expect("gate sequence is always available");
+        let amplitudes = gates.iter().fold(*state.amplitudes(), |amplitudes, gate| {
+            gate.matrix * amplitudes
+        });
+        QuantumState::from_vector(amplitudes, false)
+    }
+
+    fn num_parameters(&self) -> usize {
+        self.pairs.len() * self.depth
+    }
+
+    /// Every Givens rotation moves an excitation between two qubits
+    fn gate_count(&self) -> usize {
+        self.num_parameters()
+    }
+
+    fn two_qubit_gate_count(&self) -> usize {
+        self.num_parameters()
+    }
+
+    fn gates(&self, parameters: &[f64]) -> Option<Vec<AnsatzGate>> {
+        self.validate_parameters(parameters)
+            .expect("Invalid parameters");
+
+        let gates = (0..self.depth)
+            .flat_map(|layer| {
+                self.pairs
+                    .iter()
+                    .enumerate()
+                    .map(move |(k, &pair)| (layer, k, pair))
+            })
+            .map(|(layer, k, (i, j))| {
+                let idx = layer * self.pairs.len() + k;
+                let (matrix, derivative) = Self::givens_rotation(i, j, parameters[idx]);
+                AnsatzGate {
+                    matrix,
+                    derivatives: vec![(idx, derivative)],
+                }
+            })
+            .collect();
+
+        Some(gates)
+    }
+
+    fn ansatz_type(&self) -> Option<AnsatzType> {
+        Some(AnsatzType::ParticleConserving)
     }
 
     fn depth(&self) -> usize {
@@ -474,13 +873,120 @@ impl Ansatz for Box<dyn Ansatz> {
         (**self).num_parameters()
     }
 
-    fn ansatz_type(&self) -> AnsatzType {
+    fn ansatz_type(&self) -> Option<AnsatzType> {
+        (**self).ansatz_type()
+    }
+
+    fn depth(&self) -> usize {
+        (**self).depth()
+    }
+
+    fn gates(&self, parameters: &[f64]) -> Option<Vec<AnsatzGate>> {
+        (**self).gates(parameters)
+    }
+
+    fn gate_count(&self) -> usize {
+        (**self).gate_count()
+    }
+
+    fn two_qubit_gate_count(&self) -> usize {
+        (**self).two_qubit_gate_count()
+    }
+}
+
+impl Ansatz for Arc<dyn Ansatz> {
+    fn apply(&self, state: &QuantumState, parameters: &[f64]) -> QuantumState {
+        (**self).apply(state, parameters)
+    }
+
+    fn num_parameters(&self) -> usize {
+        (**self).num_parameters()
+    }
+
+    fn ansatz_type(&self) -> Option<AnsatzType> {
         (**self).ansatz_type()
     }
 
     fn depth(&self) -> usize {
         (**self).depth()
     }
+
+    fn gates(&self, parameters: &[f64]) -> Option<Vec<AnsatzGate>> {
+        (**self).gates(parameters)
+    }
+
+    fn gate_count(&self) -> usize {
+        (**self).gate_count()
+    }
+
+    fn two_qubit_gate_count(&self) -> usize {
+        (**self).two_qubit_gate_count()
+    }
+}
+
+/// Strategy for drawing the initial parameters of a variational circuit.
+///
+/// All built-in ansätze lay out their parameters layer by layer and reduce to
+/// (close to) the identity at zero angles, which the near-identity variants
+/// rely on. Which strategy suits best depends on the ansatz and algorithm:
+/// small angles keep deep circuits out of barren plateaus, while wide draws
+/// help penalty-based searches such as VQD escape the previous minimum.
+#[derive(Clone, Debug, PartialEq)]
+pub enum InitStrategy {
+    /// All angles zero (the identity circuit).
+    Zeros,
+    /// Uniform draws from `[-scale, scale]`.
+    SmallRandom { scale: f64 },
+    /// Normal draws with mean zero and standard deviation `std`.
+    Gaussian { std: f64 },
+    /// First layer uniform in `[-π, π]`, all deeper layers zero.
+    ///
+    /// The circuit starts as one random block followed by identity blocks,
+    /// so gradients are non-trivial while the effective depth stays 1.
+    IdentityBlock,
+}
+
+impl InitStrategy {
+    /// Draw `num_params` initial angles for a circuit with `depth` layers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the scale or standard deviation is negative or not finite.
+    pub fn sample<R: Rng + ?Sized>(
+        &self,
+        num_params: usize,
+        depth: usize,
+        rng: &mut R,
+    ) -> Vec<f64> {
+        match *self {
+            InitStrategy::Zeros => vec![0.0; num_params],
+            InitStrategy::SmallRandom { scale } => {
+                assert!(
+                    scale.is_finite() && scale >= 0.0,
+                    "scale must be non-negative"
+                );
+                (0..num_params)
+                    .map(|_| rng.gen_range(-scale..=scale))
+                    .collect()
+            }
+            InitStrategy::Gaussian { std } => {
+                let normal = Normal::new(0.0, std).expect("std must be non-negative");
+                (0..num_params).map(|_| normal.sample(rng)).collect()
+            }
+            InitStrategy::IdentityBlock => {
+                let first_layer = num_params / depth.max(1);
+                (0..num_params)
+                    .map(|i| {
+                        if i < first_layer {
+                            rng.gen_range(-PI..PI)
+                        } else {
+                            0.0
+                        }
+                    })
+                    .collect()
+            }
+        }
+    }
 }
 
 /// Factory function to create ansatz instances
@@ -489,6 +995,7 @@ pub fn create_ansatz(ansatz_type: AnsatzType, depth: usize) -> Box<dyn Ansatz> {
         AnsatzType::HardwareEfficient => Box::new(HardwareEfficientAnsatz::new(depth)),
         AnsatzType::EfficientSU2 => Box::new(EfficientSU2Ansatz::new(depth)),
         AnsatzType::Metatron => Box::new(MetatronAnsatz::new(depth)),
+        AnsatzType::ParticleConserving => Box::new(ParticleConservingAnsatz::new(depth)),
     }
 }
 
@@ -517,4 +1024,126 @@ mod tests {
         let new_state = ansatz.apply(&state, &params);
         assert!(new_state.is_normalized(1e-10));
     }
+
+    #[test]
+    fn test_gate_sequences_reproduce_apply() {
+        let ansatze: Vec<Box<dyn Ansatz>> = vec![
+            Box::new(HardwareEfficientAnsatz::new(2)),
+            Box::new(EfficientSU2Ansatz::new(1)),
+            Box::new(MetatronAnsatz::new(2)),
+            Box::new(MetatronAnsatz::new_with_entanglement(
+                1,
+                EntanglementStrategy::Full,
+            )),
+            Box::new(ParticleConservingAnsatz::new(2)),
+        ];
+        let state = QuantumState::basis_state(4).unwrap();
+
+        for ansatz in &ansatze {
+            let params: Vec<f64> = (0..ansatz.num_parameters())
+                .map(|i| (0.7 * i as f64).sin())
+                .collect();
+            let gates = ansatz.gates(&params).unwrap();
+            let mut amplitudes = *state.amplitudes();
+            for gate in &gates {
+                amplitudes = gate.matrix * amplitudes;
+                let unitarity = gate.matrix.adjoint() * gate.matrix - OperatorMatrix::identity();
+                assert!(unitarity.norm() < 1e-12);
+            }
+            let expected = ansatz.apply(&state, &params);
+            assert!((amplitudes - expected.amplitudes()).norm() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_gate_counts() {
+        let n = METATRON_DIMENSION;
+        let depth = 2;
+
+        let hea = HardwareEfficientAnsatz::new(depth);
+        assert_eq!(hea.gate_count(), hea.num_parameters() + (n - 1) * depth);
+        assert_eq!(hea.two_qubit_gate_count(), (n - 1) * depth);
+
+        let su2 = EfficientSU2Ansatz::new(depth);
+        assert_eq!(su2.gate_count(), su2.num_parameters() + (n - 1) * depth);
+        assert_eq!(su2.two_qubit_gate_count(), (n - 1) * depth);
+
+        let ring = MetatronAnsatz::new(depth);
+        let full = MetatronAnsatz::new_with_entanglement(depth, EntanglementStrategy::Full);
+        assert_eq!(ring.gate_count(), ring.num_parameters());
+        assert_eq!(
+            ring.two_qubit_gate_count(),
+            ring.num_parameters() - n * depth
+        );
+        assert_eq!(
+            full.two_qubit_gate_count(),
+            full.num_parameters() - n * depth
+        );
+        assert_eq!(full.two_qubit_gate_count(), n * (n - 1) / 2 * depth);
+        assert_eq!(full.two_qubit_gate_count(), 6 * ring.two_qubit_gate_count());
+
+        // The gate sequences agree wherever they are one gate per operation
+        let boxed: Box<dyn Ansatz> = Box::new(full.clone());
+        let params = vec![0.0; full.num_parameters()];
+        assert_eq!(boxed.gate_count(), full.gates(&params).unwrap().len());
+        let pca = ParticleConservingAnsatz::new(depth);
+        assert_eq!(pca.gate_count(), pca.gates(&vec![0.0; 38]).unwrap().len());
+    }
+
+    #[test]
+    fn test_particle_conserving_preserves_sectors() {
+        let ansatz = ParticleConservingAnsatz::new(2);
+        assert_eq!(ansatz.num_parameters(), 19 * 2);
+
+        let sector_weights = |state: &QuantumState| {
+            let mut weights = [0.0; 4];
+            for (k, amplitude) in state.amplitudes().iter().enumerate() {
+                weights[k.count_ones() as usize] += amplitude.norm_sqr();
+            }
+            weights
+        };
+        let state = QuantumState::random(Some(17));
+        let params: Vec<f64> = (0..ansatz.num_parameters())
+            .map(|i| 1.3 * (i as f64 + 0.5).cos())
+            .collect();
+        let evolved = ansatz.apply(&state, &params);
+
+        assert!(evolved.is_normalized(1e-10));
+        assert!((evolved.amplitudes() - state.amplitudes()).norm() > 1e-3);
+        for (before, after) in sector_weights(&state).iter().zip(sector_weights(&evolved)) {
+            assert!((before - after).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_init_strategies() {
+        use rand::SeedableRng;
+        use rand::rngs::SmallRng;
+
+        let ansatz = HardwareEfficientAnsatz::new(3);
+        let n = ansatz.num_parameters();
+        let draw = |strategy: &InitStrategy, seed| {
+            strategy.sample(n, ansatz.depth(), &mut SmallRng::seed_from_u64(seed))
+        };
+
+        assert_eq!(draw(&InitStrategy::Zeros, 0), vec![0.0; n]);
+
+        let small = InitStrategy::SmallRandom { scale: 0.05 };
+        assert_eq!(draw(&small, 7), draw(&small, 7));
+        assert!(draw(&small, 7).iter().all(|p| p.abs() <= 0.05));
+
+        let gaussian = draw(&InitStrategy::Gaussian { std: 0.1 }, 3);
+        assert_eq!(gaussian.len(), n);
+        assert!(gaussian.iter().any(|&p| p != 0.0));
+
+        // Only the first layer is random; the rest of the circuit is the identity.
+        let identity = draw(&InitStrategy::IdentityBlock, 11);
+        let per_layer = n / 3;
+        assert!(identity[..per_layer].iter().any(|&p| p != 0.0));
+        assert!(identity[per_layer..].iter().all(|&p| p == 0.0));
+        let state = QuantumState::uniform_superposition();
+        let one_layer = HardwareEfficientAnsatz::new(1).apply(&state, &identity[..per_layer]);
+        let full = ansatz.apply(&state, &identity);
+        assert!((one_layer.amplitudes() - full.amplitudes()).norm() < 1e-12);
+    }
 }
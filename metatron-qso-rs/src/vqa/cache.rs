@@ -0,0 +1,154 @@
+//! Bounded memoisation of cost-function evaluations.
+//!
+//! [`EvaluationCache`] keys entries on the raw bit patterns of the parameter
+//! vector, so a lookup costs one hash over `n` machine words instead of
+//! formatting every component as text. Only bit-identical parameters hit;
+//! `0.0` and `-0.0` are distinct keys.
+//!
+//! The cache is bounded and evicts the least recently used entry once it is
+//! full. A capacity of zero (the default) disables it entirely: lookups miss
+//! without taking the lock and inserts are dropped. Stochastic optimizers such
+//! as SPSA almost never revisit a parameter vector, so leave the cache off for
+//! them; it pays off for gradient rules and line searches that re-evaluate the
+//! same points.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Mutex;
+
+type CacheKey = Vec<u64>;
+
+/// Thread-safe LRU cache mapping parameter vectors to cost values.
+#[derive(Debug, Default)]
+pub struct EvaluationCache {
+    capacity: usize,
+    state: Mutex<LruState>,
+}
+
+#[derive(Debug, Default)]
+struct LruState {
+    /// Cached value and the tick of its last use.
+    entries: HashMap<CacheKey, (f64, u64)>,
+    /// Keys ordered by last use; the first entry is evicted next.
+    recency: BTreeMap<u64, CacheKey>,
+    tick: u64,
+}
+
+impl LruState {
+    /// Move `key` to the most recently used position and return its new tick.
+    fn touch(&mut self, key: &CacheKey, last_used: Option<u64>) -> u64 {
+        self.tick += 1;
+        if let Some(last_used) = last_used {
+            self.recency.remove(&last_used);
+        }
+        self.recency.insert(self.tick, key.clone());
+        self.tick
+    }
+}
+
+impl EvaluationCache {
+    /// Cache holding at most `capacity` entries; zero disables caching.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            state: Mutex::default(),
+        }
+    }
+
+    /// Maximum number of entries kept.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Whether lookups can ever hit.
+    pub fn is_enabled(&self) -> bool {
+        self.capacity > 0
+    }
+
+    /// Number of entries currently stored.
+    pub fn len(&self) -> usize {
+        self.state.lock().unwrap().entries.len()
+    }
+
+    /// Whether the cache holds no entries.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Cached value for `parameters`, marking it as most recently used.
+    pub fn get(&self, parameters: &[f64]) -> Option<f64> {
+        if !self.is_enabled() {
+            return None;
+        }
+        let key = cache_key(parameters);
+        let mut state = self.state.lock().unwrap();
+        let (value, last_used) = *state.entries.get(&key)?;
+        let tick = state.touch(&key, Some(last_used));
+        state.entries.insert(key, (value, tick));
+        Some(value)
+    }
+
+    /// Store `value` for `parameters`, evicting the least recently used
+    /// entry if the cache is full.
+    pub fn insert(&self, parameters: &[f64], value: f64) {
+        if !self.is_enabled() {
+            return;
+        }
+        let key = cache_key(parameters);
+        let mut state = self.state.lock().unwrap();
+        let last_used = state.entries.get(&key).map(|&(_, last_used)| last_used);
+        if last_used.is_none()
+            && state.entries.len() >= self.capacity
+            && let Some((_, evicted)) = state.recency.pop_first()
+        {
+            state.entries.remove(&evicted);
+        }
+        let tick = state.touch(&key, last_used);
+        state.entries.insert(key, (value, tick));
+    }
+
+    /// Drop every entry.
+    pub fn clear(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.entries.clear();
+        state.recency.clear();
+    }
+}
+
+fn cache_key(parameters: &[f64]) -> CacheKey {
+    parameters.iter().map(|p| p.to_bits()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn evicts_least_recently_used_entry() {
+        let cache = EvaluationCache::new(2);
+        cache.insert(&[0.0], 1.0);
+        cache.insert(&[1.0], 2.0);
+        // Touch [0.0] so [1.0] becomes the eviction candidate.
+        assert_eq!(cache.get(&[0.0]), Some(1.0));
+        cache.insert(&[2.0], 3.0);
+
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get(&[1.0]), None);
+        assert_eq!(cache.get(&[0.0]), Some(1.0));
+        assert_eq!(cache.get(&[2.0]), Some(3.0));
+
+        // Overwriting an existing key must not evict anything.
+        cache.insert(&[2.0], 4.0);
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get(&[2.0]), Some(4.0));
+        assert_eq!(cache.get(&[-0.0]), None);
+    }
+
+    #[test]
+    fn zero_capacity_disables_caching() {
+        let cache = EvaluationCache::default();
+        cache.insert(&[0.5, 0.25], 1.0);
+        assert!(!cache.is_enabled());
+        assert!(cache.is_empty());
+        assert_eq!(cache.get(&[0.5, 0.25]), None);
+    }
+}
@@ -4,15 +4,19 @@
 //! Supports VQE (eigenvalue), QAOA (optimization), and VQC (classification) cost functions.
 
 use crate::hamiltonian::MetatronHamiltonian;
-use crate::quantum::operator::QuantumOperator;
-use crate::quantum::state::QuantumState;
+use crate::quantum::operator::{OperatorMatrix, QuantumOperator};
+use crate::quantum::state::{METATRON_DIMENSION, QuantumState};
 use crate::vqa::ParameterVector;
 use crate::vqa::ansatz::Ansatz;
+use crate::vqa::cache::EvaluationCache;
+use nalgebra::{SVector, SymmetricEigen};
 use num_complex::Complex64;
 use rayon::prelude::*;
-use std::collections::HashMap;
 use std::f64::consts::PI;
-use std::sync::{Arc, Mutex};
+use std::sync::Arc;
+
+/// Projected norms below this are treated as having no symmetric component.
+const SYMMETRY_SECTOR_TOLERANCE: f64 = 1e-9;
 
 /// Gradient computation methods
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -21,6 +25,11 @@ pub enum GradientMethod {
     ParameterShift,
     /// Finite differences (numerical approximation)
     FiniteDifference,
+    /// Adjoint (reverse-mode) differentiation through the ansatz gates:
+    /// exact, and one forward plus one backward sweep regardless of the
+    /// parameter count. Cost functions or ansätze without support fall back
+    /// to parameter shifts.
+    Adjoint,
     /// No gradient computation
     None,
 }
@@ -30,9 +39,30 @@ pub trait CostFunction: Send + Sync {
     /// Evaluate the cost function at given parameters
     fn evaluate(&self, parameters: &[f64]) -> f64;
 
+    /// Evaluate the cost at several parameter sets, in order.
+    ///
+    /// Gradient rules submit all shifted points as one batch so that
+    /// implementations can share setup work across it. The default simply
+    /// evaluates every set in parallel.
+    fn evaluate_batch(&self, param_sets: &[Vec<f64>]) -> Vec<f64> {
+        param_sets.par_iter().map(|p| self.evaluate(p)).collect()
+    }
+
     /// Compute gradient using specified method
     fn gradient(&self, parameters: &[f64], method: GradientMethod) -> ParameterVector;
 
+    /// Cost at θ and its gradient in one call.
+    ///
+    /// Implementations can share the forward pass at θ between both results;
+    /// the default simply calls `evaluate` and `gradient`.
+    fn gradient_with_value(
+        &self,
+        parameters: &[f64],
+        method: GradientMethod,
+    ) -> (f64, ParameterVector) {
+        (self.evaluate(parameters), self.gradient(parameters, method))
+    }
+
     /// Compute Hessian (second derivatives) for advanced optimizers
     fn hessian(&self, parameters: &[f64]) -> Vec<Vec<f64>> {
         // Default: finite difference approximation
@@ -73,14 +103,60 @@ pub trait CostFunction: Send + Sync {
     fn dimension(&self) -> usize;
 }
 
+/// Step of the forward finite-difference rule
+const FINITE_DIFFERENCE_STEP: f64 = 1e-7;
+
+/// Cost at θ and its gradient from shifted evaluations of `cost`.
+///
+/// Parameter shifts (also used for [`GradientMethod::Adjoint`], which needs
+/// support from the cost function itself) evaluate at θ ± π/2; finite
+/// differences evaluate at θ + h·eᵢ and reuse the cost at θ. All shifted
+/// points go through [`CostFunction::evaluate_batch`] as a single batch.
+pub fn shift_gradient_with_value<C: CostFunction + ?Sized>(
+    cost: &C,
+    parameters: &[f64],
+    method: GradientMethod,
+) -> (f64, ParameterVector) {
+    let n = parameters.len();
+    let shifted = |deltas: &[f64]| -> Vec<f64> {
+        let sets: Vec<_> = deltas
+            .iter()
+            .flat_map(|&delta| {
+                (0..n).map(move |i| {
+                    let mut params = parameters.to_vec();
+                    params[i] += delta;
+                    params
+                })
+            })
+            .collect();
+        cost.evaluate_batch(&sets)
+    };
+
+    let f_0 = cost.evaluate(parameters);
+    let gradient = match method {
+        GradientMethod::ParameterShift | GradientMethod::Adjoint => {
+            let values = shifted(&[PI / 2.0, -PI / 2.0]);
+            (0..n).map(|i| (values[i] - values[n + i]) / 2.0).collect()
+        }
+        GradientMethod::FiniteDifference => {
+            let h = FINITE_DIFFERENCE_STEP;
+            shifted(&[h]).into_iter().map(|f| (f - f_0) / h).collect()
+        }
+        GradientMethod::None => vec![0.0; n],
+    };
+    (f_0, gradient)
+}
+
 /// VQE Cost Function: ⟨ψ(θ)|H|ψ(θ)⟩
 ///
 /// Computes expectation value of Hamiltonian for finding ground state energy
 pub struct VQECostFunction<A: Ansatz> {
-    hamiltonian: Arc<MetatronHamiltonian>,
+    /// Complex form of the Hamiltonian, built once and shared by all evaluations
+    operator: QuantumOperator,
     ansatz: A,
     initial_state: QuantumState,
-    cache: Arc<Mutex<HashMap<String, f64>>>,
+    symmetry_projector: Option<OperatorMatrix>,
+    cache: EvaluationCache,
 }
 
 impl<A: Ansatz> VQECostFunction<A> {
@@ -90,88 +166,174 @@ impl<A: Ansatz> VQECostFunction<A> {
         initial_state: QuantumState,
     ) -> Self {
         Self {
-            hamiltonian,
+            operator: QuantumOperator::from_matrix(hamiltonian.as_complex_operator()),
             ansatz,
             initial_state,
-            cache: Arc::new(Mutex::new(HashMap::new())),
+            symmetry_projector: None,
+            cache: EvaluationCache::default(),
         }
     }
 
-    fn params_to_key(&self, parameters: &[f64]) -> String {
-        parameters
-            .iter()
-            .map(|p| format!("{:.10}", p))
-            .collect::<Vec<_>>()
-            .join(",")
+    /// Memoise up to `capacity` evaluations in an LRU cache (0 disables it).
+    ///
+    /// See [`EvaluationCache`] for when caching is worthwhile.
+    pub fn with_cache_capacity(mut self, capacity: usize) -> Self {
+        self.cache = EvaluationCache::new(capacity);
+        self
+    }
+
+    /// Restrict the search to the symmetric sector of a symmetry group.
+    ///
+    /// `ops` must list every element of a finite group acting on the state
+    /// space (e.g. [`MetatronGraph::symmetry_operators`]). The ansatz output
+    /// is projected with P = (1/|G|) Σ_g g onto the trivial irrep and
+    /// renormalised before the energy is taken, so the cost becomes
+    /// ⟨ψ|PHP|ψ⟩ / ⟨ψ|P|ψ⟩. Parameter shifts are no longer exact for this
+    /// ratio; prefer finite differences. An empty `ops` disables projection.
+    ///
+    /// [`MetatronGraph::symmetry_operators`]: crate::graph::metatron::MetatronGraph::symmetry_operators
+    pub fn with_symmetry_projection(mut self, ops: Vec<QuantumOperator>) -> Self {
+        self.symmetry_projector = (!ops.is_empty()).then(|| {
+            let sum = ops
+                .iter()
+                .fold(OperatorMatrix::zeros(), |acc, op| acc + op.matrix());
+            sum / Complex64::new(ops.len() as f64, 0.0)
+        });
+        self.cache.clear();
+        self
     }
 
-    /// Parameter Shift Rule for single parameter
-    fn parameter_shift_single(&self, parameters: &[f64], param_idx: usize) -> f64 {
-        let mut params_plus = parameters.to_vec();
-        let mut params_minus = parameters.to_vec();
+    /// Prepared state |ψ(θ)⟩, projected onto the symmetry sector if one is set.
+    ///
+    /// A state with no weight in the sector is returned unprojected.
+    pub fn state(&self, parameters: &[f64]) -> QuantumState {
+        let psi = self.ansatz.apply(&self.initial_state, parameters);
+        match &self.symmetry_projector {
+            Some(projector) => {
+                let projected = projector * psi.amplitudes();
+                if projected.norm() < SYMMETRY_SECTOR_TOLERANCE {
+                    psi
+                } else {
+                    QuantumState::from_vector(projected, true)
+                }
+            }
+            None => psi,
+        }
+    }
 
-        params_plus[param_idx] += PI / 2.0;
-        params_minus[param_idx] -= PI / 2.0;
+    /// ⟨ψ(θ)|H|ψ(θ)⟩ without consulting the cache
+    fn energy(&self, parameters: &[f64]) -> f64 {
+        self.state(parameters).expectation_value(&self.operator).re
+    }
 
-        let f_plus = self.evaluate(&params_plus);
-        let f_minus = self.evaluate(&params_minus);
+    /// Energy and exact gradient from one forward and one backward sweep
+    /// through [`Ansatz::gates`]; `None` if the ansatz has no gate sequence.
+    fn adjoint_gradient(&self, parameters: &[f64]) -> Option<(f64, ParameterVector)> {
+        let gates = self.ansatz.gates(parameters)?;
+        let mut psi = gates
+            .iter()
+            .fold(*self.initial_state.amplitudes(), |psi, gate| {
+                gate.matrix * psi
+            });
+
+        // dE/dθ = 2 Re⟨λ|∂ψ⟩ with λ = Mψ. Without projection M = H; with
+        // E = ⟨ψ|PHP|ψ⟩ / ⟨ψ|P|ψ⟩ it is M = (PHP − E·P) / ⟨ψ|P|ψ⟩.
+        let hamiltonian = self.operator.matrix();
+        let projected = self
+            .symmetry_projector
+            .as_ref()
+            .map(|projector| (projector, projector * psi))
+            .filter(|(_, projected)| projected.norm() >= SYMMETRY_SECTOR_TOLERANCE);
+        let (energy, mut lambda) = match projected {
+            Some((projector, projected)) => {
+                let weight = projected.norm_squared();
+                let h_projected = hamiltonian * projected;
+                let energy = projected.dotc(&h_projected).re / weight;
+                (
+                    energy,
+                    (projector * h_projected - projected * Complex64::new(energy, 0.0))
+                        / Complex64::new(weight, 0.0),
+                )
+            }
+            None => {
+                let h_psi = hamiltonian * psi;
+                (psi.dotc(&h_psi).re, h_psi)
+            }
+        };
+
+        let mut gradient = vec![0.0; parameters.len()];
+        for gate in gates.iter().rev() {
+            let inverse = gate.matrix.adjoint();
+            psi = inverse * psi;
+            for (idx, derivative) in &gate.derivatives {
+                gradient[*idx] += 2.0 * lambda.dotc(&(derivative * psi)).re;
+            }
+            lambda = inverse * lambda;
+        }
 
-        (f_plus - f_minus) / 2.0
+        Some((energy, gradient))
     }
 }
 
 impl<A: Ansatz> CostFunction for VQECostFunction<A> {
     fn evaluate(&self, parameters: &[f64]) -> f64 {
         // Check cache first
-        let key = self.params_to_key(parameters);
-        {
-            let cache = self.cache.lock().unwrap();
-            if let Some(&value) = cache.get(&key) {
-                return value;
-            }
+        if let Some(value) = self.cache.get(parameters) {
+            return value;
         }
 
-        // Apply ansatz to initial state
-        let psi = self.ansatz.apply(&self.initial_state, parameters);
-
-        // Compute ⟨ψ|H|ψ⟩
-        let h_operator = QuantumOperator::from_matrix(self.hamiltonian.as_complex_operator());
-        let expectation = psi.expectation_value(&h_operator);
-        let energy = expectation.re;
+        // Apply ansatz to initial state (projected onto the symmetry sector)
+        // and compute ⟨ψ|H|ψ⟩
+        let energy = self.energy(parameters);
 
-        // Cache result
-        {
-            let mut cache = self.cache.lock().unwrap();
-            cache.insert(key, energy);
-        }
+        self.cache.insert(parameters, energy);
 
         energy
     }
 
+    /// Looks every set up in the cache first and evaluates only the misses,
+    /// in parallel, against the shared operator.
+    fn evaluate_batch(&self, param_sets: &[Vec<f64>]) -> Vec<f64> {
+        let mut values: Vec<Option<f64>> = param_sets.iter().map(|p| self.cache.get(p)).collect();
+
+        let misses: Vec<usize> = (0..param_sets.len())
+            .filter(|&i| values[i].is_none())
+            .collect();
+        let energies: Vec<f64> = misses
+            .par_iter()
+            .map(|&i| self.energy(&param_sets[i]))
+            .collect();
+
+        for (&i, energy) in misses.iter().zip(energies) {
+            self.cache.insert(&param_sets[i], energy);
+            values[i] = Some(energy);
+        }
+        values.into_iter().map(Option::unwrap).collect()
+    }
+
     fn gradient(&self, parameters: &[f64], method: GradientMethod) -> ParameterVector {
-        match method {
-            GradientMethod::ParameterShift => {
-                // Parallel computation of gradient components
-                (0..parameters.len())
-                    .into_par_iter()
-                    .map(|i| self.parameter_shift_single(parameters, i))
-                    .collect()
-            }
-            GradientMethod::FiniteDifference => {
-                let h = 1e-7;
-                (0..parameters.len())
-                    .into_par_iter()
-                    .map(|i| {
-                        let mut params_plus = parameters.to_vec();
-                        params_plus[i] += h;
-                        let f_plus = self.evaluate(&params_plus);
-                        let f_0 = self.evaluate(parameters);
-                        (f_plus - f_0) / h
-                    })
-                    .collect()
-            }
-            GradientMethod::None => vec![0.0; parameters.len()],
+        self.gradient_with_value(parameters, method).1
+    }
+
+    /// Evaluates the ansatz at θ once. Finite differences reuse that value
+    /// for every component, so a gradient costs n + 1 ansatz applications.
+    /// Exact parameter shifts evaluate at θ ± π/2 and cannot share the
+    /// forward pass; there the returned cost just saves a separate
+    /// `evaluate` call by the optimizer. See [`shift_gradient_with_value`].
+    /// The adjoint method bypasses the cache and takes the cost from its
+    /// forward sweep.
+    fn gradient_with_value(
+        &self,
+        parameters: &[f64],
+        method: GradientMethod,
+    ) -> (f64, ParameterVector) {
+        if method == GradientMethod::Adjoint
+            && let Some(result) = self.adjoint_gradient(parameters)
+        {
+            return result;
         }
+
+        shift_gradient_with_value(self, parameters, method)
     }
 
     fn dimension(&self) -> usize {
@@ -179,18 +341,47 @@ impl<A: Ansatz> CostFunction for VQECostFunction<A> {
     }
 }
 
+/// Eigendecomposition H = V diag(λ) V† of a Hermitian generator, used to form
+/// exact propagators exp(-iHt) = V diag(e^{-iλt}) V† for any t.
+struct HermitianPropagator {
+    eigenvalues: SVector<f64, METATRON_DIMENSION>,
+    eigenvectors: OperatorMatrix,
+}
+
+impl HermitianPropagator {
+    fn new(hamiltonian: &QuantumOperator) -> Self {
+        let eigen = SymmetricEigen::new(*hamiltonian.matrix());
+        Self {
+            eigenvalues: eigen.eigenvalues,
+            eigenvectors: eigen.eigenvectors,
+        }
+    }
+
+    fn evolution(&self, time: f64) -> QuantumOperator {
+        let mut scaled = self.eigenvectors;
+        for (k, &lambda) in self.eigenvalues.iter().enumerate() {
+            let mut column = scaled.column_mut(k);
+            column *= Complex64::from_polar(1.0, -lambda * time);
+        }
+        QuantumOperator::from_matrix(scaled * self.eigenvectors.adjoint())
+    }
+}
+
 /// QAOA Cost Function: ⟨ψ(γ,β)|H_C|ψ(γ,β)⟩
 ///
 /// For combinatorial optimization problems
 pub struct QAOACostFunction {
     cost_hamiltonian: Arc<QuantumOperator>,
-    mixer_hamiltonian: Arc<QuantumOperator>,
+    cost_propagator: HermitianPropagator,
+    mixer_propagator: HermitianPropagator,
     depth: usize,
     initial_state: QuantumState,
-    cache: Arc<Mutex<HashMap<String, f64>>>,
+    cache: EvaluationCache,
 }
 
 impl QAOACostFunction {
+    /// Both Hamiltonians must be Hermitian; they are diagonalised once here
+    /// and reused for every layer and evaluation.
     pub fn new(
         cost_hamiltonian: Arc<QuantumOperator>,
         mixer_hamiltonian: Arc<QuantumOperator>,
@@ -198,20 +389,32 @@ impl QAOACostFunction {
         initial_state: QuantumState,
     ) -> Self {
         Self {
+            cost_propagator: HermitianPropagator::new(&cost_hamiltonian),
+            mixer_propagator: HermitianPropagator::new(&mixer_hamiltonian),
             cost_hamiltonian,
-            mixer_hamiltonian,
             depth,
             initial_state,
-            cache: Arc::new(Mutex::new(HashMap::new())),
+            cache: EvaluationCache::default(),
         }
     }
 
-    fn params_to_key(&self, parameters: &[f64]) -> String {
-        parameters
-            .iter()
-            .map(|p| format!("{:.10}", p))
-            .collect::<Vec<_>>()
-            .join(",")
+    /// Memoise up to `capacity` evaluations in an LRU cache (0 disables it).
+    ///
+    /// See [`EvaluationCache`] for when caching is worthwhile.
+    pub fn with_cache_capacity(mut self, capacity: usize) -> Self {
+        self.cache = EvaluationCache::new(capacity);
+        self
+    }
+
+    /// QAOA state |ψ(γ,β)⟩ for parameters laid out as [γ₁..γₚ, β₁..βₚ]
+    pub fn prepare_state(&self, parameters: &[f64]) -> QuantumState {
+        assert_eq!(
+            parameters.len(),
+            2 * self.depth,
+            "QAOA requires 2*depth parameters"
+        );
+        let (gamma, beta) = parameters.split_at(self.depth);
+        self.apply_qaoa_circuit(gamma, beta)
     }
 
     /// Apply QAOA circuit: alternating cost and mixer evolutions
@@ -220,43 +423,14 @@ impl QAOACostFunction {
 
         for layer in 0..self.depth {
             // Cost Hamiltonian evolution: exp(-iγH_C)
-            let cost_evolution =
-                self.create_evolution_operator(&self.cost_hamiltonian, gamma[layer]);
-            state = state.apply(&cost_evolution);
+            state = state.apply(&self.cost_propagator.evolution(gamma[layer]));
 
             // Mixer Hamiltonian evolution: exp(-iβB)
-            let mixer_evolution =
-                self.create_evolution_operator(&self.mixer_hamiltonian, beta[layer]);
-            state = state.apply(&mixer_evolution);
+            state = state.apply(&self.mixer_propagator.evolution(beta[layer]));
         }
 
         state
     }
-
-    /// Create time evolution operator exp(-iHt) using first-order approximation
-    fn create_evolution_operator(
-        &self,
-        hamiltonian: &QuantumOperator,
-        time: f64,
-    ) -> QuantumOperator {
-        // For simplicity: exp(-iHt) ≈ I - iHt (first order)
-        // For production: use proper matrix exponential
-        let matrix = hamiltonian.matrix();
-        let mut evolved = *matrix;
-
-        for i in 0..evolved.nrows() {
-            for j in 0..evolved.ncols() {
-                if i == j {
-                    evolved[(i, j)] =
-                        Complex64::new(1.0, 0.0) - Complex64::new(0.0, 1.0) * matrix[(i, j)] * time;
-                } else {
-                    evolved[(i, j)] = -Complex64::new(0.0, 1.0) * matrix[(i, j)] * time;
-                }
-            }
-        }
-
-        QuantumOperator::from_matrix(evolved)
-    }
 }
 
 impl CostFunction for QAOACostFunction {
@@ -268,12 +442,8 @@ impl CostFunction for QAOACostFunction {
         );
 
         // Check cache
-        let key = self.params_to_key(parameters);
-        {
-            let cache = self.cache.lock().unwrap();
-            if let Some(&value) = cache.get(&key) {
-                return value;
-            }
+        if let Some(value) = self.cache.get(parameters) {
+            return value;
         }
 
         let (gamma, beta) = parameters.split_at(self.depth);
@@ -283,40 +453,21 @@ impl CostFunction for QAOACostFunction {
         let expectation = state.expectation_value(&self.cost_hamiltonian);
         let cost = expectation.re;
 
-        // Cache result
-        {
-            let mut cache = self.cache.lock().unwrap();
-            cache.insert(key, cost);
-        }
+        self.cache.insert(parameters, cost);
 
         cost
     }
 
     fn gradient(&self, parameters: &[f64], method: GradientMethod) -> ParameterVector {
-        match method {
-            GradientMethod::ParameterShift => (0..parameters.len())
-                .into_par_iter()
-                .map(|i| {
-                    let mut params_plus = parameters.to_vec();
-                    let mut params_minus = parameters.to_vec();
-                    params_plus[i] += PI / 2.0;
-                    params_minus[i] -= PI / 2.0;
-                    (self.evaluate(&params_plus) - self.evaluate(&params_minus)) / 2.0
-                })
-                .collect(),
-            GradientMethod::FiniteDifference => {
-                let h = 1e-7;
-                (0..parameters.len())
-                    .into_par_iter()
-                    .map(|i| {
-                        let mut params_plus = parameters.to_vec();
-                        params_plus[i] += h;
-                        (self.evaluate(&params_plus) - self.evaluate(parameters)) / h
-                    })
-                    .collect()
-            }
-            GradientMethod::None => vec![0.0; parameters.len()],
-        }
+        shift_gradient_with_value(self, parameters, method).1
+    }
+
+    fn gradient_with_value(
+        &self,
+        parameters: &[f64],
+        method: GradientMethod,
+    ) -> (f64, ParameterVector) {
+        shift_gradient_with_value(self, parameters, method)
     }
 
     fn dimension(&self) -> usize {
@@ -324,107 +475,130 @@ impl CostFunction for QAOACostFunction {
     }
 }
 
+/// Class probabilities read out from node-basis measurement probabilities.
+///
+/// Class `c < num_classes − 1` is the probability of measuring node `c`; the
+/// last class collects every remaining node. For two classes this is
+/// P(|0⟩) versus 1 − P(|0⟩).
+pub fn class_probabilities(probabilities: &[f64], num_classes: usize) -> Vec<f64> {
+    let last = num_classes - 1;
+    let mut classes = probabilities[..last].to_vec();
+    classes.push(probabilities[last..].iter().sum());
+    classes
+}
+
 /// VQC Cost Function: Classification loss
 ///
-/// Binary cross-entropy for quantum classification
+/// Categorical cross-entropy −Σ_c t_c ln q_c between target class
+/// distributions t and the binned read-out q of [`class_probabilities`];
+/// with two classes it reduces to binary cross-entropy.
 pub struct VQCCostFunction<A: Ansatz> {
     ansatz: A,
     training_data: Vec<QuantumState>,
-    training_labels: Vec<f64>,
-    cache: Arc<Mutex<HashMap<String, f64>>>,
+    targets: Vec<Vec<f64>>,
+    cache: EvaluationCache,
 }
 
 impl<A: Ansatz> VQCCostFunction<A> {
+    /// Binary classifier; `training_labels` holds the target P(class 0).
     pub fn new(ansatz: A, training_data: Vec<QuantumState>, training_labels: Vec<f64>) -> Self {
         assert_eq!(training_data.len(), training_labels.len());
+        let targets = training_labels.iter().map(|&p| vec![p, 1.0 - p]).collect();
         Self {
             ansatz,
             training_data,
-            training_labels,
-            cache: Arc::new(Mutex::new(HashMap::new())),
+            targets,
+            cache: EvaluationCache::default(),
         }
     }
 
-    fn params_to_key(&self, parameters: &[f64]) -> String {
-        parameters
+    /// Classifier over `num_classes` classes (2 to 13) with integer labels.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_classes` is out of range or a label is not below it.
+    pub fn multiclass(
+        ansatz: A,
+        training_data: Vec<QuantumState>,
+        training_labels: &[usize],
+        num_classes: usize,
+    ) -> Self {
+        assert_eq!(training_data.len(), training_labels.len());
+        assert!(
+            (2..=METATRON_DIMENSION).contains(&num_classes),
+            "num_classes must be between 2 and {}",
+            METATRON_DIMENSION
+        );
+        let targets = training_labels
             .iter()
-            .map(|p| format!("{:.10}", p))
-            .collect::<Vec<_>>()
-            .join(",")
+            .map(|&label| {
+                assert!(label < num_classes, "label {} out of range", label);
+                let mut target = vec![0.0; num_classes];
+                target[label] = 1.0;
+                target
+            })
+            .collect();
+        Self {
+            ansatz,
+            training_data,
+            targets,
+            cache: EvaluationCache::default(),
+        }
     }
 
-    /// Predict probability for class 0
-    fn predict(&self, state: &QuantumState, parameters: &[f64]) -> f64 {
-        let output_state = self.ansatz.apply(state, parameters);
-        let probs = output_state.probabilities();
-        probs[0] // Probability of measuring |0⟩
+    /// Memoise up to `capacity` evaluations in an LRU cache (0 disables it).
+    ///
+    /// See [`EvaluationCache`] for when caching is worthwhile.
+    pub fn with_cache_capacity(mut self, capacity: usize) -> Self {
+        self.cache = EvaluationCache::new(capacity);
+        self
     }
 
-    /// Binary cross-entropy loss
-    fn binary_cross_entropy(&self, prediction: f64, label: f64) -> f64 {
+    /// Categorical cross-entropy of one sample
+    fn cross_entropy(&self, state: &QuantumState, parameters: &[f64], target: &[f64]) -> f64 {
         let epsilon = 1e-10;
-        let pred = prediction.clamp(epsilon, 1.0 - epsilon);
-        -label * pred.ln() - (1.0 - label) * (1.0 - pred).ln()
+        let output_state = self.ansatz.apply(state, parameters);
+        let predicted = class_probabilities(&output_state.probabilities(), target.len());
+        target
+            .iter()
+            .zip(predicted.iter())
+            .filter(|(t, _)| **t > 0.0)
+            .map(|(t, q)| -t * q.clamp(epsilon, 1.0).ln())
+            .sum()
     }
 }
 
 impl<A: Ansatz> CostFunction for VQCCostFunction<A> {
     fn evaluate(&self, parameters: &[f64]) -> f64 {
         // Check cache
-        let key = self.params_to_key(parameters);
-        {
-            let cache = self.cache.lock().unwrap();
-            if let Some(&value) = cache.get(&key) {
-                return value;
-            }
+        if let Some(value) = self.cache.get(parameters) {
+            return value;
         }
 
         let total_loss: f64 = self
             .training_data
             .iter()
-            .zip(self.training_labels.iter())
-            .map(|(state, &label)| {
-                let prediction = self.predict(state, parameters);
-                self.binary_cross_entropy(prediction, label)
-            })
+            .zip(self.targets.iter())
+            .map(|(state, target)| self.cross_entropy(state, parameters, target))
             .sum();
 
         let avg_loss = total_loss / self.training_data.len() as f64;
 
-        // Cache result
-        {
-            let mut cache = self.cache.lock().unwrap();
-            cache.insert(key, avg_loss);
-        }
+        self.cache.insert(parameters, avg_loss);
 
         avg_loss
     }
 
     fn gradient(&self, parameters: &[f64], method: GradientMethod) -> ParameterVector {
-        match method {
-            GradientMethod::ParameterShift => (0..parameters.len())
-                .into_par_iter()
-                .map(|i| {
-                    let mut params_plus = parameters.to_vec();
-                    let mut params_minus = parameters.to_vec();
-                    params_plus[i] += PI / 2.0;
-                    params_minus[i] -= PI / 2.0;
-                    (self.evaluate(&params_plus) - self.evaluate(&params_minus)) / 2.0
-                })
-                .collect(),
-            GradientMethod::FiniteDifference => {
-                let h = 1e-7;
-                (0..parameters.len())
-                    .into_par_iter()
-                    .map(|i| {
-                        let mut params_plus = parameters.to_vec();
-                        params_plus[i] += h;
-                        (self.evaluate(&params_plus) - self.evaluate(parameters)) / h
-                    })
-                    .collect()
-            }
-            GradientMethod::None => vec![0.0; parameters.len()],
-        }
+        shift_gradient_with_value(self, parameters, method).1
+    }
+
+    fn gradient_with_value(
+        &self,
+        parameters: &[f64],
+        method: GradientMethod,
+    ) -> (f64, ParameterVector) {
+        shift_gradient_with_value(self, parameters, method)
     }
 
     fn dimension(&self) -> usize {
@@ -471,4 +645,144 @@ mod tests {
         assert_eq!(gradient.len(), parameters.len());
         assert!(gradient.iter().all(|g| g.is_finite()));
     }
+
+    #[test]
+    fn test_gradient_with_value_matches_separate_calls() {
+        let graph = MetatronGraph::new();
+        let hamiltonian = Arc::new(MetatronHamiltonian::new(&graph, &QSOParameters::default()));
+        let ansatz = create_ansatz(AnsatzType::HardwareEfficient, 1);
+        let cost_fn =
+            VQECostFunction::new(hamiltonian, ansatz, QuantumState::uniform_superposition());
+        let parameters: Vec<f64> = (0..cost_fn.dimension()).map(|i| 0.05 * i as f64).collect();
+
+        for method in [
+            GradientMethod::ParameterShift,
+            GradientMethod::FiniteDifference,
+        ] {
+            let (value, gradient) = cost_fn.gradient_with_value(&parameters, method.clone());
+            assert_eq!(value, cost_fn.evaluate(&parameters));
+            assert_eq!(gradient, cost_fn.gradient(&parameters, method));
+        }
+    }
+
+    #[test]
+    fn test_adjoint_gradient_matches_finite_differences() {
+        let graph = MetatronGraph::new();
+        let hamiltonian = Arc::new(MetatronHamiltonian::new(&graph, &QSOParameters::default()));
+        let cases = [
+            (AnsatzType::HardwareEfficient, false),
+            (AnsatzType::EfficientSU2, false),
+            (AnsatzType::Metatron, false),
+            (AnsatzType::Metatron, true),
+        ];
+
+        // The rotations act on two of the 13 levels and leave the rest alone,
+        // so the π/2 shift rule is only approximate here; central
+        // differences are the reference instead.
+        for (ansatz_type, project) in cases {
+            let mut cost_fn = VQECostFunction::new(
+                hamiltonian.clone(),
+                create_ansatz(ansatz_type, 2),
+                QuantumState::basis_state(1).unwrap(),
+            );
+            if project {
+                cost_fn = cost_fn.with_symmetry_projection(graph.symmetry_operators());
+            }
+            let theta: Vec<f64> = (0..cost_fn.dimension())
+                .map(|i| (0.37 * i as f64).sin())
+                .collect();
+            let (value, adjoint) = cost_fn.gradient_with_value(&theta, GradientMethod::Adjoint);
+            assert!((value - cost_fn.evaluate(&theta)).abs() < 1e-12);
+
+            let h = 1e-6;
+            for (i, a) in adjoint.iter().enumerate() {
+                let mut plus = theta.clone();
+                let mut minus = theta.clone();
+                plus[i] += h;
+                minus[i] -= h;
+                let central = (cost_fn.evaluate(&plus) - cost_fn.evaluate(&minus)) / (2.0 * h);
+                assert!(
+                    (a - central).abs() < 1e-6,
+                    "adjoint {a} vs central {central}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_evaluate_batch_matches_single_evaluations() {
+        let graph = MetatronGraph::new();
+        let hamiltonian = Arc::new(MetatronHamiltonian::new(&graph, &QSOParameters::default()));
+        let make = || {
+            VQECostFunction::new(
+                hamiltonian.clone(),
+                create_ansatz(AnsatzType::Metatron, 1),
+                QuantumState::uniform_superposition(),
+            )
+        };
+        let cost_fn = make().with_cache_capacity(16);
+        let sets: Vec<Vec<f64>> = (0..5)
+            .map(|k| {
+                (0..cost_fn.dimension())
+                    .map(|i| 0.1 * (k * i) as f64)
+                    .collect()
+            })
+            .collect();
+
+        // Prime the cache for one entry so the batch mixes hits and misses.
+        let first = cost_fn.evaluate(&sets[2]);
+        let batch = cost_fn.evaluate_batch(&sets);
+        assert_eq!(batch[2], first);
+
+        let fresh = make();
+        let single: Vec<f64> = sets.iter().map(|p| fresh.evaluate(p)).collect();
+        assert_eq!(batch, single);
+    }
+
+    #[test]
+    fn test_symmetry_projected_vqe_stays_in_sector() {
+        use crate::vqa::optimizer::{Optimizer, OptimizerConfig, OptimizerType};
+
+        // -L on the Metatron graph: ground energy -13 is 12-fold degenerate
+        let graph = MetatronGraph::new();
+        let hamiltonian = Arc::new(MetatronHamiltonian::new(&graph, &QSOParameters::default()));
+        let ground = hamiltonian.spectrum_info().eigenvalues[0];
+        let ops = graph.symmetry_operators();
+        let make = |project: bool| {
+            let cost_fn = VQECostFunction::new(
+                hamiltonian.clone(),
+                create_ansatz(AnsatzType::Metatron, 1),
+                QuantumState::basis_state(1).unwrap(),
+            );
+            if project {
+                cost_fn.with_symmetry_projection(ops.clone())
+            } else {
+                cost_fn
+            }
+        };
+
+        let initial: Vec<f64> = (0..make(false).dimension())
+            .map(|i| (0.37 * (i + 1) as f64).sin())
+            .collect();
+        let config = OptimizerConfig {
+            max_iterations: 200,
+            learning_rate: 0.05,
+            energy_tolerance: 1e-12,
+            gradient_method: GradientMethod::FiniteDifference,
+            ..Default::default()
+        };
+
+        let in_sector = |state: &QuantumState| {
+            ops.iter()
+                .all(|op| (op.apply(state.amplitudes()) - state.amplitudes()).norm() < 1e-8)
+        };
+        for project in [false, true] {
+            let cost_fn = Arc::new(make(project));
+            let result = Optimizer::new(OptimizerType::Adam, config.clone())
+                .optimize(cost_fn.clone(), initial.clone());
+            assert!((result.optimal_cost - ground).abs() < 1e-6);
+            let state = cost_fn.state(&result.optimal_parameters);
+            assert_eq!(in_sector(&state), project);
+        }
+    }
 }
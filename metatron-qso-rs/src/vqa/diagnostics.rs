@@ -0,0 +1,145 @@
+//! Trainability Diagnostics for Variational Ansätze
+//!
+//! Barren plateaus show up as an exponentially small variance of the cost
+//! gradient over random parameter initialisations. Sampling that variance
+//! before a long optimisation run helps choose an ansatz depth that is still
+//! trainable.
+
+use crate::vqa::ansatz::Ansatz;
+use crate::vqa::cost_function::CostFunction;
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+use std::f64::consts::PI;
+
+/// Default variance below which a landscape is flagged as a likely barren plateau.
+pub const DEFAULT_BARREN_THRESHOLD: f64 = 1e-4;
+
+/// Statistics of one gradient component over random parameter samples.
+#[derive(Clone, Debug)]
+pub struct GradientVarianceReport {
+    /// Index of the sampled gradient component
+    pub component: usize,
+    /// Number of random parameter vectors drawn
+    pub num_samples: usize,
+    /// Sample mean of ∂C/∂θ_component
+    pub mean: f64,
+    /// Sample variance of ∂C/∂θ_component
+    pub variance: f64,
+}
+
+impl GradientVarianceReport {
+    /// Whether the gradient variance is below `threshold`.
+    pub fn is_likely_barren(&self, threshold: f64) -> bool {
+        self.variance < threshold
+    }
+}
+
+/// Variance of the first gradient component over `num_samples` random
+/// parameter vectors drawn uniformly from [0, 2π).
+pub fn gradient_variance<C, A>(cost_fn: &C, ansatz: &A, num_samples: usize) -> f64
+where
+    C: CostFunction + ?Sized,
+    A: Ansatz + ?Sized,
+{
+    gradient_variance_report(cost_fn, ansatz, num_samples, 0, None).variance
+}
+
+/// Sample the gradient component `component` and report its mean and variance.
+///
+/// Each sample uses the parameter-shift rule on that component only, i.e.
+/// two cost evaluations per sample. `seed` makes the sampling reproducible.
+pub fn gradient_variance_report<C, A>(
+    cost_fn: &C,
+    ansatz: &A,
+    num_samples: usize,
+    component: usize,
+    seed: Option<u64>,
+) -> GradientVarianceReport
+where
+    C: CostFunction + ?Sized,
+    A: Ansatz + ?Sized,
+{
+    let num_parameters = ansatz.num_parameters();
+    assert_eq!(
+        num_parameters,
+        cost_fn.dimension(),
+        "ansatz and cost function disagree on the parameter count"
+    );
+    assert!(
+        component < num_parameters,
+        "gradient component {} out of range for {} parameters",
+        component,
+        num_parameters
+    );
+
+    let mut rng = match seed {
+        Some(seed) => SmallRng::seed_from_u64(seed),
+        None => SmallRng::from_entropy(),
+    };
+
+    let samples: Vec<f64> = (0..num_samples)
+        .map(|_| {
+            let mut params: Vec<f64> = (0..num_parameters)
+                .map(|_| rng.gen_range(0.0..2.0 * PI))
+                .collect();
+            let original = params[component];
+            params[component] = original + PI / 2.0;
+            let f_plus = cost_fn.evaluate(&params);
+            params[component] = original - PI / 2.0;
+            let f_minus = cost_fn.evaluate(&params);
+            (f_plus - f_minus) / 2.0
+        })
+        .collect();
+
+    let n = samples.len().max(1) as f64;
+    let mean = samples.iter().sum::<f64>() / n;
+    let variance = samples.iter().map(|g| (g - mean).powi(2)).sum::<f64>() / n;
+
+    GradientVarianceReport {
+        component,
+        num_samples,
+        mean,
+        variance,
+    }
+}
+
+/// Whether the first gradient component's variance falls below `threshold`
+/// ([`DEFAULT_BARREN_THRESHOLD`] is a reasonable starting point).
+pub fn is_likely_barren<C, A>(cost_fn: &C, ansatz: &A, num_samples: usize, threshold: f64) -> bool
+where
+    C: CostFunction + ?Sized,
+    A: Ansatz + ?Sized,
+{
+    gradient_variance(cost_fn, ansatz, num_samples) < threshold
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::metatron::MetatronGraph;
+    use crate::hamiltonian::MetatronHamiltonian;
+    use crate::params::QSOParameters;
+    use crate::quantum::state::QuantumState;
+    use crate::vqa::ansatz::HardwareEfficientAnsatz;
+    use crate::vqa::cost_function::VQECostFunction;
+    use std::sync::Arc;
+
+    #[test]
+    fn gradient_variance_is_reproducible_and_finite() {
+        let graph = MetatronGraph::new();
+        let hamiltonian = Arc::new(MetatronHamiltonian::new(&graph, &QSOParameters::default()));
+        let ansatz = HardwareEfficientAnsatz::new(1);
+        let cost_fn = VQECostFunction::new(
+            hamiltonian,
+            ansatz.clone(),
+            QuantumState::uniform_superposition(),
+        );
+
+        let first = gradient_variance_report(&cost_fn, &ansatz, 16, 3, Some(7));
+        let second = gradient_variance_report(&cost_fn, &ansatz, 16, 3, Some(7));
+        assert_eq!(first.variance, second.variance);
+        assert!(first.variance.is_finite() && first.variance >= 0.0);
+        assert!(first.is_likely_barren(f64::INFINITY));
+        assert!(!first.is_likely_barren(0.0));
+    }
+}
@@ -7,6 +7,7 @@
 //! ## Algorithms
 //!
 //! - **VQE (Variational Quantum Eigensolver)**: Find ground state energies
+//! - **VQD (Variational Quantum Deflation)**: Find low-lying excited states
 //! - **QAOA (Quantum Approximate Optimization Algorithm)**: Solve combinatorial problems
 //! - **VQC (Variational Quantum Classifier)**: Quantum machine learning
 //!
@@ -14,33 +15,49 @@
 //!
 //! - **Ansatz**: Parametrized quantum circuits
 //! - **Cost Functions**: Problem-specific objectives with gradient computation
-//! - **Optimizers**: Classical optimization algorithms (COBYLA, ADAM, L-BFGS-B)
+//! - **Evaluation Cache**: Optional bounded LRU memoisation of cost evaluations
+//! - **Diagnostics**: Gradient-variance checks for barren plateaus
+//! - **Optimizers**: Classical optimization algorithms (ADAM, L-BFGS, Nelder-Mead, SPSA, COBYLA, TRITON)
 //! - **Hybrid Loop**: Orchestration of quantum-classical iterations
 
+use serde::{Deserialize, Serialize};
+
 pub mod ansatz;
+pub mod cache;
 pub mod cost_function;
+pub mod diagnostics;
 pub mod optimizer;
 pub mod qaoa;
 pub mod vqc;
+pub mod vqd;
 pub mod vqe;
 
 pub use ansatz::{
-    Ansatz, AnsatzType, EfficientSU2Ansatz, EntanglementStrategy, HardwareEfficientAnsatz,
-    MetatronAnsatz,
+    Ansatz, AnsatzGate, AnsatzType, EfficientSU2Ansatz, EntanglementStrategy,
+    HardwareEfficientAnsatz, InitStrategy, MetatronAnsatz, ParticleConservingAnsatz,
 };
+pub use cache::EvaluationCache;
 pub use cost_function::{
     CostFunction, GradientMethod, QAOACostFunction, VQCCostFunction, VQECostFunction,
 };
-pub use optimizer::{OptimizationResult, Optimizer, OptimizerConfig, OptimizerType};
-pub use qaoa::QAOA;
+pub use diagnostics::{GradientVarianceReport, gradient_variance, gradient_variance_report};
+#[cfg(feature = "triton")]
+pub use optimizer::TritonConfig;
+pub use optimizer::{
+    CallbackAction, CobylaConfig, IterationCallback, OptimizationResult, Optimizer,
+    OptimizerConfig, OptimizerMemory, OptimizerState, OptimizerType, ResumeError, SpsaConfig,
+    StopReason,
+};
+pub use qaoa::{QAOA, ring_mixer, sign_pattern_state, xy_mixer};
 pub use vqc::VQC;
+pub use vqd::VQD;
 pub use vqe::VQE;
 
 /// Parameter vector type for variational algorithms
 pub type ParameterVector = Vec<f64>;
 
 /// History entry for optimization tracking
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct HistoryEntry {
     pub iteration: usize,
     pub parameters: ParameterVector,
@@ -50,7 +67,7 @@ pub struct HistoryEntry {
 }
 
 /// Complete optimization history
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct OptimizationHistory {
     pub entries: Vec<HistoryEntry>,
     pub total_quantum_evaluations: usize,
@@ -79,3 +96,98 @@ impl OptimizationHistory {
         self.entries.last().map(|e| e.cost)
     }
 }
+
+/// Outcome of independent restarts from seeded initial parameters.
+///
+/// Start `k` uses seed `seeds[k]`; configuring that seed with a single start
+/// reproduces its run exactly.
+#[derive(Clone, Debug)]
+pub struct MultistartResult<R> {
+    /// Result of the start with the lowest final value.
+    pub best: R,
+    /// Index of `best` within the starts.
+    pub best_start: usize,
+    /// Seed of every start.
+    pub seeds: Vec<u64>,
+    /// Final optimised value of every start.
+    pub final_values: Vec<f64>,
+}
+
+impl<R> MultistartResult<R> {
+    /// Run `num_starts` (at least one) starts with seeds `base_seed + k`.
+    ///
+    /// A start whose final value is NaN (e.g. a diverged optimizer) is only
+    /// chosen as `best` if every start is NaN; ties keep the earliest start.
+    pub(crate) fn collect(
+        base_seed: u64,
+        num_starts: usize,
+        mut run: impl FnMut(u64) -> (R, f64),
+    ) -> Self {
+        let seeds: Vec<u64> = (0..num_starts.max(1) as u64)
+            .map(|k| base_seed.wrapping_add(k))
+            .collect();
+        // Rank NaN after every number, including +∞
+        let rank = |value: f64| if value.is_nan() { None } else { Some(-value) };
+        let mut best: Option<(usize, R, f64)> = None;
+        let mut final_values = Vec::with_capacity(seeds.len());
+        for (start, &seed) in seeds.iter().enumerate() {
+            let (result, value) = run(seed);
+            if best
+                .as_ref()
+                .is_none_or(|&(_, _, best_value)| rank(value) > rank(best_value))
+            {
+                best = Some((start, result, value));
+            }
+            final_values.push(value);
+        }
+        let (best_start, best, _) = best.expect("at least one start");
+        Self {
+            best,
+            best_start,
+            seeds,
+            final_values,
+        }
+    }
+
+    /// Difference between the worst and best final value.
+    pub fn spread(&self) -> f64 {
+        let max = self.final_values.iter().copied().fold(f64::MIN, f64::max);
+        max - self.final_values[self.best_start]
+    }
+
+    /// Mean of the final values.
+    pub fn mean(&self) -> f64 {
+        self.final_values.iter().sum::<f64>() / self.final_values.len() as f64
+    }
+
+    /// Population standard deviation of the final values.
+    pub fn std_dev(&self) -> f64 {
+        let mean = self.mean();
+        (self
+            .final_values
+            .iter()
+            .map(|v| (v - mean).powi(2))
+            .sum::<f64>()
+            / self.final_values.len() as f64)
+            .sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn multistart_never_prefers_nan() {
+        let values = [f64::NAN, 2.0, f64::NAN, -1.0, -1.0];
+        let result = MultistartResult::collect(7, values.len(), |seed| {
+            let value = values[(seed - 7) as usize];
+            (seed, value)
+        });
+        assert_eq!(result.best_start, 3);
+        assert_eq!(result.best, 10);
+
+        let all_nan = MultistartResult::collect(0, 2, |seed| (seed, f64::NAN));
+        assert_eq!(all_nan.best_start, 0);
+    }
+}
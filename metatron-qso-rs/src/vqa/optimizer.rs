@@ -5,19 +5,140 @@
 //! - NelderMead: Gradient-free simplex method
 //! - LBFGS: Limited-memory quasi-Newton method
 //! - GradientDescent: Simple gradient descent with momentum
+//! - SPSA: Simultaneous perturbation stochastic approximation (two evaluations per step)
+//! - COBYLA: Gradient-free linear-model trust region with box bounds
+//! - Triton: Gradient-free golden-angle spiral search (`metatron_triton`,
+//!   feature `triton`)
 
 use crate::vqa::cost_function::{CostFunction, GradientMethod};
 use crate::vqa::{HistoryEntry, OptimizationHistory, ParameterVector};
-use std::sync::Arc;
+#[cfg(feature = "triton")]
+use metatron_triton::{SpectralSignature, TritonSearch};
+use nalgebra::{DMatrix, DVector};
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "triton")]
+use std::cell::Cell;
+use std::sync::{Arc, Mutex};
 use std::time::Instant;
+use thiserror::Error;
 
 /// Optimizer type selection
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum OptimizerType {
     Adam,
     NelderMead,
     LBFGS,
     GradientDescent,
+    SPSA,
+    COBYLA,
+    #[cfg(feature = "triton")]
+    Triton,
+}
+
+/// Gain schedules for SPSA (Spall's standard form).
+///
+/// Step size a_k = a / (k + 1 + A)^α and perturbation c_k = c / (k + 1)^γ.
+#[derive(Clone, Debug)]
+pub struct SpsaConfig {
+    /// Learning-rate numerator `a`.
+    pub a: f64,
+    /// Perturbation magnitude `c`.
+    pub c: f64,
+    /// Learning-rate decay exponent α.
+    pub alpha: f64,
+    /// Perturbation decay exponent γ.
+    pub gamma: f64,
+    /// Stability constant `A` delaying the learning-rate decay.
+    pub stability: f64,
+    /// Seed for the Rademacher perturbations (`None` draws from entropy).
+    pub seed: Option<u64>,
+}
+
+impl Default for SpsaConfig {
+    fn default() -> Self {
+        Self {
+            a: 0.2,
+            c: 0.1,
+            alpha: 0.602,
+            gamma: 0.101,
+            stability: 10.0,
+            seed: None,
+        }
+    }
+}
+
+impl SpsaConfig {
+    /// Learning rate a_k at iteration `k`.
+    pub fn learning_rate(&self, k: usize) -> f64 {
+        self.a / (k as f64 + 1.0 + self.stability).powf(self.alpha)
+    }
+
+    /// Perturbation size c_k at iteration `k`.
+    pub fn perturbation(&self, k: usize) -> f64 {
+        self.c / (k as f64 + 1.0).powf(self.gamma)
+    }
+}
+
+/// Trust-region radii for COBYLA.
+///
+/// The radius ρ starts at `rho_begin` and is halved whenever the linear model
+/// fails to produce an improvement; the run stops once ρ < `rho_end`.
+#[derive(Clone, Debug)]
+pub struct CobylaConfig {
+    pub rho_begin: f64,
+    pub rho_end: f64,
+}
+
+impl Default for CobylaConfig {
+    fn default() -> Self {
+        Self {
+            rho_begin: 0.5,
+            rho_end: 1e-6,
+        }
+    }
+}
+
+/// Search box, cost mapping and restarts for TRITON spiral search.
+///
+/// The spiral explores the unit cube; each coordinate is mapped linearly onto
+/// `OptimizerConfig::bounds`, or onto `initial ± search_radius` when no bounds
+/// are set. A cost becomes the quality ψ = 1 / (1 + exp((cost − c₀) / cost_scale))
+/// of a spectral signature with ρ = ω = 1, where c₀ is the best cost when the
+/// spiral started, so its resonance rises as the cost falls. `cost_scale`
+/// should be of the order of the cost differences that matter; much smaller
+/// values saturate ψ.
+///
+/// The spiral widens as it runs, so once its best resonance stalls for
+/// `stagnation_window` steps it is restarted on the best point inside a box
+/// shrunk by `shrink_factor` (clipped to the bounds).
+#[cfg(feature = "triton")]
+#[derive(Clone, Debug)]
+pub struct TritonConfig {
+    /// Half-width of the search box around the initial parameters.
+    pub search_radius: f64,
+    /// Cost difference that moves ψ from 0.5 to ≈0.73.
+    pub cost_scale: f64,
+    /// Steps without resonance gain before the spiral restarts.
+    pub stagnation_window: usize,
+    /// Factor applied to the box half-widths on every restart.
+    pub shrink_factor: f64,
+    /// Seed of the spiral's exploration noise; restart `k` uses `seed + k`.
+    pub seed: u64,
+}
+
+#[cfg(feature = "triton")]
+impl Default for TritonConfig {
+    fn default() -> Self {
+        Self {
+            search_radius: std::f64::consts::PI,
+            cost_scale: 1.0,
+            stagnation_window: 50,
+            shrink_factor: 0.8,
+            seed: 42,
+        }
+    }
 }
 
 /// Configuration for optimizers
@@ -31,6 +152,22 @@ pub struct OptimizerConfig {
     pub learning_rate: f64,
     pub gradient_method: GradientMethod,
     pub verbose: bool,
+    /// Gain schedules used by [`OptimizerType::SPSA`]
+    pub spsa: SpsaConfig,
+    /// Trust-region radii used by [`OptimizerType::COBYLA`]
+    pub cobyla: CobylaConfig,
+    /// Search box and cost mapping used by [`OptimizerType::Triton`]
+    #[cfg(feature = "triton")]
+    pub triton: TritonConfig,
+    /// Optional per-parameter box constraints (lower, upper), one pair per
+    /// parameter. Every optimizer projects its iterates back into the box after
+    /// each update; `None` keeps the unbounded behaviour.
+    pub bounds: Option<Vec<(f64, f64)>>,
+    /// Stop once the best cost has not improved by more than `min_delta` for
+    /// this many consecutive iterations (`None` disables early stopping)
+    pub patience: Option<usize>,
+    /// Minimum decrease of the best cost that resets the patience counter
+    pub min_delta: f64,
 }
 
 impl Default for OptimizerConfig {
@@ -43,24 +180,142 @@ impl Default for OptimizerConfig {
             learning_rate: 0.01,
             gradient_method: GradientMethod::ParameterShift,
             verbose: true,
+            spsa: SpsaConfig::default(),
+            cobyla: CobylaConfig::default(),
+            #[cfg(feature = "triton")]
+            triton: TritonConfig::default(),
+            bounds: None,
+            patience: None,
+            min_delta: 0.0,
         }
     }
 }
 
 /// Optimization result
-#[derive(Clone, Debug)]
+///
+/// Serializable, so an unconverged result doubles as a checkpoint for
+/// [`Optimizer::resume_from`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct OptimizationResult {
     pub optimal_parameters: ParameterVector,
     pub optimal_cost: f64,
     pub iterations: usize,
-    pub converged: bool,
+    /// Why the run ended; see [`OptimizationResult::converged`]
+    pub stop_reason: StopReason,
     pub history: OptimizationHistory,
+    /// Indices of parameters sitting on a bound at termination
+    /// (always empty when `OptimizerConfig::bounds` is `None`)
+    pub active_bounds: Vec<usize>,
+    /// Internal optimizer state at termination; set for unconverged ADAM,
+    /// L-BFGS and gradient-descent runs, `None` otherwise
+    pub resume_state: Option<OptimizerState>,
+}
+
+impl OptimizationResult {
+    /// Whether the run stopped on a convergence criterion (including early
+    /// stopping) rather than on the iteration limit or a user abort.
+    pub fn converged(&self) -> bool {
+        matches!(
+            self.stop_reason,
+            StopReason::GradientTolerance | StopReason::EnergyTolerance | StopReason::EarlyStopping
+        )
+    }
+}
+
+/// Reason an optimization run terminated.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StopReason {
+    /// The gradient norm fell below `OptimizerConfig::tolerance`. SPSA
+    /// compares its step length and COBYLA its trust-region radius
+    /// (against `CobylaConfig::rho_end`) instead.
+    GradientTolerance,
+    /// The cost change fell below `OptimizerConfig::energy_tolerance`; for
+    /// Nelder–Mead, the cost spread of the simplex fell below `tolerance`.
+    EnergyTolerance,
+    /// The best cost plateaued for `OptimizerConfig::patience` iterations.
+    EarlyStopping,
+    /// `OptimizerConfig::max_iterations` was exhausted.
+    MaxIterations,
+    /// An iteration callback returned [`CallbackAction::Stop`].
+    UserAbort,
+}
+
+/// Snapshot of an optimizer at the start of its next iteration.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct OptimizerState {
+    /// Index of the iteration the resumed run starts with
+    pub next_iteration: usize,
+    /// Current iterate (not necessarily the best parameters seen)
+    pub parameters: ParameterVector,
+    pub memory: OptimizerMemory,
+}
+
+/// Method-specific state carried across iterations.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum OptimizerMemory {
+    Adam {
+        first_moment: Vec<f64>,
+        second_moment: Vec<f64>,
+        prev_cost: f64,
+    },
+    LBFGS {
+        s_list: Vec<ParameterVector>,
+        y_list: Vec<ParameterVector>,
+        prev_gradient: ParameterVector,
+        prev_cost: f64,
+    },
+    GradientDescent {
+        velocity: Vec<f64>,
+    },
 }
 
+impl OptimizerMemory {
+    fn optimizer_type(&self) -> OptimizerType {
+        match self {
+            OptimizerMemory::Adam { .. } => OptimizerType::Adam,
+            OptimizerMemory::LBFGS { .. } => OptimizerType::LBFGS,
+            OptimizerMemory::GradientDescent { .. } => OptimizerType::GradientDescent,
+        }
+    }
+}
+
+/// Errors raised when a checkpoint cannot be resumed.
+#[derive(Debug, Error, PartialEq)]
+pub enum ResumeError {
+    /// The checkpoint has no optimizer state: it converged, or it was produced
+    /// by a method without checkpoint support (SPSA, Nelder–Mead, COBYLA,
+    /// Triton).
+    #[error("checkpoint carries no optimizer state to resume from")]
+    NoResumeState,
+
+    /// The checkpoint was produced by a different optimizer.
+    #[error("checkpoint was produced by {found:?}, cannot resume with {expected:?}")]
+    OptimizerMismatch {
+        expected: OptimizerType,
+        found: OptimizerType,
+    },
+}
+
+/// Decision returned by an iteration callback.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CallbackAction {
+    /// Keep iterating.
+    Continue,
+    /// Abort the run; the best parameters so far are returned unconverged.
+    Stop,
+}
+
+/// Hook invoked with every history entry as it is recorded.
+pub type IterationCallback = Box<dyn FnMut(&HistoryEntry) -> CallbackAction + Send>;
+
+/// Callback shared by all optimizer runs of one algorithm.
+pub(crate) type SharedCallback = Arc<Mutex<IterationCallback>>;
+
 /// Main optimizer orchestrator
 pub struct Optimizer {
     optimizer_type: OptimizerType,
     config: OptimizerConfig,
+    callback: Mutex<Option<IterationCallback>>,
 }
 
 impl Optimizer {
@@ -68,25 +323,124 @@ impl Optimizer {
         Self {
             optimizer_type,
             config,
+            callback: Mutex::new(None),
         }
     }
 
+    /// Attach a callback that observes each iteration.
+    ///
+    /// The callback receives the iteration index, cost, gradient norm (if the
+    /// method computes one) and parameters, and may return
+    /// [`CallbackAction::Stop`] to end the run early. It complements the
+    /// `verbose` console output rather than replacing it.
+    pub fn with_callback<F>(mut self, callback: F) -> Self
+    where
+        F: FnMut(&HistoryEntry) -> CallbackAction + Send + 'static,
+    {
+        self.callback = Mutex::new(Some(Box::new(callback)));
+        self
+    }
+
+    /// Attach `callback`, if any, keeping it shared with other optimizers.
+    pub(crate) fn with_shared_callback(self, callback: Option<&SharedCallback>) -> Self {
+        match callback {
+            Some(callback) => {
+                let callback = Arc::clone(callback);
+                self.with_callback(move |entry| (callback.lock().unwrap())(entry))
+            }
+            None => self,
+        }
+    }
+
+    /// Append an entry to the history and notify the callback.
+    ///
+    /// Returns `true` if the callback requested a stop.
+    fn record(&self, history: &mut OptimizationHistory, entry: HistoryEntry) -> bool {
+        let action = match self.callback.lock().unwrap().as_mut() {
+            Some(callback) => callback(&entry),
+            None => CallbackAction::Continue,
+        };
+        history.add_entry(entry);
+        action == CallbackAction::Stop
+    }
+
     /// Run optimization with given cost function
     pub fn optimize(
         &self,
         cost_function: Arc<dyn CostFunction>,
         initial_parameters: ParameterVector,
     ) -> OptimizationResult {
-        match self.optimizer_type {
-            OptimizerType::Adam => self.optimize_adam(cost_function, initial_parameters),
+        let bounds = self.config.bounds.as_deref();
+        if let Some(bounds) = bounds {
+            assert_eq!(
+                bounds.len(),
+                initial_parameters.len(),
+                "bounds must provide one (lower, upper) pair per parameter"
+            );
+        }
+        let initial_parameters = project_onto_bounds(&initial_parameters, bounds);
+
+        let mut result = match self.optimizer_type {
+            OptimizerType::Adam => self.optimize_adam(cost_function, initial_parameters, None),
             OptimizerType::NelderMead => {
                 self.optimize_nelder_mead(cost_function, initial_parameters)
             }
-            OptimizerType::LBFGS => self.optimize_lbfgs(cost_function, initial_parameters),
+            OptimizerType::LBFGS => self.optimize_lbfgs(cost_function, initial_parameters, None),
             OptimizerType::GradientDescent => {
-                self.optimize_gradient_descent(cost_function, initial_parameters)
+                self.optimize_gradient_descent(cost_function, initial_parameters, None)
             }
+            OptimizerType::SPSA => self.optimize_spsa(cost_function, initial_parameters),
+            OptimizerType::COBYLA => self.optimize_cobyla(cost_function, initial_parameters),
+            #[cfg(feature = "triton")]
+            OptimizerType::Triton => self.optimize_triton(cost_function, initial_parameters),
+        };
+        result.active_bounds = active_bound_indices(&result.optimal_parameters, bounds);
+        result
+    }
+
+    /// Continue an interrupted run from a checkpoint.
+    ///
+    /// `checkpoint` is an unconverged [`OptimizationResult`] (e.g. stopped by
+    /// a callback or by `max_iterations`, possibly serialized in between).
+    /// Iteration resumes at `resume_state.next_iteration` and runs until this
+    /// optimizer's `max_iterations`, appending to the saved history. With the
+    /// same configuration and cost function the outcome is numerically
+    /// identical to an uninterrupted run.
+    ///
+    /// Checkpoints usually come from disk, so a converged or foreign
+    /// checkpoint is reported as a [`ResumeError`] rather than a panic.
+    pub fn resume_from(
+        &self,
+        checkpoint: &OptimizationResult,
+        cost_function: Arc<dyn CostFunction>,
+    ) -> Result<OptimizationResult, ResumeError> {
+        let state = checkpoint
+            .resume_state
+            .as_ref()
+            .ok_or(ResumeError::NoResumeState)?;
+        let found = state.memory.optimizer_type();
+        if found != self.optimizer_type {
+            return Err(ResumeError::OptimizerMismatch {
+                expected: self.optimizer_type.clone(),
+                found,
+            });
         }
+
+        let parameters = state.parameters.clone();
+        let mut result = match state.memory {
+            OptimizerMemory::Adam { .. } => {
+                self.optimize_adam(cost_function, parameters, Some(checkpoint))
+            }
+            OptimizerMemory::LBFGS { .. } => {
+                self.optimize_lbfgs(cost_function, parameters, Some(checkpoint))
+            }
+            OptimizerMemory::GradientDescent { .. } => {
+                self.optimize_gradient_descent(cost_function, parameters, Some(checkpoint))
+            }
+        };
+        let bounds = self.config.bounds.as_deref();
+        result.active_bounds = active_bound_indices(&result.optimal_parameters, bounds);
+        Ok(result)
     }
 
     /// ADAM Optimizer (Adaptive Moment Estimation)
@@ -94,6 +448,7 @@ impl Optimizer {
         &self,
         cost_function: Arc<dyn CostFunction>,
         initial_parameters: ParameterVector,
+        checkpoint: Option<&OptimizationResult>,
     ) -> OptimizationResult {
         let start_time = Instant::now();
         let mut params = initial_parameters.clone();
@@ -111,11 +466,35 @@ impl Optimizer {
         let mut best_cost = f64::INFINITY;
         let mut best_params = params.clone();
         let mut prev_cost = f64::INFINITY;
+        let mut start_iteration = 0;
+        if let Some(checkpoint) = checkpoint
+            && let Some(OptimizerState {
+                next_iteration,
+                memory:
+                    OptimizerMemory::Adam {
+                        first_moment,
+                        second_moment,
+                        prev_cost: saved_cost,
+                    },
+                ..
+            }) = &checkpoint.resume_state
+        {
+            m = first_moment.clone();
+            v = second_moment.clone();
+            prev_cost = *saved_cost;
+            best_cost = checkpoint.optimal_cost;
+            best_params = checkpoint.optimal_parameters.clone();
+            history = checkpoint.history.clone();
+            start_iteration = *next_iteration;
+        }
+        let bounds = self.config.bounds.as_deref();
+        let mut plateau = EarlyStopping::new(&self.config).replay(&history);
 
-        for iter in 0..self.config.max_iterations {
+        for iter in start_iteration..self.config.max_iterations {
             // Evaluate cost and gradient
-            let cost = cost_function.evaluate(&params);
-            let gradient = cost_function.gradient(&params, self.config.gradient_method.clone());
+            let (cost, gradient) =
+                cost_function.gradient_with_value(&params, self.config.gradient_method.clone());
+            let gradient_norm = projected_gradient_norm(&params, &gradient, bounds);
 
             // Update biased moment estimates
             for i in 0..params.len() {
@@ -137,6 +516,7 @@ impl Optimizer {
             for i in 0..params.len() {
                 params[i] -= alpha * m_hat[i] / (v_hat[i].sqrt() + epsilon);
             }
+            params = project_onto_bounds(&params, bounds);
 
             // Track best solution
             if cost < best_cost {
@@ -145,14 +525,16 @@ impl Optimizer {
             }
 
             // Record history
-            let gradient_norm = gradient.iter().map(|g| g * g).sum::<f64>().sqrt();
-            history.add_entry(HistoryEntry {
-                iteration: iter,
-                parameters: params.clone(),
-                cost,
-                gradient_norm: Some(gradient_norm),
-                elapsed_time: start_time.elapsed().as_secs_f64(),
-            });
+            let stop = self.record(
+                &mut history,
+                HistoryEntry {
+                    iteration: iter,
+                    parameters: params.clone(),
+                    cost,
+                    gradient_norm: Some(gradient_norm),
+                    elapsed_time: start_time.elapsed().as_secs_f64(),
+                },
+            );
             history.total_quantum_evaluations += 1 + params.len() * 2; // Cost + gradient evals
 
             // Verbose output
@@ -172,32 +554,83 @@ impl Optimizer {
             let energy_converged = iter > 0 && energy_change < self.config.energy_tolerance;
 
             if gradient_converged || energy_converged {
+                let stop_reason = if gradient_converged {
+                    StopReason::GradientTolerance
+                } else {
+                    StopReason::EnergyTolerance
+                };
                 if self.config.verbose {
-                    let reason = if gradient_converged {
-                        "gradient"
-                    } else {
-                        "energy"
-                    };
-                    println!("Converged ({}) after {} iterations", reason, iter + 1);
+                    println!(
+                        "Converged ({:?}) after {} iterations",
+                        stop_reason,
+                        iter + 1
+                    );
                 }
                 return OptimizationResult {
                     optimal_parameters: best_params,
                     optimal_cost: best_cost,
                     iterations: iter + 1,
-                    converged: true,
+                    stop_reason,
                     history,
+                    active_bounds: Vec::new(),
+                    resume_state: None,
+                };
+            }
+
+            if plateau.update(cost) {
+                if self.config.verbose {
+                    println!("Early stopping after {} iterations", iter + 1);
+                }
+                return OptimizationResult {
+                    optimal_parameters: best_params,
+                    optimal_cost: best_cost,
+                    iterations: iter + 1,
+                    stop_reason: StopReason::EarlyStopping,
+                    history,
+                    active_bounds: Vec::new(),
+                    resume_state: None,
                 };
             }
 
             prev_cost = cost;
+
+            if stop {
+                return OptimizationResult {
+                    optimal_parameters: best_params,
+                    optimal_cost: best_cost,
+                    iterations: iter + 1,
+                    stop_reason: StopReason::UserAbort,
+                    history,
+                    active_bounds: Vec::new(),
+                    resume_state: Some(OptimizerState {
+                        next_iteration: iter + 1,
+                        parameters: params,
+                        memory: OptimizerMemory::Adam {
+                            first_moment: m,
+                            second_moment: v,
+                            prev_cost,
+                        },
+                    }),
+                };
+            }
         }
 
         OptimizationResult {
             optimal_parameters: best_params,
             optimal_cost: best_cost,
             iterations: self.config.max_iterations,
-            converged: false,
+            stop_reason: StopReason::MaxIterations,
             history,
+            active_bounds: Vec::new(),
+            resume_state: Some(OptimizerState {
+                next_iteration: self.config.max_iterations,
+                parameters: params,
+                memory: OptimizerMemory::Adam {
+                    first_moment: m,
+                    second_moment: v,
+                    prev_cost,
+                },
+            }),
         }
     }
 
@@ -217,6 +650,7 @@ impl Optimizer {
         let gamma = 2.0; // expansion
         let rho = 0.5; // contraction
         let sigma = 0.5; // shrinkage
+        let bounds = self.config.bounds.as_deref();
 
         // Initialize simplex
         let mut simplex: Vec<(ParameterVector, f64)> = Vec::with_capacity(n + 1);
@@ -229,10 +663,12 @@ impl Optimizer {
         for i in 0..n {
             let mut vertex = initial_parameters.clone();
             vertex[i] += 0.1;
+            let vertex = project_onto_bounds(&vertex, bounds);
             let cost = cost_function.evaluate(&vertex);
             simplex.push((vertex, cost));
         }
 
+        let mut plateau = EarlyStopping::new(&self.config);
         for iter in 0..self.config.max_iterations {
             // Sort simplex by cost
             simplex.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
@@ -241,19 +677,34 @@ impl Optimizer {
             let worst_cost = simplex[n].1;
 
             // Record history
-            history.add_entry(HistoryEntry {
-                iteration: iter,
-                parameters: simplex[0].0.clone(),
-                cost: best_cost,
-                gradient_norm: None,
-                elapsed_time: start_time.elapsed().as_secs_f64(),
-            });
+            let stop = self.record(
+                &mut history,
+                HistoryEntry {
+                    iteration: iter,
+                    parameters: simplex[0].0.clone(),
+                    cost: best_cost,
+                    gradient_norm: None,
+                    elapsed_time: start_time.elapsed().as_secs_f64(),
+                },
+            );
             history.total_quantum_evaluations += 1;
 
             if self.config.verbose && iter % 10 == 0 {
                 println!("Nelder-Mead Iter {}: best_cost = {:.8}", iter, best_cost);
             }
 
+            if stop {
+                return OptimizationResult {
+                    optimal_parameters: simplex[0].0.clone(),
+                    optimal_cost: best_cost,
+                    iterations: iter + 1,
+                    stop_reason: StopReason::UserAbort,
+                    history,
+                    active_bounds: Vec::new(),
+                    resume_state: None,
+                };
+            }
+
             // Convergence check
             if (worst_cost - best_cost).abs() < self.config.tolerance {
                 if self.config.verbose {
@@ -263,8 +714,25 @@ impl Optimizer {
                     optimal_parameters: simplex[0].0.clone(),
                     optimal_cost: best_cost,
                     iterations: iter + 1,
-                    converged: true,
+                    stop_reason: StopReason::EnergyTolerance,
+                    history,
+                    active_bounds: Vec::new(),
+                    resume_state: None,
+                };
+            }
+
+            if plateau.update(best_cost) {
+                if self.config.verbose {
+                    println!("Early stopping after {} iterations", iter + 1);
+                }
+                return OptimizationResult {
+                    optimal_parameters: simplex[0].0.clone(),
+                    optimal_cost: best_cost,
+                    iterations: iter + 1,
+                    stop_reason: StopReason::EarlyStopping,
                     history,
+                    active_bounds: Vec::new(),
+                    resume_state: None,
                 };
             }
 
@@ -284,6 +752,7 @@ impl Optimizer {
             for j in 0..n {
                 reflected[j] = centroid[j] + alpha * (centroid[j] - simplex[n].0[j]);
             }
+            let reflected = project_onto_bounds(&reflected, bounds);
             let reflected_cost = cost_function.evaluate(&reflected);
 
             if reflected_cost < simplex[n - 1].1 && reflected_cost >= simplex[0].1 {
@@ -297,6 +766,7 @@ impl Optimizer {
                 for j in 0..n {
                     expanded[j] = centroid[j] + gamma * (reflected[j] - centroid[j]);
                 }
+                let expanded = project_onto_bounds(&expanded, bounds);
                 let expanded_cost = cost_function.evaluate(&expanded);
 
                 if expanded_cost < reflected_cost {
@@ -312,6 +782,7 @@ impl Optimizer {
             for j in 0..n {
                 contracted[j] = centroid[j] + rho * (simplex[n].0[j] - centroid[j]);
             }
+            let contracted = project_onto_bounds(&contracted, bounds);
             let contracted_cost = cost_function.evaluate(&contracted);
 
             if contracted_cost < simplex[n].1 {
@@ -334,8 +805,10 @@ impl Optimizer {
             optimal_parameters: simplex[0].0.clone(),
             optimal_cost: simplex[0].1,
             iterations: self.config.max_iterations,
-            converged: false,
+            stop_reason: StopReason::MaxIterations,
             history,
+            active_bounds: Vec::new(),
+            resume_state: None,
         }
     }
 
@@ -345,6 +818,7 @@ impl Optimizer {
         &self,
         cost_function: Arc<dyn CostFunction>,
         initial_parameters: ParameterVector,
+        checkpoint: Option<&OptimizationResult>,
     ) -> OptimizationResult {
         let start_time = Instant::now();
         let mut params = initial_parameters.clone();
@@ -354,30 +828,61 @@ impl Optimizer {
         let mut s_list: Vec<ParameterVector> = Vec::new();
         let mut y_list: Vec<ParameterVector> = Vec::new();
 
-        let mut prev_gradient =
-            cost_function.gradient(&params, self.config.gradient_method.clone());
-        let mut best_cost = cost_function.evaluate(&params);
+        let mut prev_gradient;
+        let mut best_cost;
         let mut best_params = params.clone();
-        let mut prev_cost = best_cost;
+        let mut prev_cost;
+        let mut start_iteration = 0;
+        if let Some(checkpoint) = checkpoint
+            && let Some(OptimizerState {
+                next_iteration,
+                memory:
+                    OptimizerMemory::LBFGS {
+                        s_list: saved_s,
+                        y_list: saved_y,
+                        prev_gradient: saved_gradient,
+                        prev_cost: saved_cost,
+                    },
+                ..
+            }) = &checkpoint.resume_state
+        {
+            s_list = saved_s.clone();
+            y_list = saved_y.clone();
+            prev_gradient = saved_gradient.clone();
+            prev_cost = *saved_cost;
+            best_cost = checkpoint.optimal_cost;
+            best_params = checkpoint.optimal_parameters.clone();
+            history = checkpoint.history.clone();
+            start_iteration = *next_iteration;
+        } else {
+            prev_gradient = cost_function.gradient(&params, self.config.gradient_method.clone());
+            best_cost = cost_function.evaluate(&params);
+            prev_cost = best_cost;
+        }
+        let bounds = self.config.bounds.as_deref();
+        let mut plateau = EarlyStopping::new(&self.config).replay(&history);
 
-        for iter in 0..self.config.max_iterations {
-            let cost = cost_function.evaluate(&params);
-            let gradient = cost_function.gradient(&params, self.config.gradient_method.clone());
+        for iter in start_iteration..self.config.max_iterations {
+            let (cost, gradient) =
+                cost_function.gradient_with_value(&params, self.config.gradient_method.clone());
 
             if cost < best_cost {
                 best_cost = cost;
                 best_params = params.clone();
             }
 
-            let gradient_norm = gradient.iter().map(|g| g * g).sum::<f64>().sqrt();
+            let gradient_norm = projected_gradient_norm(&params, &gradient, bounds);
 
-            history.add_entry(HistoryEntry {
-                iteration: iter,
-                parameters: params.clone(),
-                cost,
-                gradient_norm: Some(gradient_norm),
-                elapsed_time: start_time.elapsed().as_secs_f64(),
-            });
+            let stop = self.record(
+                &mut history,
+                HistoryEntry {
+                    iteration: iter,
+                    parameters: params.clone(),
+                    cost,
+                    gradient_norm: Some(gradient_norm),
+                    elapsed_time: start_time.elapsed().as_secs_f64(),
+                },
+            );
             history.total_quantum_evaluations += 1 + params.len() * 2;
 
             if self.config.verbose && iter % 10 == 0 {
@@ -396,20 +901,41 @@ impl Optimizer {
             let energy_converged = iter > 0 && energy_change < self.config.energy_tolerance;
 
             if gradient_converged || energy_converged {
+                let stop_reason = if gradient_converged {
+                    StopReason::GradientTolerance
+                } else {
+                    StopReason::EnergyTolerance
+                };
                 if self.config.verbose {
-                    let reason = if gradient_converged {
-                        "gradient"
-                    } else {
-                        "energy"
-                    };
-                    println!("Converged ({}) after {} iterations", reason, iter + 1);
+                    println!(
+                        "Converged ({:?}) after {} iterations",
+                        stop_reason,
+                        iter + 1
+                    );
+                }
+                return OptimizationResult {
+                    optimal_parameters: best_params,
+                    optimal_cost: best_cost,
+                    iterations: iter + 1,
+                    stop_reason,
+                    history,
+                    active_bounds: Vec::new(),
+                    resume_state: None,
+                };
+            }
+
+            if plateau.update(cost) {
+                if self.config.verbose {
+                    println!("Early stopping after {} iterations", iter + 1);
                 }
                 return OptimizationResult {
                     optimal_parameters: best_params,
                     optimal_cost: best_cost,
                     iterations: iter + 1,
-                    converged: true,
+                    stop_reason: StopReason::EarlyStopping,
                     history,
+                    active_bounds: Vec::new(),
+                    resume_state: None,
                 };
             }
 
@@ -477,6 +1003,7 @@ impl Optimizer {
             for j in 0..new_params.len() {
                 new_params[j] -= step_size * r[j];
             }
+            let new_params = project_onto_bounds(&new_params, bounds);
 
             // Update history
             let s: ParameterVector = params
@@ -490,8 +1017,13 @@ impl Optimizer {
                 .map(|(g_new, g_old)| g_new - g_old)
                 .collect();
 
-            s_list.push(s);
-            y_list.push(y);
+            // Skip pairs violating the curvature condition (e.g. a step
+            // fully absorbed by a bound), which would make ρ = 1/(s·y) blow up
+            let curvature: f64 = s.iter().zip(y.iter()).map(|(si, yi)| si * yi).sum();
+            if curvature > 1e-12 {
+                s_list.push(s);
+                y_list.push(y);
+            }
 
             if s_list.len() > memory_size {
                 s_list.remove(0);
@@ -500,14 +1032,46 @@ impl Optimizer {
 
             params = new_params;
             prev_gradient = gradient;
+
+            if stop {
+                return OptimizationResult {
+                    optimal_parameters: best_params,
+                    optimal_cost: best_cost,
+                    iterations: iter + 1,
+                    stop_reason: StopReason::UserAbort,
+                    history,
+                    active_bounds: Vec::new(),
+                    resume_state: Some(OptimizerState {
+                        next_iteration: iter + 1,
+                        parameters: params,
+                        memory: OptimizerMemory::LBFGS {
+                            s_list,
+                            y_list,
+                            prev_gradient,
+                            prev_cost,
+                        },
+                    }),
+                };
+            }
         }
 
         OptimizationResult {
             optimal_parameters: best_params,
             optimal_cost: best_cost,
             iterations: self.config.max_iterations,
-            converged: false,
+            stop_reason: StopReason::MaxIterations,
             history,
+            active_bounds: Vec::new(),
+            resume_state: Some(OptimizerState {
+                next_iteration: self.config.max_iterations,
+                parameters: params,
+                memory: OptimizerMemory::LBFGS {
+                    s_list,
+                    y_list,
+                    prev_gradient,
+                    prev_cost,
+                },
+            }),
         }
     }
 
@@ -516,6 +1080,7 @@ impl Optimizer {
         &self,
         cost_function: Arc<dyn CostFunction>,
         initial_parameters: ParameterVector,
+        checkpoint: Option<&OptimizationResult>,
     ) -> OptimizationResult {
         let start_time = Instant::now();
         let mut params = initial_parameters.clone();
@@ -526,10 +1091,27 @@ impl Optimizer {
 
         let mut best_cost = f64::INFINITY;
         let mut best_params = params.clone();
+        let mut start_iteration = 0;
+        if let Some(checkpoint) = checkpoint
+            && let Some(OptimizerState {
+                next_iteration,
+                memory: OptimizerMemory::GradientDescent { velocity: saved },
+                ..
+            }) = &checkpoint.resume_state
+        {
+            velocity = saved.clone();
+            best_cost = checkpoint.optimal_cost;
+            best_params = checkpoint.optimal_parameters.clone();
+            history = checkpoint.history.clone();
+            start_iteration = *next_iteration;
+        }
+        let bounds = self.config.bounds.as_deref();
+        let mut plateau = EarlyStopping::new(&self.config).replay(&history);
 
-        for iter in 0..self.config.max_iterations {
-            let cost = cost_function.evaluate(&params);
-            let gradient = cost_function.gradient(&params, self.config.gradient_method.clone());
+        for iter in start_iteration..self.config.max_iterations {
+            let (cost, gradient) =
+                cost_function.gradient_with_value(&params, self.config.gradient_method.clone());
+            let gradient_norm = projected_gradient_norm(&params, &gradient, bounds);
 
             if cost < best_cost {
                 best_cost = cost;
@@ -542,15 +1124,25 @@ impl Optimizer {
                 params[i] -= velocity[i];
             }
 
-            let gradient_norm = gradient.iter().map(|g| g * g).sum::<f64>().sqrt();
+            // Project onto the box and drop momentum pushing into a bound
+            let projected = project_onto_bounds(&params, bounds);
+            for i in 0..params.len() {
+                if projected[i] != params[i] {
+                    velocity[i] = 0.0;
+                }
+            }
+            params = projected;
 
-            history.add_entry(HistoryEntry {
-                iteration: iter,
-                parameters: params.clone(),
-                cost,
-                gradient_norm: Some(gradient_norm),
-                elapsed_time: start_time.elapsed().as_secs_f64(),
-            });
+            let stop = self.record(
+                &mut history,
+                HistoryEntry {
+                    iteration: iter,
+                    parameters: params.clone(),
+                    cost,
+                    gradient_norm: Some(gradient_norm),
+                    elapsed_time: start_time.elapsed().as_secs_f64(),
+                },
+            );
             history.total_quantum_evaluations += 1 + params.len() * 2;
 
             if self.config.verbose && iter % 10 == 0 {
@@ -568,22 +1160,642 @@ impl Optimizer {
                     optimal_parameters: best_params,
                     optimal_cost: best_cost,
                     iterations: iter + 1,
-                    converged: true,
+                    stop_reason: StopReason::GradientTolerance,
+                    history,
+                    active_bounds: Vec::new(),
+                    resume_state: None,
+                };
+            }
+
+            if plateau.update(cost) {
+                if self.config.verbose {
+                    println!("Early stopping after {} iterations", iter + 1);
+                }
+                return OptimizationResult {
+                    optimal_parameters: best_params,
+                    optimal_cost: best_cost,
+                    iterations: iter + 1,
+                    stop_reason: StopReason::EarlyStopping,
+                    history,
+                    active_bounds: Vec::new(),
+                    resume_state: None,
+                };
+            }
+
+            if stop {
+                return OptimizationResult {
+                    optimal_parameters: best_params,
+                    optimal_cost: best_cost,
+                    iterations: iter + 1,
+                    stop_reason: StopReason::UserAbort,
+                    history,
+                    active_bounds: Vec::new(),
+                    resume_state: Some(OptimizerState {
+                        next_iteration: iter + 1,
+                        parameters: params,
+                        memory: OptimizerMemory::GradientDescent { velocity },
+                    }),
+                };
+            }
+        }
+
+        OptimizationResult {
+            optimal_parameters: best_params,
+            optimal_cost: best_cost,
+            iterations: self.config.max_iterations,
+            stop_reason: StopReason::MaxIterations,
+            history,
+            active_bounds: Vec::new(),
+            resume_state: Some(OptimizerState {
+                next_iteration: self.config.max_iterations,
+                parameters: params,
+                memory: OptimizerMemory::GradientDescent { velocity },
+            }),
+        }
+    }
+
+    /// SPSA Optimizer (Simultaneous Perturbation Stochastic Approximation)
+    ///
+    /// Estimates the full gradient from two cost evaluations along a random
+    /// ±1 direction, independent of the parameter count. The cost recorded in
+    /// the history is the mean of the two perturbed evaluations; the returned
+    /// optimum is re-evaluated exactly once at the end. Convergence is declared
+    /// when the parameter update norm drops below `tolerance`.
+    fn optimize_spsa(
+        &self,
+        cost_function: Arc<dyn CostFunction>,
+        initial_parameters: ParameterVector,
+    ) -> OptimizationResult {
+        let start_time = Instant::now();
+        let mut params = initial_parameters.clone();
+        let mut history = OptimizationHistory::new();
+        let schedule = &self.config.spsa;
+        let mut rng = match schedule.seed {
+            Some(seed) => SmallRng::seed_from_u64(seed),
+            None => SmallRng::from_entropy(),
+        };
+
+        let mut best_estimate = f64::INFINITY;
+        let mut best_params = params.clone();
+        let bounds = self.config.bounds.as_deref();
+        let mut plateau = EarlyStopping::new(&self.config);
+
+        for iter in 0..self.config.max_iterations {
+            let a_k = schedule.learning_rate(iter);
+            let c_k = schedule.perturbation(iter);
+            let delta: Vec<f64> = (0..params.len())
+                .map(|_| if rng.r#gen::<bool>() { 1.0 } else { -1.0 })
+                .collect();
+
+            let params_plus: ParameterVector = params
+                .iter()
+                .zip(delta.iter())
+                .map(|(p, d)| p + c_k * d)
+                .collect();
+            let params_minus: ParameterVector = params
+                .iter()
+                .zip(delta.iter())
+                .map(|(p, d)| p - c_k * d)
+                .collect();
+            let f_plus = cost_function.evaluate(&params_plus);
+            let f_minus = cost_function.evaluate(&params_minus);
+            let cost = 0.5 * (f_plus + f_minus);
+
+            if cost < best_estimate {
+                best_estimate = cost;
+                best_params = params.clone();
+            }
+
+            let gradient: Vec<f64> = delta
+                .iter()
+                .map(|d| (f_plus - f_minus) / (2.0 * c_k * d))
+                .collect();
+            let gradient_norm = gradient.iter().map(|g| g * g).sum::<f64>().sqrt();
+            for (p, g) in params.iter_mut().zip(gradient.iter()) {
+                *p -= a_k * g;
+            }
+            params = project_onto_bounds(&params, bounds);
+
+            let stop = self.record(
+                &mut history,
+                HistoryEntry {
+                    iteration: iter,
+                    parameters: params.clone(),
+                    cost,
+                    gradient_norm: Some(gradient_norm),
+                    elapsed_time: start_time.elapsed().as_secs_f64(),
+                },
+            );
+            history.total_quantum_evaluations += 2;
+
+            if self.config.verbose && iter % 10 == 0 {
+                println!(
+                    "SPSA Iter {}: cost ≈ {:.8}, |ĝ| = {:.6e}, a_k = {:.4e}, c_k = {:.4e}",
+                    iter, cost, gradient_norm, a_k, c_k
+                );
+            }
+
+            if stop {
+                let optimal_cost = cost_function.evaluate(&params);
+                history.total_quantum_evaluations += 1;
+                return OptimizationResult {
+                    optimal_parameters: params,
+                    optimal_cost,
+                    iterations: iter + 1,
+                    stop_reason: StopReason::UserAbort,
+                    history,
+                    active_bounds: Vec::new(),
+                    resume_state: None,
+                };
+            }
+
+            if a_k * gradient_norm < self.config.tolerance {
+                if self.config.verbose {
+                    println!("Converged after {} iterations", iter + 1);
+                }
+                let optimal_cost = cost_function.evaluate(&params);
+                history.total_quantum_evaluations += 1;
+                return OptimizationResult {
+                    optimal_parameters: params,
+                    optimal_cost,
+                    iterations: iter + 1,
+                    stop_reason: StopReason::GradientTolerance,
+                    history,
+                    active_bounds: Vec::new(),
+                    resume_state: None,
+                };
+            }
+
+            if plateau.update(cost) {
+                if self.config.verbose {
+                    println!("Early stopping after {} iterations", iter + 1);
+                }
+                let optimal_cost = cost_function.evaluate(&params);
+                history.total_quantum_evaluations += 1;
+                return OptimizationResult {
+                    optimal_parameters: params,
+                    optimal_cost,
+                    iterations: iter + 1,
+                    stop_reason: StopReason::EarlyStopping,
+                    history,
+                    active_bounds: Vec::new(),
+                    resume_state: None,
+                };
+            }
+        }
+
+        let final_cost = cost_function.evaluate(&params);
+        let best_cost = cost_function.evaluate(&best_params);
+        history.total_quantum_evaluations += 2;
+        let (optimal_parameters, optimal_cost) = if final_cost <= best_cost {
+            (params, final_cost)
+        } else {
+            (best_params, best_cost)
+        };
+
+        OptimizationResult {
+            optimal_parameters,
+            optimal_cost,
+            iterations: self.config.max_iterations,
+            stop_reason: StopReason::MaxIterations,
+            history,
+            active_bounds: Vec::new(),
+            resume_state: None,
+        }
+    }
+
+    /// TRITON spiral search (gradient-free)
+    ///
+    /// Runs [`TritonSearch`] spirals on the unit cube mapped onto a search box
+    /// (see [`TritonConfig`]), one cost evaluation per iteration. The first
+    /// spiral starts at the centre of the full box; each restart recentres on
+    /// the best point in a shrunken box. History entries record the best
+    /// point found so far.
+    #[cfg(feature = "triton")]
+    fn optimize_triton(
+        &self,
+        cost_function: Arc<dyn CostFunction>,
+        initial_parameters: ParameterVector,
+    ) -> OptimizationResult {
+        let start_time = Instant::now();
+        let mut history = OptimizationHistory::new();
+        let settings = &self.config.triton;
+        let bounds = self.config.bounds.as_deref();
+        let (mut centre, mut half_widths): (Vec<f64>, Vec<f64>) = match bounds {
+            Some(bounds) => bounds
+                .iter()
+                .map(|&(lower, upper)| (0.5 * (lower + upper), 0.5 * (upper - lower)))
+                .unzip(),
+            None => initial_parameters
+                .iter()
+                .map(|&p| (p, settings.search_radius))
+                .unzip(),
+        };
+
+        let mut best_params = initial_parameters.clone();
+        let mut best_cost = cost_function.evaluate(&best_params);
+        history.total_quantum_evaluations += 1;
+
+        let mut plateau = EarlyStopping::new(&self.config);
+        let mut iter = 0;
+        let mut restart = 0;
+        while iter < self.config.max_iterations {
+            let search_box: Vec<(f64, f64)> = centre
+                .iter()
+                .zip(half_widths.iter())
+                .map(|(&c, &h)| (c - h, c + h))
+                .collect();
+            let search_box = match bounds {
+                Some(bounds) => search_box
+                    .iter()
+                    .zip(bounds.iter())
+                    .map(|(&(lower, upper), &(lo, hi))| (lower.max(lo), upper.min(hi)))
+                    .collect(),
+                None => search_box,
+            };
+            let to_parameters = |unit: &[f64]| -> ParameterVector {
+                unit.iter()
+                    .zip(search_box.iter())
+                    .map(|(u, (lower, upper))| lower + u * (upper - lower))
+                    .collect()
+            };
+
+            let reference_cost = best_cost;
+            let last_cost = Cell::new(f64::INFINITY);
+            let evaluator = |unit: &[f64]| {
+                let cost = cost_function.evaluate(&to_parameters(unit));
+                last_cost.set(cost);
+                let psi = 1.0 / (1.0 + ((cost - reference_cost) / settings.cost_scale).exp());
+                SpectralSignature::new(psi, 1.0, 1.0)
+            };
+            let mut search = TritonSearch::new(
+                initial_parameters.len(),
+                settings.seed + restart,
+                self.config.max_iterations - iter,
+                evaluator,
+            )
+            .with_stagnation(settings.stagnation_window, 0.0);
+
+            while iter < self.config.max_iterations {
+                let step = search.step();
+                history.total_quantum_evaluations += 1;
+                if last_cost.get() < best_cost {
+                    best_cost = last_cost.get();
+                    best_params = to_parameters(&step.point);
+                }
+
+                let stop = self.record(
+                    &mut history,
+                    HistoryEntry {
+                        iteration: iter,
+                        parameters: best_params.clone(),
+                        cost: best_cost,
+                        gradient_norm: None,
+                        elapsed_time: start_time.elapsed().as_secs_f64(),
+                    },
+                );
+
+                if self.config.verbose && iter % 10 == 0 {
+                    println!(
+                        "TRITON Iter {}: best_cost = {:.8}, restart = {}, radius = {:.4}",
+                        iter, best_cost, restart, step.radius
+                    );
+                }
+                iter += 1;
+
+                let stop_reason = if stop {
+                    StopReason::UserAbort
+                } else if plateau.update(best_cost) {
+                    if self.config.verbose {
+                        println!("Early stopping after {} iterations", iter);
+                    }
+                    StopReason::EarlyStopping
+                } else if search.is_stagnant(settings.stagnation_window, f64::MIN_POSITIVE) {
+                    break;
+                } else {
+                    continue;
+                };
+                return OptimizationResult {
+                    optimal_parameters: best_params,
+                    optimal_cost: best_cost,
+                    iterations: iter,
+                    stop_reason,
                     history,
+                    active_bounds: Vec::new(),
+                    resume_state: None,
                 };
             }
+
+            centre.clone_from(&best_params);
+            for h in half_widths.iter_mut() {
+                *h *= settings.shrink_factor;
+            }
+            restart += 1;
         }
 
         OptimizationResult {
             optimal_parameters: best_params,
             optimal_cost: best_cost,
             iterations: self.config.max_iterations,
-            converged: false,
+            stop_reason: StopReason::MaxIterations,
+            history,
+            active_bounds: Vec::new(),
+            resume_state: None,
+        }
+    }
+
+    /// COBYLA-style optimizer (Constrained Optimization BY Linear Approximation)
+    ///
+    /// Maintains a simplex of n+1 points, fits a linear model of the cost to
+    /// it and steps to the minimiser of that model inside a trust region of
+    /// radius ρ, clipped to `config.bounds`. Failed steps halve ρ and rebuild
+    /// the simplex around the incumbent; the run converges once ρ < `rho_end`.
+    #[allow(clippy::needless_range_loop)]
+    fn optimize_cobyla(
+        &self,
+        cost_function: Arc<dyn CostFunction>,
+        initial_parameters: ParameterVector,
+    ) -> OptimizationResult {
+        let start_time = Instant::now();
+        let n = initial_parameters.len();
+        let mut history = OptimizationHistory::new();
+        let bounds = self.config.bounds.as_deref();
+        let mut rho = self.config.cobyla.rho_begin;
+
+        let mut best = project_onto_bounds(&initial_parameters, bounds);
+        let mut best_cost = cost_function.evaluate(&best);
+        history.total_quantum_evaluations += 1;
+        let mut simplex = build_simplex(
+            &best,
+            rho,
+            bounds,
+            &cost_function,
+            &mut history.total_quantum_evaluations,
+        );
+
+        let mut plateau = EarlyStopping::new(&self.config);
+        for iter in 0..self.config.max_iterations {
+            let stop = self.record(
+                &mut history,
+                HistoryEntry {
+                    iteration: iter,
+                    parameters: best.clone(),
+                    cost: best_cost,
+                    gradient_norm: None,
+                    elapsed_time: start_time.elapsed().as_secs_f64(),
+                },
+            );
+
+            if self.config.verbose && iter % 10 == 0 {
+                println!(
+                    "COBYLA Iter {}: best_cost = {:.8}, rho = {:.4e}",
+                    iter, best_cost, rho
+                );
+            }
+
+            if stop {
+                return OptimizationResult {
+                    optimal_parameters: best,
+                    optimal_cost: best_cost,
+                    iterations: iter + 1,
+                    stop_reason: StopReason::UserAbort,
+                    history,
+                    active_bounds: Vec::new(),
+                    resume_state: None,
+                };
+            }
+
+            if rho < self.config.cobyla.rho_end {
+                if self.config.verbose {
+                    println!("Converged after {} iterations", iter + 1);
+                }
+                return OptimizationResult {
+                    optimal_parameters: best,
+                    optimal_cost: best_cost,
+                    iterations: iter + 1,
+                    stop_reason: StopReason::GradientTolerance,
+                    history,
+                    active_bounds: Vec::new(),
+                    resume_state: None,
+                };
+            }
+
+            if plateau.update(best_cost) {
+                if self.config.verbose {
+                    println!("Early stopping after {} iterations", iter + 1);
+                }
+                return OptimizationResult {
+                    optimal_parameters: best,
+                    optimal_cost: best_cost,
+                    iterations: iter + 1,
+                    stop_reason: StopReason::EarlyStopping,
+                    history,
+                    active_bounds: Vec::new(),
+                    resume_state: None,
+                };
+            }
+
+            // Linear model f(x) ≈ f(best) + g·(x - best) through the simplex
+            let mut displacements = DMatrix::<f64>::zeros(n, n);
+            let mut differences = DVector::<f64>::zeros(n);
+            for (row, (vertex, cost)) in simplex.iter().enumerate() {
+                for col in 0..n {
+                    displacements[(row, col)] = vertex[col] - best[col];
+                }
+                differences[row] = cost - best_cost;
+            }
+            let gradient = match displacements.lu().solve(&differences) {
+                Some(g) if g.iter().all(|v| v.is_finite()) => g,
+                _ => {
+                    simplex = build_simplex(
+                        &best,
+                        rho,
+                        bounds,
+                        &cost_function,
+                        &mut history.total_quantum_evaluations,
+                    );
+                    continue;
+                }
+            };
+
+            let gradient_norm = gradient.norm();
+            let mut improved = false;
+            if gradient_norm > 0.0 {
+                let trial: ParameterVector = (0..n)
+                    .map(|j| best[j] - rho * gradient[j] / gradient_norm)
+                    .collect();
+                let trial = project_onto_bounds(&trial, bounds);
+                let trial_cost = cost_function.evaluate(&trial);
+                history.total_quantum_evaluations += 1;
+
+                if trial_cost < best_cost {
+                    // Replace the worst vertex with the old incumbent
+                    let worst = simplex
+                        .iter()
+                        .enumerate()
+                        .max_by(|a, b| a.1.1.partial_cmp(&b.1.1).unwrap())
+                        .map(|(idx, _)| idx)
+                        .unwrap_or(0);
+                    simplex[worst] = (best.clone(), best_cost);
+                    best = trial;
+                    best_cost = trial_cost;
+                    improved = true;
+                }
+            }
+
+            if !improved {
+                rho *= 0.5;
+                simplex = build_simplex(
+                    &best,
+                    rho,
+                    bounds,
+                    &cost_function,
+                    &mut history.total_quantum_evaluations,
+                );
+                if let Some((vertex, cost)) = simplex
+                    .iter()
+                    .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
+                    .cloned()
+                    .filter(|(_, cost)| *cost < best_cost)
+                {
+                    best = vertex;
+                    best_cost = cost;
+                    simplex = build_simplex(
+                        &best,
+                        rho,
+                        bounds,
+                        &cost_function,
+                        &mut history.total_quantum_evaluations,
+                    );
+                }
+            }
+        }
+
+        OptimizationResult {
+            optimal_parameters: best,
+            optimal_cost: best_cost,
+            iterations: self.config.max_iterations,
+            stop_reason: StopReason::MaxIterations,
             history,
+            active_bounds: Vec::new(),
+            resume_state: None,
+        }
+    }
+}
+
+/// Patience counter behind `OptimizerConfig::patience`.
+struct EarlyStopping {
+    patience: Option<usize>,
+    min_delta: f64,
+    best: f64,
+    stale: usize,
+}
+
+impl EarlyStopping {
+    fn new(config: &OptimizerConfig) -> Self {
+        Self {
+            patience: config.patience,
+            min_delta: config.min_delta,
+            best: f64::INFINITY,
+            stale: 0,
+        }
+    }
+
+    /// Replay the costs of a resumed run so the counter continues where it left off.
+    fn replay(mut self, history: &OptimizationHistory) -> Self {
+        for entry in &history.entries {
+            self.update(entry.cost);
         }
+        self
+    }
+
+    /// Record one iteration's cost; `true` once patience is exhausted.
+    fn update(&mut self, cost: f64) -> bool {
+        let Some(patience) = self.patience else {
+            return false;
+        };
+        if cost < self.best - self.min_delta {
+            self.best = cost;
+            self.stale = 0;
+        } else {
+            self.stale += 1;
+        }
+        self.stale >= patience
+    }
+}
+
+/// Clip parameters into the box defined by `bounds`.
+fn project_onto_bounds(parameters: &[f64], bounds: Option<&[(f64, f64)]>) -> ParameterVector {
+    match bounds {
+        Some(bounds) => parameters
+            .iter()
+            .zip(bounds.iter())
+            .map(|(&p, &(lower, upper))| p.clamp(lower, upper))
+            .collect(),
+        None => parameters.to_vec(),
     }
 }
 
+/// Norm of the projected gradient.
+///
+/// Components that would push a parameter further past an active bound are
+/// dropped, so a constrained optimum reports a vanishing gradient.
+fn projected_gradient_norm(
+    parameters: &[f64],
+    gradient: &[f64],
+    bounds: Option<&[(f64, f64)]>,
+) -> f64 {
+    gradient
+        .iter()
+        .enumerate()
+        .map(|(i, &g)| match bounds.and_then(|b| b.get(i)) {
+            Some(&(lower, _)) if parameters[i] <= lower && g > 0.0 => 0.0,
+            Some(&(_, upper)) if parameters[i] >= upper && g < 0.0 => 0.0,
+            _ => g,
+        })
+        .map(|g| g * g)
+        .sum::<f64>()
+        .sqrt()
+}
+
+/// Indices of parameters lying on a bound (within 1e-9).
+fn active_bound_indices(parameters: &[f64], bounds: Option<&[(f64, f64)]>) -> Vec<usize> {
+    let Some(bounds) = bounds else {
+        return Vec::new();
+    };
+    parameters
+        .iter()
+        .zip(bounds.iter())
+        .enumerate()
+        .filter(|(_, (p, (lower, upper)))| (*p - lower).abs() < 1e-9 || (*p - upper).abs() < 1e-9)
+        .map(|(idx, _)| idx)
+        .collect()
+}
+
+/// Axis-aligned simplex of radius `rho` around `center`, flipped inward at bounds.
+fn build_simplex(
+    center: &[f64],
+    rho: f64,
+    bounds: Option<&[(f64, f64)]>,
+    cost_function: &Arc<dyn CostFunction>,
+    evaluations: &mut usize,
+) -> Vec<(ParameterVector, f64)> {
+    (0..center.len())
+        .map(|axis| {
+            let mut vertex = center.to_vec();
+            vertex[axis] += rho;
+            if let Some(&(lower, upper)) = bounds.and_then(|b| b.get(axis))
+                && vertex[axis] > upper
+            {
+                vertex[axis] = (center[axis] - rho).max(lower);
+            }
+            let cost = cost_function.evaluate(&vertex);
+            *evaluations += 1;
+            (vertex, cost)
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -632,4 +1844,263 @@ mod tests {
 
         assert!(result.optimal_cost < 1.0);
     }
+
+    #[test]
+    fn test_spsa_uses_two_evaluations_per_iteration() {
+        let cost_fn = Arc::new(TestCostFunction);
+        let initial = vec![-1.0, -1.0];
+
+        let config = OptimizerConfig {
+            max_iterations: 500,
+            tolerance: 0.0,
+            verbose: false,
+            spsa: SpsaConfig {
+                a: 0.01,
+                seed: Some(42),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let optimizer = Optimizer::new(OptimizerType::SPSA, config);
+        let result = optimizer.optimize(cost_fn.clone(), initial.clone());
+
+        assert_eq!(result.history.total_quantum_evaluations, 2 * 500 + 2);
+        assert!(result.optimal_cost < cost_fn.evaluate(&initial));
+    }
+
+    #[cfg(feature = "triton")]
+    #[test]
+    fn test_triton_minimizes_rosenbrock() {
+        let cost_fn = Arc::new(TestCostFunction);
+        let initial = vec![-1.0, -1.0];
+
+        let config = OptimizerConfig {
+            max_iterations: 2000,
+            verbose: false,
+            bounds: Some(vec![(-2.0, 2.0), (-2.0, 2.0)]),
+            ..Default::default()
+        };
+
+        let optimizer = Optimizer::new(OptimizerType::Triton, config);
+        let result = optimizer.optimize(cost_fn.clone(), initial.clone());
+
+        assert_eq!(result.stop_reason, StopReason::MaxIterations);
+        assert_eq!(result.history.total_quantum_evaluations, 2000 + 1);
+        assert!((cost_fn.evaluate(&result.optimal_parameters) - result.optimal_cost).abs() < 1e-12);
+        // Restarts keep the spiral moving down the curved valley towards (1, 1).
+        assert!(result.optimal_cost < 0.25);
+        assert!(result.optimal_parameters[0] > 0.5);
+    }
+
+    #[test]
+    fn test_cobyla_respects_bound_constraint() {
+        let cost_fn = Arc::new(TestCostFunction);
+        let initial = vec![-1.0, -1.0];
+
+        // Unconstrained minimum is (1, 1); cap x₀ at 0.5 so the bound is active.
+        let config = OptimizerConfig {
+            max_iterations: 5000,
+            verbose: false,
+            bounds: Some(vec![(-2.0, 0.5), (-2.0, 2.0)]),
+            ..Default::default()
+        };
+
+        let optimizer = Optimizer::new(OptimizerType::COBYLA, config);
+        let result = optimizer.optimize(cost_fn, initial);
+
+        assert!(result.converged());
+        assert_eq!(result.stop_reason, StopReason::GradientTolerance);
+        assert!(result.optimal_parameters[0] <= 0.5);
+        assert!((result.optimal_parameters[0] - 0.5).abs() < 1e-6);
+        assert!((result.optimal_parameters[1] - 0.25).abs() < 1e-3);
+        assert_eq!(result.active_bounds, vec![0]);
+    }
+
+    #[test]
+    fn test_bounds_are_respected_by_gradient_optimizers() {
+        let bounds = vec![(-2.0, 0.5), (-2.0, 2.0)];
+        for optimizer_type in [
+            OptimizerType::Adam,
+            OptimizerType::GradientDescent,
+            OptimizerType::LBFGS,
+            OptimizerType::NelderMead,
+        ] {
+            let config = OptimizerConfig {
+                max_iterations: 200,
+                learning_rate: 0.01,
+                verbose: false,
+                bounds: Some(bounds.clone()),
+                ..Default::default()
+            };
+            let optimizer = Optimizer::new(optimizer_type.clone(), config);
+            let result = optimizer.optimize(Arc::new(TestCostFunction), vec![3.0, 0.0]);
+
+            for entry in &result.history.entries {
+                for (p, (lower, upper)) in entry.parameters.iter().zip(bounds.iter()) {
+                    assert!(
+                        p >= lower && p <= upper,
+                        "{:?} left the box",
+                        optimizer_type
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_callback_observes_iterations_and_can_stop() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let sink = seen.clone();
+        let config = OptimizerConfig {
+            max_iterations: 100,
+            learning_rate: 0.1,
+            verbose: false,
+            ..Default::default()
+        };
+
+        let optimizer = Optimizer::new(OptimizerType::Adam, config).with_callback(move |entry| {
+            sink.lock().unwrap().push((entry.iteration, entry.cost));
+            if entry.iteration == 4 {
+                CallbackAction::Stop
+            } else {
+                CallbackAction::Continue
+            }
+        });
+        let result = optimizer.optimize(Arc::new(TestCostFunction), vec![-1.0, -1.0]);
+
+        assert_eq!(result.iterations, 5);
+        assert!(!result.converged());
+        assert_eq!(result.stop_reason, StopReason::UserAbort);
+        let seen = seen.lock().unwrap();
+        assert_eq!(seen.len(), 5);
+        assert_eq!(seen[4].0, 4);
+    }
+
+    #[test]
+    fn test_patience_stops_on_plateau() {
+        // Constant cost: every iteration after the first fails to improve.
+        struct Flat;
+        impl CostFunction for Flat {
+            fn evaluate(&self, _parameters: &[f64]) -> f64 {
+                1.0
+            }
+            fn gradient(&self, parameters: &[f64], _method: GradientMethod) -> ParameterVector {
+                vec![1.0; parameters.len()]
+            }
+            fn dimension(&self) -> usize {
+                2
+            }
+        }
+
+        for optimizer_type in [OptimizerType::GradientDescent, OptimizerType::NelderMead] {
+            let config = OptimizerConfig {
+                max_iterations: 100,
+                tolerance: 0.0,
+                verbose: false,
+                patience: Some(5),
+                min_delta: 1e-9,
+                ..Default::default()
+            };
+            let result = Optimizer::new(optimizer_type.clone(), config)
+                .optimize(Arc::new(Flat), vec![0.0, 0.0]);
+            assert_eq!(result.iterations, 6, "{:?}", optimizer_type);
+            assert_eq!(result.stop_reason, StopReason::EarlyStopping);
+            assert!(result.converged());
+        }
+
+        let config = OptimizerConfig {
+            max_iterations: 20,
+            learning_rate: 0.001,
+            tolerance: 0.0,
+            energy_tolerance: 0.0,
+            verbose: false,
+            patience: Some(5),
+            ..Default::default()
+        };
+        let result = Optimizer::new(OptimizerType::Adam, config)
+            .optimize(Arc::new(TestCostFunction), vec![-1.0, 1.5]);
+        assert_eq!(result.stop_reason, StopReason::MaxIterations);
+        assert!(!result.converged());
+    }
+
+    #[test]
+    fn test_resume_matches_uninterrupted_run() {
+        for optimizer_type in [
+            OptimizerType::Adam,
+            OptimizerType::LBFGS,
+            OptimizerType::GradientDescent,
+        ] {
+            let config = OptimizerConfig {
+                max_iterations: 40,
+                learning_rate: 0.001,
+                tolerance: 0.0,
+                energy_tolerance: 0.0,
+                verbose: false,
+                ..Default::default()
+            };
+            let initial = vec![-1.0, 1.5];
+
+            let full = Optimizer::new(optimizer_type.clone(), config.clone())
+                .optimize(Arc::new(TestCostFunction), initial.clone());
+
+            let interrupted = Optimizer::new(optimizer_type.clone(), config.clone())
+                .with_callback(|entry| {
+                    if entry.iteration == 14 {
+                        CallbackAction::Stop
+                    } else {
+                        CallbackAction::Continue
+                    }
+                })
+                .optimize(Arc::new(TestCostFunction), initial);
+            assert_eq!(interrupted.iterations, 15);
+
+            let json = serde_json::to_string(&interrupted).unwrap();
+            let checkpoint: OptimizationResult = serde_json::from_str(&json).unwrap();
+            let resumed = Optimizer::new(optimizer_type.clone(), config)
+                .resume_from(&checkpoint, Arc::new(TestCostFunction))
+                .unwrap();
+
+            assert_eq!(resumed.optimal_parameters, full.optimal_parameters);
+            assert_eq!(resumed.optimal_cost, full.optimal_cost);
+            let costs = |r: &OptimizationResult| -> Vec<f64> {
+                r.history.entries.iter().map(|e| e.cost).collect()
+            };
+            assert_eq!(costs(&resumed), costs(&full), "{:?}", optimizer_type);
+        }
+    }
+
+    #[test]
+    fn test_resume_rejects_unusable_checkpoints() {
+        let config = OptimizerConfig {
+            max_iterations: 5,
+            tolerance: 0.0,
+            energy_tolerance: 0.0,
+            verbose: false,
+            ..Default::default()
+        };
+        let adam = Optimizer::new(OptimizerType::Adam, config.clone());
+        let checkpoint = adam.optimize(Arc::new(TestCostFunction), vec![-1.0, 1.5]);
+        assert!(checkpoint.resume_state.is_some());
+
+        let lbfgs = Optimizer::new(OptimizerType::LBFGS, config.clone());
+        assert_eq!(
+            lbfgs
+                .resume_from(&checkpoint, Arc::new(TestCostFunction))
+                .unwrap_err(),
+            ResumeError::OptimizerMismatch {
+                expected: OptimizerType::LBFGS,
+                found: OptimizerType::Adam,
+            }
+        );
+
+        // Nelder–Mead never leaves optimizer state behind
+        let simplex = Optimizer::new(OptimizerType::NelderMead, config)
+            .optimize(Arc::new(TestCostFunction), vec![-1.0, 1.5]);
+        assert_eq!(
+            adam.resume_from(&simplex, Arc::new(TestCostFunction))
+                .unwrap_err(),
+            ResumeError::NoResumeState
+        );
+    }
 }
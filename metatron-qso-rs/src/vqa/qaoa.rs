@@ -7,13 +7,38 @@
 //! |ψ(γ,β)⟩ = ∏ᵢ₌ₚ e^{-iβᵢB} e^{-iγᵢH_C} |+⟩⊗ⁿ
 //!
 //! where H_C is the cost Hamiltonian and B is the mixer Hamiltonian.
+//!
+//! ## Mixers and initial states
+//!
+//! The default mixer is a transverse-field-style operator that connects all
+//! basis states, so the uniform superposition is a good starting point.
+//! Hamming-weight-preserving mixers ([`ring_mixer`], [`xy_mixer`]) only hop
+//! amplitude between coupled nodes. The uniform superposition is an
+//! eigenstate of both, so starting there makes every mixer layer a global
+//! phase and QAOA cannot move. Pair them with a non-uniform start via
+//! [`QAOABuilder::initial_state`], e.g. a basis state encoding a feasible
+//! solution.
+//!
+//! ## Warm start
+//!
+//! [`QAOABuilder::warm_start`] replaces the uniform start with a state biased
+//! toward a classical cut: every node carries the same weight and the two
+//! sides of the cut get opposite signs, see [`sign_pattern_state`]. The
+//! register holds one amplitude per node rather than one qubit per node, so
+//! this is not the per-qubit RY product state of qubit warm-start QAOA and
+//! the mixer is left unchanged.
 
 use crate::quantum::operator::{OperatorMatrix, QuantumOperator};
 use crate::quantum::state::{METATRON_DIMENSION, QuantumState};
 use crate::vqa::cost_function::{GradientMethod, QAOACostFunction};
-use crate::vqa::optimizer::{OptimizationResult, Optimizer, OptimizerConfig, OptimizerType};
+use crate::vqa::optimizer::{
+    CallbackAction, OptimizationResult, Optimizer, OptimizerConfig, OptimizerType, SharedCallback,
+};
+use crate::vqa::{HistoryEntry, MultistartResult};
 use num_complex::Complex64;
-use rand::Rng;
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+use std::collections::HashMap;
 use std::sync::Arc;
 
 /// QAOA Configuration
@@ -22,6 +47,11 @@ pub struct QAOAConfig {
     pub depth: usize,
     pub optimizer_type: OptimizerType,
     pub optimizer_config: OptimizerConfig,
+    /// Capacity of the LRU evaluation cache; 0 (the default) disables it.
+    /// Leave it off for stochastic optimizers such as SPSA.
+    pub cache_capacity: usize,
+    /// Seed for the initial angles (`None` draws from entropy)
+    pub seed: Option<u64>,
 }
 
 impl Default for QAOAConfig {
@@ -36,6 +66,8 @@ impl Default for QAOAConfig {
                 verbose: true,
                 ..Default::default()
             },
+            cache_capacity: 0,
+            seed: None,
         }
     }
 }
@@ -57,6 +89,9 @@ pub struct QAOA {
     mixer_hamiltonian: Arc<QuantumOperator>,
     config: QAOAConfig,
     classical_optimum: Option<f64>,
+    initial_state: QuantumState,
+    initial_parameters: Option<Vec<f64>>,
+    callback: Option<SharedCallback>,
 }
 
 impl QAOA {
@@ -73,6 +108,9 @@ impl QAOA {
             mixer_hamiltonian: mixer,
             config,
             classical_optimum: None,
+            initial_state: QuantumState::uniform_superposition(),
+            initial_parameters: None,
+            callback: None,
         }
     }
 
@@ -82,6 +120,27 @@ impl QAOA {
         self
     }
 
+    /// Start the circuit from `state` instead of the uniform superposition
+    pub fn with_initial_state(mut self, state: QuantumState) -> Self {
+        self.initial_state = state;
+        self
+    }
+
+    /// Start the optimizer from `parameters` (γ₁..γ_p, β₁..β_p) instead of
+    /// random angles
+    ///
+    /// # Panics
+    /// If `parameters` does not hold exactly `2 * depth` values.
+    pub fn with_initial_parameters(mut self, parameters: Vec<f64>) -> Self {
+        assert_eq!(
+            parameters.len(),
+            2 * self.config.depth,
+            "initial QAOA parameters must hold 2 * depth angles"
+        );
+        self.initial_parameters = Some(parameters);
+        self
+    }
+
     /// Default mixer: X mixer (sum of Pauli-X operators)
     fn default_mixer() -> QuantumOperator {
         let mut mixer_matrix = OperatorMatrix::zeros();
@@ -111,29 +170,35 @@ impl QAOA {
 
         println!("═══════════════════════════════════════════════════════");
 
-        // Create initial state (uniform superposition)
-        let initial_state = QuantumState::uniform_superposition();
+        let initial_state = self.initial_state.clone();
 
         // Create cost function
-        let cost_function = Arc::new(QAOACostFunction::new(
-            self.cost_hamiltonian.clone(),
-            self.mixer_hamiltonian.clone(),
-            self.config.depth,
-            initial_state.clone(),
-        ));
+        let cost_function = Arc::new(
+            QAOACostFunction::new(
+                self.cost_hamiltonian.clone(),
+                self.mixer_hamiltonian.clone(),
+                self.config.depth,
+                initial_state.clone(),
+            )
+            .with_cache_capacity(self.config.cache_capacity),
+        );
 
-        // Generate initial parameters
-        let initial_parameters = self.generate_initial_parameters();
+        // Use the supplied starting angles or generate them
+        let initial_parameters = match &self.initial_parameters {
+            Some(parameters) => parameters.clone(),
+            None => self.generate_initial_parameters(),
+        };
 
         // Run optimization
         let optimizer = Optimizer::new(
             self.config.optimizer_type.clone(),
             self.config.optimizer_config.clone(),
-        );
+        )
+        .with_shared_callback(self.callback.as_ref());
         let optimization_result = optimizer.optimize(cost_function.clone(), initial_parameters);
 
         // Compute optimal state
-        let optimal_state = self.construct_qaoa_state(&optimization_result.optimal_parameters);
+        let optimal_state = cost_function.prepare_state(&optimization_result.optimal_parameters);
 
         // Compute approximation ratio
         let approximation_ratio = if let Some(classical_opt) = self.classical_optimum {
@@ -161,7 +226,14 @@ impl QAOA {
         );
         println!("Approximation Ratio:    {:.6}", approximation_ratio);
         println!("Iterations:             {}", optimization_result.iterations);
-        println!("Converged:              {}", optimization_result.converged);
+        println!(
+            "Converged:              {}",
+            optimization_result.converged()
+        );
+        println!(
+            "Stop Reason:            {:?}",
+            optimization_result.stop_reason
+        );
         println!(
             "Quantum Evaluations:    {}",
             optimization_result.history.total_quantum_evaluations
@@ -180,9 +252,43 @@ impl QAOA {
         }
     }
 
+    /// Run [`QAOA::run`] from `num_starts` independently seeded initial
+    /// angles and keep the lowest cost.
+    ///
+    /// Start `k` is seeded with `seed + k` (a seed drawn from entropy if none
+    /// is configured).
+    pub fn run_multistart(&self, num_starts: usize) -> MultistartResult<QAOAResult> {
+        let base_seed = self.config.seed.unwrap_or_else(|| self.rng().r#gen());
+        MultistartResult::collect(base_seed, num_starts, |seed| {
+            let qaoa = QAOA {
+                cost_hamiltonian: self.cost_hamiltonian.clone(),
+                mixer_hamiltonian: self.mixer_hamiltonian.clone(),
+                config: QAOAConfig {
+                    seed: Some(seed),
+                    ..self.config.clone()
+                },
+                classical_optimum: self.classical_optimum,
+                initial_state: self.initial_state.clone(),
+                initial_parameters: self.initial_parameters.clone(),
+                callback: self.callback.clone(),
+            };
+            let result = qaoa.run();
+            let cost = result.optimal_cost;
+            (result, cost)
+        })
+    }
+
+    /// Random source for initial angles, seeded if configured.
+    fn rng(&self) -> SmallRng {
+        match self.config.seed {
+            Some(seed) => SmallRng::seed_from_u64(seed),
+            None => SmallRng::from_entropy(),
+        }
+    }
+
     /// Generate initial parameters (heuristic initialization)
     fn generate_initial_parameters(&self) -> Vec<f64> {
-        let mut rng = rand::thread_rng();
+        let mut rng = self.rng();
         let mut params = Vec::with_capacity(2 * self.config.depth);
 
         // Gamma parameters (cost evolution angles)
@@ -198,56 +304,6 @@ impl QAOA {
         params
     }
 
-    /// Construct QAOA state from parameters
-    fn construct_qaoa_state(&self, parameters: &[f64]) -> QuantumState {
-        assert_eq!(parameters.len(), 2 * self.config.depth);
-
-        let (gamma, beta) = parameters.split_at(self.config.depth);
-        let mut state = QuantumState::uniform_superposition();
-
-        for layer in 0..self.config.depth {
-            // Cost evolution: exp(-iγH_C)
-            let cost_unitary = self.create_evolution_unitary(&self.cost_hamiltonian, gamma[layer]);
-            state = state.apply(&cost_unitary);
-
-            // Mixer evolution: exp(-iβB)
-            let mixer_unitary = self.create_evolution_unitary(&self.mixer_hamiltonian, beta[layer]);
-            state = state.apply(&mixer_unitary);
-        }
-
-        state
-    }
-
-    /// Create time evolution unitary exp(-iHt)
-    fn create_evolution_unitary(
-        &self,
-        hamiltonian: &QuantumOperator,
-        time: f64,
-    ) -> QuantumOperator {
-        // Use matrix exponential approximation
-        // For small time steps: exp(-iHt) ≈ I - iHt + (iHt)²/2! - ...
-
-        let h_matrix = hamiltonian.matrix();
-        let n = h_matrix.nrows();
-
-        // Simplified: Use first-order Trotter approximation
-        // For production: implement proper matrix exponential via diagonalization
-        let mut result = OperatorMatrix::identity();
-
-        // First order: exp(-iHt) ≈ I - iHt
-        let i_time = Complex64::new(0.0, time);
-
-        for row in 0..n {
-            for col in 0..n {
-                result[(row, col)] -= i_time * h_matrix[(row, col)];
-            }
-        }
-
-        // Renormalize to maintain unitarity (approximate)
-        // TODO: Use proper matrix exponential for production
-        QuantumOperator::from_matrix(result)
-    }
-
     /// Estimate classical optimum from cost Hamiltonian eigenvalues
     fn estimate_classical_optimum(&self) -> f64 {
         // For MaxCut and similar problems, classical optimum is often
@@ -259,8 +315,10 @@ impl QAOA {
     }
 
     /// Sample measurement outcomes from optimal state
+    ///
+    /// Draws from the configured seed, so a seeded QAOA samples reproducibly.
     pub fn sample_solutions(&self, state: &QuantumState, num_samples: usize) -> Vec<usize> {
-        let mut rng = rand::thread_rng();
+        let mut rng = self.rng();
         let mut samples = Vec::with_capacity(num_samples);
 
         for _ in 0..num_samples {
@@ -272,6 +330,22 @@ impl QAOA {
         samples
     }
 
+    /// Measure `shots` copies of `state` and count the sampled assignments
+    ///
+    /// Node `b` reads as the assignment of [`node_assignment`]. Shots are
+    /// drawn from the configured seed, so a seeded QAOA samples reproducibly.
+    pub fn sample_bitstrings(
+        &self,
+        state: &QuantumState,
+        shots: usize,
+    ) -> HashMap<Vec<bool>, usize> {
+        state
+            .sample(shots, &mut self.rng())
+            .into_iter()
+            .map(|(node, count)| (node_assignment(node), count))
+            .collect()
+    }
+
     /// Compute cost distribution from samples
     pub fn analyze_samples(
         &self,
@@ -304,6 +378,8 @@ pub struct QAOABuilder {
     mixer_hamiltonian: Option<Arc<QuantumOperator>>,
     config: QAOAConfig,
     classical_optimum: Option<f64>,
+    initial_state: Option<QuantumState>,
+    callback: Option<SharedCallback>,
 }
 
 impl QAOABuilder {
@@ -313,6 +389,8 @@ impl QAOABuilder {
             mixer_hamiltonian: None,
             config: QAOAConfig::default(),
             classical_optimum: None,
+            initial_state: None,
+            callback: None,
         }
     }
 
@@ -321,11 +399,28 @@ impl QAOABuilder {
         self
     }
 
+    /// Override the default mixer (see [`ring_mixer`] and [`xy_mixer`]).
+    ///
+    /// Hamming-weight-preserving mixers need a non-uniform
+    /// [`initial_state`](Self::initial_state).
     pub fn mixer_hamiltonian(mut self, h: Arc<QuantumOperator>) -> Self {
         self.mixer_hamiltonian = Some(h);
         self
     }
 
+    /// Start from the sign pattern of a classical cut (e.g. a greedy
+    /// MaxCut solution), see [`sign_pattern_state`].
+    pub fn warm_start(mut self, assignment: &[bool]) -> Self {
+        self.initial_state = Some(sign_pattern_state(assignment));
+        self
+    }
+
+    /// Initial state of the circuit (default: uniform superposition)
+    pub fn initial_state(mut self, state: QuantumState) -> Self {
+        self.initial_state = Some(state);
+        self
+    }
+
     pub fn depth(mut self, depth: usize) -> Self {
         self.config.depth = depth;
         self
@@ -356,6 +451,28 @@ impl QAOABuilder {
         self
     }
 
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.config.seed = Some(seed);
+        self
+    }
+
+    pub fn cache_capacity(mut self, capacity: usize) -> Self {
+        self.config.cache_capacity = capacity;
+        self
+    }
+
+    /// Observe every optimizer iteration (see [`Optimizer::with_callback`])
+    ///
+    /// The callback is shared by all starts of [`QAOA::run_multistart`];
+    /// [`CallbackAction::Stop`] ends the optimizer run it is returned from.
+    pub fn callback<F>(mut self, callback: F) -> Self
+    where
+        F: FnMut(&HistoryEntry) -> CallbackAction + Send + 'static,
+    {
+        self.callback = Some(Arc::new(std::sync::Mutex::new(Box::new(callback))));
+        self
+    }
+
     pub fn build(self) -> QAOA {
         let mut qaoa = QAOA::new(
             self.cost_hamiltonian.expect("Cost Hamiltonian must be set"),
@@ -366,6 +483,10 @@ impl QAOABuilder {
         if let Some(opt) = self.classical_optimum {
             qaoa = qaoa.with_classical_optimum(opt);
         }
+        if let Some(state) = self.initial_state {
+            qaoa = qaoa.with_initial_state(state);
+        }
+        qaoa.callback = self.callback;
 
         qaoa
     }
@@ -377,28 +498,250 @@ impl Default for QAOABuilder {
     }
 }
 
+/// Approximation ratio of QAOA at each of `depths` on `cost_hamiltonian`
+///
+/// Every depth runs with a copy of `config` (its `depth` replaced) and the
+/// default mixer, and the ratio is taken against the lowest eigenvalue of
+/// the cost Hamiltonian. For a negative minimum, as with MaxCut, it never
+/// exceeds 1; for a positive minimum it is at least 1 instead, and a zero
+/// minimum reports 1. With `interpolate`, each
+/// depth after the first starts from the previous optimum resampled by
+/// [`interpolate_angles`] instead of random angles.
+pub fn depth_sweep(
+    cost_hamiltonian: Arc<QuantumOperator>,
+    depths: &[usize],
+    config: &QAOAConfig,
+    interpolate: bool,
+) -> Vec<(usize, f64)> {
+    let optimum = cost_hamiltonian.matrix().symmetric_eigenvalues().min();
+    let mut previous: Option<Vec<f64>> = None;
+    depths
+        .iter()
+        .map(|&depth| {
+            let config = QAOAConfig {
+                depth,
+                ..config.clone()
+            };
+            let mut qaoa =
+                QAOA::new(cost_hamiltonian.clone(), None, config).with_classical_optimum(optimum);
+            if interpolate && let Some(parameters) = &previous {
+                qaoa = qaoa.with_initial_parameters(interpolate_angles(parameters, depth));
+            }
+            let result = qaoa.run();
+            previous = Some(result.optimal_parameters);
+            (depth, result.approximation_ratio)
+        })
+        .collect()
+}
+
+/// Resample QAOA angles (γ₁..γ_p, β₁..β_p) to `depth` layers
+///
+/// The γ and β schedules are each linearly interpolated over the circuit,
+/// the standard way to carry a depth-p optimum to depth p + 1 (Zhou et al.,
+/// PRX 10, 021067 (2020)).
+///
+/// # Panics
+/// If `parameters` is empty or has an odd length, since it then holds no
+/// complete (γ, β) layer.
+pub fn interpolate_angles(parameters: &[f64], depth: usize) -> Vec<f64> {
+    assert!(
+        !parameters.is_empty() && parameters.len().is_multiple_of(2),
+        "QAOA angles must hold at least one (γ, β) layer, got {} values",
+        parameters.len()
+    );
+    let p = parameters.len() / 2;
+    let resample = |schedule: &[f64]| -> Vec<f64> {
+        (0..depth)
+            .map(|j| {
+                if p == 1 || depth == 1 {
+                    return schedule[0];
+                }
+                let x = j as f64 * (p - 1) as f64 / (depth - 1) as f64;
+                let lower = x.floor() as usize;
+                let upper = (lower + 1).min(p - 1);
+                let frac = x - lower as f64;
+                schedule[lower] * (1.0 - frac) + schedule[upper] * frac
+            })
+            .collect()
+    };
+    let mut angles = resample(&parameters[..p]);
+    angles.extend(resample(&parameters[p..]));
+    angles
+}
+
+/// Assignment read from measuring node `index`: node `i` lies in partition
+/// `(index >> i) & 1`
+pub fn node_assignment(index: usize) -> Vec<bool> {
+    (0..METATRON_DIMENSION)
+        .map(|i| (index >> i) & 1 == 1)
+        .collect()
+}
+
+/// Number of `edges` crossing the partition `assignment`
+pub fn cut_value(assignment: &[bool], edges: &[(usize, usize)]) -> f64 {
+    weighted_cut_value(assignment, &unit_weights(edges))
+}
+
+/// Total weight of the `(i, j, weight)` edges crossing `assignment`
+pub fn weighted_cut_value(assignment: &[bool], edges: &[(usize, usize, f64)]) -> f64 {
+    edges
+        .iter()
+        .filter(|&&(i, j, _)| assignment[i] != assignment[j])
+        .map(|&(_, _, weight)| weight)
+        .sum()
+}
+
+/// Highest-cut assignment among sampled `counts` and its cut value
+///
+/// Ties go to the more frequently sampled assignment. Returns `None` when
+/// nothing was sampled.
+pub fn best_assignment(
+    counts: &HashMap<Vec<bool>, usize>,
+    edges: &[(usize, usize)],
+) -> Option<(Vec<bool>, f64)> {
+    best_weighted_assignment(counts, &unit_weights(edges))
+}
+
+/// [`best_assignment`] scored by [`weighted_cut_value`]
+pub fn best_weighted_assignment(
+    counts: &HashMap<Vec<bool>, usize>,
+    edges: &[(usize, usize, f64)],
+) -> Option<(Vec<bool>, f64)> {
+    counts
+        .iter()
+        .map(|(assignment, &count)| (assignment, count, weighted_cut_value(assignment, edges)))
+        .max_by(|a, b| a.2.total_cmp(&b.2).then(a.1.cmp(&b.1)).then(b.0.cmp(a.0)))
+        .map(|(assignment, _, cut)| (assignment.clone(), cut))
+}
+
 /// Factory function to create MaxCut cost Hamiltonian
+///
+/// Every edge has weight 1; see [`create_weighted_maxcut_hamiltonian`].
 pub fn create_maxcut_hamiltonian(edges: &[(usize, usize)]) -> QuantumOperator {
+    create_weighted_maxcut_hamiltonian(&unit_weights(edges))
+}
+
+/// MaxCut cost Hamiltonian with a weight per `(i, j, weight)` edge
+///
+/// Each edge contributes its weight times the unweighted term, so heavier
+/// edges dominate the cost QAOA minimises. Edges touching nodes outside
+/// the register are ignored.
+pub fn create_weighted_maxcut_hamiltonian(edges: &[(usize, usize, f64)]) -> QuantumOperator {
     let mut hamiltonian = OperatorMatrix::zeros();
 
-    // H_C = -1/2 * Σ_{(i,j)∈E} (I - Z_i Z_j)
+    // H_C = -1/2 * Σ_{(i,j)∈E} w_ij (I - Z_i Z_j)
     // Simplified for 13-dim Hilbert space
-    for &(i, j) in edges {
+    for &(i, j, weight) in edges {
         if i < METATRON_DIMENSION && j < METATRON_DIMENSION {
             // Add edge contribution
-            hamiltonian[(i, i)] -= Complex64::new(0.5, 0.0);
-            hamiltonian[(j, j)] -= Complex64::new(0.5, 0.0);
-            hamiltonian[(i, j)] += Complex64::new(0.5, 0.0);
-            hamiltonian[(j, i)] += Complex64::new(0.5, 0.0);
+            let half = Complex64::new(0.5 * weight, 0.0);
+            hamiltonian[(i, i)] -= half;
+            hamiltonian[(j, j)] -= half;
+            hamiltonian[(i, j)] += half;
+            hamiltonian[(j, i)] += half;
         }
     }
 
     QuantumOperator::from_matrix(hamiltonian)
 }
 
+/// Diagonal cost Hamiltonian of an Ising model on the node register
+///
+/// Basis state `k` encodes the spins of [`node_assignment`]`(k)`, with
+/// `s_i = +1` for `false` and `s_i = -1` for `true`, and gets energy
+///
+/// E(s) = Σᵢ hᵢ sᵢ + Σ_{(i,j)} J_ij sᵢ sⱼ
+///
+/// from `linear[i] = hᵢ` and `quadratic` entries `(i, j, J_ij)`. QAOA
+/// minimises this energy, so favourable configurations need negative
+/// terms: a ferromagnetic pair has `J_ij < 0`. A QUBO over `x ∈ {0, 1}`
+/// maps here via `xᵢ = (1 - sᵢ) / 2`, dropping the constant offset; MaxCut
+/// is the case `hᵢ = 0`, `J_ij = w_ij / 2`, whose energy is the cut weight
+/// negated plus a constant.
+///
+/// Only the 13 basis states exist, so spins above bit 3 are pinned to
+/// `+1` in every basis state. Missing `linear` entries count as zero and
+/// terms on nodes outside the register are ignored.
+pub fn create_ising_hamiltonian(
+    linear: &[f64],
+    quadratic: &[(usize, usize, f64)],
+) -> QuantumOperator {
+    let mut hamiltonian = OperatorMatrix::zeros();
+
+    for k in 0..METATRON_DIMENSION {
+        let spins: Vec<f64> = node_assignment(k)
+            .iter()
+            .map(|&up| if up { -1.0 } else { 1.0 })
+            .collect();
+        let field: f64 = linear.iter().zip(&spins).map(|(h, s)| h * s).sum();
+        let coupling: f64 = quadratic
+            .iter()
+            .filter(|&&(i, j, _)| i < METATRON_DIMENSION && j < METATRON_DIMENSION)
+            .map(|&(i, j, coupling)| coupling * spins[i] * spins[j])
+            .sum();
+        hamiltonian[(k, k)] = Complex64::new(field + coupling, 0.0);
+    }
+
+    QuantumOperator::from_matrix(hamiltonian)
+}
+
+fn unit_weights(edges: &[(usize, usize)]) -> Vec<(usize, usize, f64)> {
+    edges.iter().map(|&(i, j)| (i, j, 1.0)).collect()
+}
+
+/// Sign-pattern initial state for a cut assignment.
+///
+/// Every node gets amplitude ±1/√13: `+` for nodes on the `false` side of the
+/// cut and `−` for nodes on the `true` side. Opposite signs across an edge are
+/// what the MaxCut cost from [`create_maxcut_hamiltonian`] rewards, so the
+/// state starts QAOA with the cut's correlations already in place.
+pub fn sign_pattern_state(assignment: &[bool]) -> QuantumState {
+    assert_eq!(
+        assignment.len(),
+        METATRON_DIMENSION,
+        "sign-pattern state requires one side per node"
+    );
+
+    let amplitudes: Vec<Complex64> = assignment
+        .iter()
+        .map(|&side| Complex64::new(if side { -1.0 } else { 1.0 }, 0.0))
+        .collect();
+    QuantumState::try_new(&amplitudes, true).expect("sign-pattern state has 13 amplitudes")
+}
+
+/// XY ring mixer: B = ½ Σᵢ (XᵢXᵢ₊₁ + YᵢYᵢ₊₁) on the 13-node ring.
+///
+/// In the one-hot encoding each XY term hops amplitude between neighbouring
+/// nodes, |i⟩⟨i+1| + |i+1⟩⟨i|, preserving Hamming weight.
+pub fn ring_mixer() -> QuantumOperator {
+    let mut mixer = OperatorMatrix::zeros();
+    for i in 0..METATRON_DIMENSION {
+        let j = (i + 1) % METATRON_DIMENSION;
+        mixer[(i, j)] = Complex64::new(1.0, 0.0);
+        mixer[(j, i)] = Complex64::new(1.0, 0.0);
+    }
+    QuantumOperator::from_matrix(mixer)
+}
+
+/// Complete-graph XY mixer: B = ½ Σ_{i<j} (XᵢXⱼ + YᵢYⱼ).
+///
+/// Couples every pair of nodes while preserving Hamming weight.
+pub fn xy_mixer() -> QuantumOperator {
+    let mut mixer = OperatorMatrix::zeros();
+    for i in 0..METATRON_DIMENSION {
+        for j in 0..METATRON_DIMENSION {
+            if i != j {
+                mixer[(i, j)] = Complex64::new(1.0, 0.0);
+            }
+        }
+    }
+    QuantumOperator::from_matrix(mixer)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use approx::assert_relative_eq;
 
     #[test]
     fn test_qaoa_basic() {
@@ -424,6 +767,189 @@ mod tests {
 
         assert!(result.optimal_cost.is_finite());
         assert!(result.approximation_ratio.is_finite());
+
+        let multistart = qaoa.run_multistart(2);
+        assert_eq!(multistart.final_values.len(), 2);
+        assert_eq!(
+            multistart.best.optimal_cost,
+            multistart.final_values[multistart.best_start]
+        );
+    }
+
+    #[test]
+    fn test_sampled_bitstrings_and_best_cut() {
+        let edges = vec![(0, 1), (1, 2), (2, 0)];
+        let qaoa = QAOABuilder::new()
+            .cost_hamiltonian(Arc::new(create_maxcut_hamiltonian(&edges)))
+            .seed(17)
+            .verbose(false)
+            .build();
+        let state = QuantumState::uniform_superposition();
+
+        let counts = qaoa.sample_bitstrings(&state, 200);
+        assert_eq!(counts.values().sum::<usize>(), 200);
+        assert_eq!(counts, qaoa.sample_bitstrings(&state, 200));
+        assert!(counts.keys().all(|bits| bits.len() == METATRON_DIMENSION));
+
+        // Node 1 reads as {0} vs rest and node 3 as {0, 1} vs rest: both cut
+        // two triangle edges, node 0 (all in one set) cuts none.
+        let counts = HashMap::from([
+            (node_assignment(0), 5),
+            (node_assignment(1), 2),
+            (node_assignment(3), 3),
+        ]);
+        let (best, cut) = best_assignment(&counts, &edges).unwrap();
+        assert_eq!(cut, 2.0);
+        assert_eq!(best, node_assignment(3));
+        assert!(best_assignment(&HashMap::new(), &edges).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "must hold 2 * depth angles")]
+    fn test_initial_parameters_must_match_depth() {
+        let cost_h = Arc::new(create_maxcut_hamiltonian(&[(0, 1)]));
+        let config = QAOAConfig {
+            depth: 2,
+            ..QAOAConfig::default()
+        };
+        let _ = QAOA::new(cost_h, None, config).with_initial_parameters(vec![0.1, 0.2]);
+    }
+
+    #[test]
+    fn test_depth_sweep_with_interpolation() {
+        let angles = interpolate_angles(&[0.2, 0.4, 1.0, 0.0], 3);
+        let expected = [0.2, 0.3, 0.4, 1.0, 0.5, 0.0];
+        assert_eq!(angles.len(), expected.len());
+        for (angle, expected) in angles.iter().zip(expected) {
+            assert_relative_eq!(*angle, expected, epsilon = 1e-12);
+        }
+
+        // Diagonal cost with minimum −6 on node 0; the uniform start is no
+        // eigenstate of it, so every depth can make progress.
+        let mut cost_matrix = OperatorMatrix::zeros();
+        for i in 0..METATRON_DIMENSION {
+            cost_matrix[(i, i)] = Complex64::new(i as f64 - 6.0, 0.0);
+        }
+        let cost_h = Arc::new(QuantumOperator::from_matrix(cost_matrix));
+        let config = QAOAConfig {
+            optimizer_config: OptimizerConfig {
+                max_iterations: 100,
+                verbose: false,
+                ..QAOAConfig::default().optimizer_config
+            },
+            seed: Some(3),
+            ..Default::default()
+        };
+        let sweep = depth_sweep(cost_h, &[1, 2, 3], &config, true);
+        assert_eq!(
+            sweep.iter().map(|&(depth, _)| depth).collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+        assert!(sweep.iter().all(|&(_, ratio)| ratio <= 1.0 + 1e-9));
+        // Interpolated starts let the deeper circuits improve on p = 1
+        assert!(sweep[1].1 > sweep[0].1);
+        assert!(sweep[2].1 > sweep[0].1);
+    }
+
+    #[test]
+    fn test_weighted_maxcut_changes_optimal_partition() {
+        let square = [(0, 1), (1, 2), (2, 3), (3, 0)];
+        let mut weighted: Vec<_> = square.iter().map(|&(i, j)| (i, j, 1.0)).collect();
+        assert_eq!(
+            create_weighted_maxcut_hamiltonian(&weighted).matrix(),
+            create_maxcut_hamiltonian(&square).matrix()
+        );
+
+        // Best partition among all that split {0..4}, by brute force
+        let best = |edges: &[(usize, usize, f64)]| {
+            (0..16usize)
+                .map(|mask| {
+                    (0..METATRON_DIMENSION)
+                        .map(|i| (mask >> i) & 1 == 1)
+                        .collect::<Vec<_>>()
+                })
+                .max_by(|a, b| {
+                    weighted_cut_value(a, edges).total_cmp(&weighted_cut_value(b, edges))
+                })
+                .unwrap()
+        };
+        // Unit weights: alternate around the square, 0 and 2 together
+        let unweighted = best(&weighted);
+        assert_eq!(unweighted[0], unweighted[2]);
+
+        // Sign pattern of the Hamiltonian's ground state, the partition a
+        // sign-pattern start encodes; nodes with zero amplitude are undecided
+        let ground_signs = |edges: &[(usize, usize, f64)]| {
+            let h = create_weighted_maxcut_hamiltonian(edges);
+            let eigen = h.matrix().symmetric_eigen();
+            let ground = eigen.eigenvalues.imin();
+            let amplitudes = eigen.eigenvectors.column(ground).map(|a| a.re);
+            amplitudes
+                .iter()
+                .map(|&a| if a.abs() < 1e-9 { 0.0 } else { a.signum() })
+                .collect::<Vec<_>>()
+        };
+        let signs = ground_signs(&weighted);
+        assert_eq!(signs[0], signs[2]);
+        assert_eq!(signs[1], signs[3]);
+        assert_eq!(signs[0], -signs[1]);
+        assert_ne!(signs[0], 0.0);
+        let unit = create_weighted_maxcut_hamiltonian(&weighted);
+
+        // A heavy chord 0–2 must be cut, separating 0 and 2
+        weighted.push((0, 2, 10.0));
+        let heavy = best(&weighted);
+        assert_ne!(heavy[0], heavy[2]);
+        assert_eq!(weighted_cut_value(&heavy, &weighted), 12.0);
+
+        // The Hamiltonian's minimum moves with it
+        let signs = ground_signs(&weighted);
+        assert_eq!(signs[0], -signs[2]);
+        assert_ne!(signs[0], 0.0);
+
+        // and only the chord's four entries change, by half its weight
+        let h = create_weighted_maxcut_hamiltonian(&weighted);
+        let diff = h.matrix() - unit.matrix();
+        for r in 0..METATRON_DIMENSION {
+            for c in 0..METATRON_DIMENSION {
+                let expected = match (r, c) {
+                    (0, 0) | (2, 2) => -5.0,
+                    (0, 2) | (2, 0) => 5.0,
+                    _ => 0.0,
+                };
+                assert_eq!(
+                    diff[(r, c)],
+                    Complex64::new(expected, 0.0),
+                    "entry ({r}, {c})"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_ising_hamiltonian_hand_solved_optimum() {
+        // E = 0.5 s0 - s1 + 0.5 s2 - 2 s0 s1 + s1 s2. The coupling aligns s0
+        // and s1; both +1 gives -2.5 + 1.5 s2, minimised by s2 = -1 at -4.
+        // As x = (0, 0, 1) that is basis state 4.
+        let h = create_ising_hamiltonian(&[0.5, -1.0, 0.5], &[(0, 1, -2.0), (1, 2, 1.0)]);
+        let m = h.matrix();
+
+        let energies: Vec<f64> = (0..METATRON_DIMENSION).map(|k| m[(k, k)].re).collect();
+        let ground = (0..METATRON_DIMENSION)
+            .min_by(|&a, &b| energies[a].total_cmp(&energies[b]))
+            .unwrap();
+        assert_eq!(ground, 4);
+        assert!((energies[4] + 4.0).abs() < 1e-12);
+        // s0 = s1 = -1 gives -1.5 - 0.5 s2, so x = (1, 1, 0) is next at -2
+        assert!((energies[3] + 2.0).abs() < 1e-12);
+
+        for i in 0..METATRON_DIMENSION {
+            for j in 0..METATRON_DIMENSION {
+                if i != j {
+                    assert_eq!(m[(i, j)], Complex64::new(0.0, 0.0));
+                }
+            }
+        }
     }
 
     #[test]
@@ -435,4 +961,96 @@ mod tests {
         let energy = state.expectation_value(&h);
         assert!(energy.re.is_finite());
     }
+
+    #[test]
+    fn test_xy_mixers_need_non_uniform_start() {
+        let uniform = QuantumState::uniform_superposition();
+        for (mixer, eigenvalue) in [(ring_mixer(), 2.0), (xy_mixer(), 12.0)] {
+            assert!((mixer.matrix() - mixer.matrix().adjoint()).norm() < 1e-12);
+            // Uniform superposition is an eigenstate: the mixer only adds a phase
+            let mixed = uniform.apply(&mixer);
+            assert!((mixed.amplitudes() - uniform.amplitudes().scale(eigenvalue)).norm() < 1e-12);
+        }
+
+        let mut cost_matrix = OperatorMatrix::zeros();
+        for i in 0..METATRON_DIMENSION {
+            cost_matrix[(i, i)] = Complex64::new(i as f64, 0.0);
+        }
+        let qaoa = QAOABuilder::new()
+            .cost_hamiltonian(Arc::new(QuantumOperator::from_matrix(cost_matrix)))
+            .mixer_hamiltonian(Arc::new(ring_mixer()))
+            .initial_state(QuantumState::basis_state(6).unwrap())
+            .depth(1)
+            .max_iterations(20)
+            .verbose(false)
+            .build();
+        let result = qaoa.run();
+        assert!(result.optimal_cost.is_finite());
+    }
+
+    #[test]
+    fn test_warm_start_beats_cold_start() {
+        let graph = crate::graph::metatron::MetatronGraph::new();
+        let edges = graph.edges().to_vec();
+        let cost_h = Arc::new(create_maxcut_hamiltonian(&edges));
+
+        // Greedy cut: place each node opposite the majority of placed neighbours
+        let mut assignment = [false; METATRON_DIMENSION];
+        for node in 1..METATRON_DIMENSION {
+            let (mut same, mut opposite) = (0, 0);
+            for &(u, v) in &edges {
+                let other = if u == node {
+                    v
+                } else if v == node {
+                    u
+                } else {
+                    continue;
+                };
+                if other < node {
+                    if assignment[other] {
+                        same += 1
+                    } else {
+                        opposite += 1
+                    }
+                }
+            }
+            assignment[node] = same < opposite;
+        }
+
+        let classical_optimum = cost_h.matrix().symmetric_eigenvalues().min();
+        let run = |warm: bool| {
+            let mut builder = QAOABuilder::new()
+                .cost_hamiltonian(cost_h.clone())
+                .depth(1)
+                .max_iterations(100)
+                .classical_optimum(classical_optimum)
+                .verbose(false);
+            if warm {
+                builder = builder.warm_start(&assignment);
+            }
+            builder.build().run().approximation_ratio
+        };
+
+        let cold = run(false);
+        let warm = run(true);
+        assert!(warm > cold, "warm {} vs cold {}", warm, cold);
+        assert!(warm <= 1.0 + 1e-9, "ratio {} exceeds the optimum", warm);
+    }
+
+    #[test]
+    fn test_qaoa_circuit_preserves_normalization() {
+        let graph = crate::graph::metatron::MetatronGraph::new();
+        let cost_h = Arc::new(create_maxcut_hamiltonian(graph.edges()));
+        for mixer in [QAOA::default_mixer(), ring_mixer(), xy_mixer()] {
+            let cost_fn = QAOACostFunction::new(
+                cost_h.clone(),
+                Arc::new(mixer),
+                3,
+                QuantumState::basis_state(0).unwrap(),
+            );
+            // Angles far outside the regime where I − iHt is a fair approximation
+            let state = cost_fn.prepare_state(&[0.7, 2.3, -4.1, 1.9, 0.4, 3.3]);
+            assert!(state.is_normalized(1e-10));
+        }
+    }
 }
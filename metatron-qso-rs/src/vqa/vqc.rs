@@ -5,14 +5,18 @@
 //! for prediction.
 //!
 //! Mathematical formulation:
-//! L(θ) = Σᵢ loss(y_i, P₀(x_i, θ))
-//! where P₀(x, θ) = |⟨0|U(x,θ)|ψ⟩|²
+//! L(θ) = −Σᵢ ln P_{y_i}(x_i, θ)
+//! where P_c(x, θ) = |⟨c|U(x,θ)|ψ(x)⟩|² for classes c < K − 1 and the last
+//! class collects the remaining nodes (see [`class_probabilities`]). For
+//! K = 2 this is P₀ versus 1 − P₀.
 
 use crate::quantum::state::QuantumState;
-use crate::vqa::ansatz::{Ansatz, AnsatzType, create_ansatz};
-use crate::vqa::cost_function::{GradientMethod, VQCCostFunction};
+use crate::vqa::ansatz::{Ansatz, AnsatzType, InitStrategy, create_ansatz};
+use crate::vqa::cost_function::{GradientMethod, VQCCostFunction, class_probabilities};
 use crate::vqa::optimizer::{OptimizationResult, Optimizer, OptimizerConfig, OptimizerType};
-use rand::Rng;
+use rand::SeedableRng;
+use rand::rngs::SmallRng;
+use rand::seq::SliceRandom;
 use std::sync::Arc;
 
 /// VQC Configuration
@@ -23,6 +27,17 @@ pub struct VQCConfig {
     pub optimizer_type: OptimizerType,
     pub optimizer_config: OptimizerConfig,
     pub encoding_type: EncodingType,
+    /// Number of classes K (2 to 13); labels must lie in `0..K`
+    pub num_classes: usize,
+    /// Initial-parameter strategy (default `SmallRandom { scale: 0.1 }`)
+    pub init_strategy: InitStrategy,
+    /// Seed for the initial parameters and, unless `optimizer_config.spsa.seed`
+    /// is set, the SPSA perturbations. `None` draws from entropy, so repeated
+    /// training runs differ.
+    pub seed: Option<u64>,
+    /// Capacity of the LRU evaluation cache; 0 (the default) disables it.
+    /// Leave it off for stochastic optimizers such as SPSA.
+    pub cache_capacity: usize,
 }
 
 /// Data encoding type
@@ -49,8 +64,13 @@ impl Default for VQCConfig {
                 verbose: true,
                 tolerance: 1e-4,
                 energy_tolerance: 1e-3,
+                ..Default::default()
             },
             encoding_type: EncodingType::Angle,
+            num_classes: 2,
+            init_strategy: InitStrategy::SmallRandom { scale: 0.1 },
+            seed: None,
+            cache_capacity: 0,
         }
     }
 }
@@ -72,10 +92,82 @@ pub struct VQCPrediction {
     pub confidence: f64,
 }
 
+/// Per-class evaluation metrics from [`VQC::evaluate_detailed`]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClassificationReport {
+    /// `confusion_matrix[actual][predicted]` sample counts
+    pub confusion_matrix: Vec<Vec<usize>>,
+    /// Correct share of the samples predicted as each class
+    pub precision: Vec<f64>,
+    /// Correct share of the samples of each class
+    pub recall: Vec<f64>,
+    /// Harmonic mean of precision and recall per class
+    pub f1: Vec<f64>,
+    /// Overall fraction of correct predictions
+    pub accuracy: f64,
+}
+
+impl ClassificationReport {
+    /// Tally predicted against actual labels over `num_classes` classes.
+    ///
+    /// Metrics with an empty denominator (a class never predicted or never
+    /// present) are reported as 0.
+    pub fn from_predictions(predicted: &[usize], actual: &[usize], num_classes: usize) -> Self {
+        assert_eq!(predicted.len(), actual.len());
+        let mut confusion_matrix = vec![vec![0; num_classes]; num_classes];
+        for (&p, &a) in predicted.iter().zip(actual) {
+            confusion_matrix[a][p] += 1;
+        }
+
+        let ratio = |num: usize, den: usize| {
+            if den == 0 {
+                0.0
+            } else {
+                num as f64 / den as f64
+            }
+        };
+        let correct: Vec<usize> = (0..num_classes).map(|c| confusion_matrix[c][c]).collect();
+        let precision: Vec<f64> = (0..num_classes)
+            .map(|c| ratio(correct[c], confusion_matrix.iter().map(|row| row[c]).sum()))
+            .collect();
+        let recall: Vec<f64> = (0..num_classes)
+            .map(|c| ratio(correct[c], confusion_matrix[c].iter().sum()))
+            .collect();
+        let f1 = precision
+            .iter()
+            .zip(&recall)
+            .map(|(p, r)| {
+                if p + r > 0.0 {
+                    2.0 * p * r / (p + r)
+                } else {
+                    0.0
+                }
+            })
+            .collect();
+
+        Self {
+            confusion_matrix,
+            precision,
+            recall,
+            f1,
+            accuracy: ratio(correct.iter().sum(), actual.len()),
+        }
+    }
+}
+
+/// Train and test partitions produced by [`train_test_split`]
+#[derive(Clone, Debug)]
+pub struct DatasetSplit {
+    pub train_data: Vec<Vec<f64>>,
+    pub train_labels: Vec<usize>,
+    pub test_data: Vec<Vec<f64>>,
+    pub test_labels: Vec<usize>,
+}
+
 /// Variational Quantum Classifier
 pub struct VQC {
     config: VQCConfig,
-    ansatz: Box<dyn Ansatz>,
+    ansatz: Arc<dyn Ansatz>,
     optimal_parameters: Option<Vec<f64>>,
     // Normalization parameters learned from training data
     feature_min: Option<Vec<f64>>,
@@ -85,8 +177,14 @@ pub struct VQC {
 impl VQC {
     /// Create new VQC instance
     pub fn new(config: VQCConfig) -> Self {
-        let ansatz = create_ansatz(config.ansatz_type.clone(), config.ansatz_depth);
+        let ansatz = Arc::from(create_ansatz(
+            config.ansatz_type.clone(),
+            config.ansatz_depth,
+        ));
+        Self::with_ansatz(config, ansatz)
+    }
 
+    fn with_ansatz(config: VQCConfig, ansatz: Arc<dyn Ansatz>) -> Self {
         Self {
             config,
             ansatz,
@@ -110,6 +208,7 @@ impl VQC {
         println!("Ansatz Type:            {:?}", self.config.ansatz_type);
         println!("Ansatz Depth:           {}", self.config.ansatz_depth);
         println!("Encoding Type:          {:?}", self.config.encoding_type);
+        println!("Classes:                {}", self.config.num_classes);
         println!("Number of Parameters:   {}", self.ansatz.num_parameters());
         println!("Optimizer:              {:?}", self.config.optimizer_type);
         println!("═══════════════════════════════════════════════════════");
@@ -125,54 +224,25 @@ impl VQC {
             .map(|data| self.encode_data(data))
             .collect();
 
-        // Convert labels to probabilities (binary classification)
-        let label_probs: Vec<f64> = training_labels
-            .iter()
-            .map(|&label| if label == 0 { 1.0 } else { 0.0 })
-            .collect();
-
-        // Create cost function - we need to box the ansatz for polymorphism
-        // For now, we'll use a different approach with trait objects
-        struct AnsatzWrapper {
-            inner: Box<dyn Ansatz>,
-        }
-
-        impl Ansatz for AnsatzWrapper {
-            fn apply(&self, state: &QuantumState, parameters: &[f64]) -> QuantumState {
-                self.inner.apply(state, parameters)
-            }
-
-            fn num_parameters(&self) -> usize {
-                self.inner.num_parameters()
-            }
-
-            fn ansatz_type(&self) -> AnsatzType {
-                self.inner.ansatz_type()
-            }
-
-            fn depth(&self) -> usize {
-                self.inner.depth()
-            }
-        }
-
-        let wrapped_ansatz = AnsatzWrapper {
-            inner: create_ansatz(self.config.ansatz_type.clone(), self.config.ansatz_depth),
-        };
-
-        let cost_function = Arc::new(VQCCostFunction::new(
-            wrapped_ansatz,
-            encoded_states.clone(),
-            label_probs.clone(),
-        ));
+        let cost_function = Arc::new(
+            VQCCostFunction::multiclass(
+                self.ansatz.clone(),
+                encoded_states.clone(),
+                &training_labels,
+                self.config.num_classes,
+            )
+            .with_cache_capacity(self.config.cache_capacity),
+        );
 
         // Generate initial parameters
         let initial_parameters = self.generate_initial_parameters();
 
         // Run optimization
-        let optimizer = Optimizer::new(
-            self.config.optimizer_type.clone(),
-            self.config.optimizer_config.clone(),
-        );
+        let mut optimizer_config = self.config.optimizer_config.clone();
+        if optimizer_config.spsa.seed.is_none() {
+            optimizer_config.spsa.seed = self.config.seed;
+        }
+        let optimizer = Optimizer::new(self.config.optimizer_type.clone(), optimizer_config);
         let optimization_result = optimizer.optimize(cost_function.clone(), initial_parameters);
 
         // Store optimal parameters
@@ -202,7 +272,14 @@ impl VQC {
         println!("Training Accuracy:      {:.2}%", training_accuracy * 100.0);
         println!("Training Loss:          {:.6}", training_loss);
         println!("Iterations:             {}", optimization_result.iterations);
-        println!("Converged:              {}", optimization_result.converged);
+        println!(
+            "Converged:              {}",
+            optimization_result.converged()
+        );
+        println!(
+            "Stop Reason:            {:?}",
+            optimization_result.stop_reason
+        );
         println!(
             "Quantum Evaluations:    {}",
             optimization_result.history.total_quantum_evaluations
@@ -239,13 +316,13 @@ impl VQC {
         // Get probabilities
         let probs = output_state.probabilities();
 
-        // For binary classification: P(class 0) vs P(class 1)
-        let prob_class_0 = probs[0];
-        let prob_class_1 = 1.0 - prob_class_0;
-
-        let class_probabilities = vec![prob_class_0, prob_class_1];
-        let predicted_class = if prob_class_0 > prob_class_1 { 0 } else { 1 };
-        let confidence = class_probabilities[predicted_class];
+        // Bin node probabilities into classes; ties go to the higher class
+        let class_probabilities = class_probabilities(&probs, self.config.num_classes);
+        let (predicted_class, &confidence) = class_probabilities
+            .iter()
+            .enumerate()
+            .max_by(|a, b| a.1.total_cmp(b.1))
+            .expect("at least two classes");
 
         VQCPrediction {
             class_probabilities,
@@ -289,55 +366,42 @@ impl VQC {
     /// Angle encoding: data as rotation angles
     ///
     /// Encodes classical data into quantum state via parameterized rotations.
-    /// Uses RY rotations for feature encoding with proper normalization.
     ///
     /// Strategy:
-    /// 1. Start from |0⟩ state
-    /// 2. Apply Hadamard-like operation for superposition
-    /// 3. Apply feature-dependent RY rotations
+    /// 1. Start from |0⟩, the centre node
+    /// 2. Feature i applies RY(2π/3 · value_i) on the spoke (0, k) with
+    ///    k = 1 + (i mod 12), the centre's edge to node k
     ///
-    /// Feature i is encoded as: RY(π * value_i) on qubit i
+    /// Every feature owns a node (features beyond the twelfth wrap around)
+    /// and rotates amplitude out of the centre. The rotation angle stays
+    /// within 2π/3, so the centre keeps at least half of its amplitude per
+    /// feature and each feature moves the state whatever the values of the
+    /// others.
     fn angle_encoding(&self, data: &[f64]) -> QuantumState {
         use crate::quantum::operator::{OperatorMatrix, QuantumOperator};
+        use crate::quantum::state::METATRON_DIMENSION;
         use num_complex::Complex64;
         use std::f64::consts::PI;
 
         // Start from basis state |0⟩ for better classification
         let mut state = QuantumState::basis_state(0).unwrap();
 
-        // Apply Hadamard-like operation for initial superposition
-        let mut hadamard = OperatorMatrix::identity();
-        let sqrt2_inv = 1.0 / 2.0_f64.sqrt();
-        for i in 0..crate::quantum::state::METATRON_DIMENSION.min(2) {
-            hadamard[(i, i)] = Complex64::new(sqrt2_inv, 0.0);
-            if i + 1 < crate::quantum::state::METATRON_DIMENSION {
-                hadamard[(i, i + 1)] = Complex64::new(sqrt2_inv, 0.0);
-                hadamard[(i + 1, i)] = Complex64::new(sqrt2_inv, 0.0);
-                hadamard[(i + 1, i + 1)] = Complex64::new(-sqrt2_inv, 0.0);
-            }
-        }
-        let h_op = QuantumOperator::from_matrix(hadamard);
-        state = state.apply(&h_op);
-
-        // Apply feature-dependent rotations (RY gates)
+        // Apply feature-dependent rotations (RY gates) along the spokes
         for (i, &value) in data.iter().enumerate() {
-            if i >= crate::quantum::state::METATRON_DIMENSION - 1 {
-                break;
-            }
+            let k = 1 + i % (METATRON_DIMENSION - 1);
 
-            // Map normalized data [0,1] to rotation angle [0, π]
-            // This creates better separation for classification
-            let angle = value * PI;
+            // Map normalized data [0,1] to rotation angle [0, 2π/3]
+            let angle = value * 2.0 * PI / 3.0;
 
             // Create RY rotation matrix
             let mut rotation = OperatorMatrix::identity();
             let cos_half = (angle / 2.0).cos();
             let sin_half = (angle / 2.0).sin();
 
-            rotation[(i, i)] = Complex64::new(cos_half, 0.0);
-            rotation[(i, i + 1)] = Complex64::new(-sin_half, 0.0);
-            rotation[(i + 1, i)] = Complex64::new(sin_half, 0.0);
-            rotation[(i + 1, i + 1)] = Complex64::new(cos_half, 0.0);
+            rotation[(0, 0)] = Complex64::new(cos_half, 0.0);
+            rotation[(0, k)] = Complex64::new(-sin_half, 0.0);
+            rotation[(k, 0)] = Complex64::new(sin_half, 0.0);
+            rotation[(k, k)] = Complex64::new(cos_half, 0.0);
 
             let operator = QuantumOperator::from_matrix(rotation);
             state = state.apply(&operator);
@@ -432,10 +496,15 @@ impl VQC {
 
     /// Generate initial parameters
     fn generate_initial_parameters(&self) -> Vec<f64> {
-        let mut rng = rand::thread_rng();
-        (0..self.ansatz.num_parameters())
-            .map(|_| rng.gen_range(-0.1..0.1))
-            .collect()
+        let mut rng = match self.config.seed {
+            Some(seed) => SmallRng::seed_from_u64(seed),
+            None => SmallRng::from_entropy(),
+        };
+        self.config.init_strategy.sample(
+            self.ansatz.num_parameters(),
+            self.ansatz.depth(),
+            &mut rng,
+        )
     }
 
     /// Evaluate model on test data
@@ -453,17 +522,118 @@ impl VQC {
 
         correct as f64 / test_labels.len() as f64
     }
+
+    /// Evaluate model on test data with a confusion matrix and per-class
+    /// precision, recall and F1 over the configured classes
+    pub fn evaluate_detailed(
+        &self,
+        test_data: &[Vec<f64>],
+        test_labels: &[usize],
+    ) -> ClassificationReport {
+        let predictions: Vec<usize> = test_data
+            .iter()
+            .map(|data| self.predict(data).predicted_class)
+            .collect();
+        ClassificationReport::from_predictions(&predictions, test_labels, self.config.num_classes)
+    }
+}
+
+/// Shuffle the samples with `seed` and put the first `⌊train_ratio · n⌋` in
+/// the training partition, the rest in the test partition.
+///
+/// # Panics
+///
+/// Panics if `data` and `labels` differ in length or `train_ratio` is not in
+/// [0, 1].
+pub fn train_test_split(
+    data: &[Vec<f64>],
+    labels: &[usize],
+    train_ratio: f64,
+    seed: u64,
+) -> DatasetSplit {
+    assert_eq!(data.len(), labels.len());
+    assert!(
+        (0.0..=1.0).contains(&train_ratio),
+        "train_ratio must lie in [0, 1]"
+    );
+    let indices = shuffled_indices(data.len(), &mut SmallRng::seed_from_u64(seed));
+    let num_train = (train_ratio * data.len() as f64).floor() as usize;
+    let (train, test) = indices.split_at(num_train);
+    DatasetSplit {
+        train_data: train.iter().map(|&i| data[i].clone()).collect(),
+        train_labels: train.iter().map(|&i| labels[i]).collect(),
+        test_data: test.iter().map(|&i| data[i].clone()).collect(),
+        test_labels: test.iter().map(|&i| labels[i]).collect(),
+    }
+}
+
+/// K-fold cross-validation: per-fold test accuracies of a fresh [`VQC`]
+/// trained on the other `k − 1` folds.
+///
+/// Samples are shuffled with `config.seed` (from entropy if `None`) and cut
+/// into `k` folds whose sizes differ by at most one. Each fold's classifier
+/// uses the same `config`, seed included.
+///
+/// # Panics
+///
+/// Panics if `data` and `labels` differ in length or `k` is not in
+/// `2..=data.len()`.
+pub fn cross_validate(
+    config: &VQCConfig,
+    data: &[Vec<f64>],
+    labels: &[usize],
+    k: usize,
+) -> Vec<f64> {
+    assert_eq!(data.len(), labels.len());
+    assert!(
+        (2..=data.len()).contains(&k),
+        "k must lie in 2..={}",
+        data.len()
+    );
+    let mut rng = match config.seed {
+        Some(seed) => SmallRng::seed_from_u64(seed),
+        None => SmallRng::from_entropy(),
+    };
+    let indices = shuffled_indices(data.len(), &mut rng);
+
+    let mut accuracies = Vec::with_capacity(k);
+    let mut start = 0;
+    for fold in 0..k {
+        let size = data.len() / k + usize::from(fold < data.len() % k);
+        let test = &indices[start..start + size];
+        let train = indices[..start].iter().chain(&indices[start + size..]);
+        start += size;
+
+        let mut vqc = VQC::new(config.clone());
+        vqc.train(
+            train.clone().map(|&i| data[i].clone()).collect(),
+            train.map(|&i| labels[i]).collect(),
+        );
+        accuracies.push(vqc.evaluate(
+            test.iter().map(|&i| data[i].clone()).collect(),
+            test.iter().map(|&i| labels[i]).collect(),
+        ));
+    }
+    accuracies
+}
+
+fn shuffled_indices(len: usize, rng: &mut SmallRng) -> Vec<usize> {
+    let mut indices: Vec<usize> = (0..len).collect();
+    indices.shuffle(rng);
+    indices
 }
 
 /// Builder for VQC
 pub struct VQCBuilder {
     config: VQCConfig,
+    custom_ansatz: Option<Arc<dyn Ansatz>>,
 }
 
 impl VQCBuilder {
     pub fn new() -> Self {
         Self {
             config: VQCConfig::default(),
+            custom_ansatz: None,
         }
     }
 
@@ -477,11 +647,27 @@ impl VQCBuilder {
         self
     }
 
+    /// Classify with `ansatz` instead of a built-in [`AnsatzType`]
+    ///
+    /// Takes precedence over the configured [`AnsatzType`] and sets the depth
+    /// to the ansatz's own. [`cross_validate`] rebuilds classifiers from a
+    /// config alone, so it trains the configured built-in type instead.
+    pub fn custom_ansatz(mut self, ansatz: Box<dyn Ansatz>) -> Self {
+        self.config.ansatz_depth = ansatz.depth();
+        self.custom_ansatz = Some(Arc::from(ansatz));
+        self
+    }
+
     pub fn encoding(mut self, encoding_type: EncodingType) -> Self {
         self.config.encoding_type = encoding_type;
         self
     }
 
+    pub fn num_classes(mut self, num_classes: usize) -> Self {
+        self.config.num_classes = num_classes;
+        self
+    }
+
     pub fn optimizer(mut self, optimizer_type: OptimizerType) -> Self {
         self.config.optimizer_type = optimizer_type;
         self
@@ -507,8 +693,26 @@ impl VQCBuilder {
         self
     }
 
+    pub fn init_strategy(mut self, strategy: InitStrategy) -> Self {
+        self.config.init_strategy = strategy;
+        self
+    }
+
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.config.seed = Some(seed);
+        self
+    }
+
+    pub fn cache_capacity(mut self, capacity: usize) -> Self {
+        self.config.cache_capacity = capacity;
+        self
+    }
+
     pub fn build(self) -> VQC {
-        VQC::new(self.config)
+        match self.custom_ansatz {
+            Some(ansatz) => VQC::with_ansatz(self.config, ansatz),
+            None => VQC::new(self.config),
+        }
     }
 }
 
@@ -568,4 +772,155 @@ mod tests {
         assert!(prediction.predicted_class <= 1);
         assert!(prediction.confidence >= 0.0 && prediction.confidence <= 1.0);
     }
+
+    #[test]
+    fn test_vqc_three_class_separable() {
+        // Three well-separated clusters in the first two features
+        let centres = [[0.1, 0.1], [0.9, 0.1], [0.1, 0.9]];
+        let offsets = [[0.0, 0.0], [0.05, -0.03], [-0.04, 0.05], [0.03, 0.04]];
+        let mut data = Vec::new();
+        let mut labels = Vec::new();
+        for (class, centre) in centres.iter().enumerate() {
+            for offset in &offsets {
+                data.push(vec![centre[0] + offset[0], centre[1] + offset[1]]);
+                labels.push(class);
+            }
+        }
+
+        let mut vqc = VQCBuilder::new()
+            .num_classes(3)
+            .ansatz_depth(2)
+            .max_iterations(100)
+            .learning_rate(0.05)
+            .seed(1)
+            .verbose(false)
+            .build();
+        let result = vqc.train(data.clone(), labels.clone());
+        assert!(
+            result.training_accuracy > 0.8,
+            "accuracy {}",
+            result.training_accuracy
+        );
+
+        let prediction = vqc.predict(&data[0]);
+        assert_eq!(prediction.class_probabilities.len(), 3);
+        assert!((prediction.class_probabilities.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_angle_encoding_uses_every_feature() {
+        let vqc = VQCBuilder::new().verbose(false).build();
+        let base = vec![0.3; 16];
+        let encoded = vqc.encode_data(&base);
+        assert!(encoded.is_normalized(1e-10));
+
+        // Feature 10 and the wrapped feature 14 both move the state.
+        for feature in [10, 14] {
+            let mut changed = base.clone();
+            changed[feature] = 0.9;
+            let fidelity = encoded.inner_product(&vqc.encode_data(&changed)).norm_sqr();
+            assert!(fidelity < 0.99, "feature {} fidelity {}", feature, fidelity);
+        }
+
+        // Even when all earlier features are zero.
+        let mut sparse = vec![0.0; 13];
+        let zero = vqc.encode_data(&sparse);
+        sparse[10] = 1.0;
+        assert!(zero.inner_product(&vqc.encode_data(&sparse)).norm_sqr() < 0.99);
+    }
+
+    #[test]
+    fn test_split_and_cross_validate() {
+        let data: Vec<Vec<f64>> = (0..10)
+            .map(|i| vec![0.1 * i as f64, 1.0 - 0.1 * i as f64])
+            .collect();
+        let labels: Vec<usize> = (0..10).map(|i| usize::from(i >= 5)).collect();
+
+        let split = train_test_split(&data, &labels, 0.7, 9);
+        assert_eq!(split.train_data.len(), 7);
+        assert_eq!(split.test_labels.len(), 3);
+        let mut seen: Vec<f64> = split
+            .train_data
+            .iter()
+            .chain(&split.test_data)
+            .map(|x| x[0])
+            .collect();
+        seen.sort_by(f64::total_cmp);
+        assert_eq!(seen, data.iter().map(|x| x[0]).collect::<Vec<_>>());
+        assert_eq!(
+            train_test_split(&data, &labels, 0.7, 9).test_data,
+            split.test_data
+        );
+
+        let config = VQCConfig {
+            ansatz_depth: 1,
+            optimizer_config: OptimizerConfig {
+                max_iterations: 10,
+                verbose: false,
+                ..Default::default()
+            },
+            seed: Some(2),
+            ..Default::default()
+        };
+        let accuracies = cross_validate(&config, &data, &labels, 3);
+        assert_eq!(accuracies.len(), 3);
+        assert!(accuracies.iter().all(|a| (0.0..=1.0).contains(a)));
+        assert_eq!(accuracies, cross_validate(&config, &data, &labels, 3));
+    }
+
+    #[test]
+    fn test_classification_report_on_imbalanced_data() {
+        // Eight negatives, two positives; one of each misclassified
+        let actual = [0, 0, 0, 0, 0, 0, 0, 0, 1, 1];
+        let predicted = [0, 0, 0, 0, 0, 0, 0, 1, 1, 0];
+        let report = ClassificationReport::from_predictions(&predicted, &actual, 2);
+        assert_eq!(report.confusion_matrix, vec![vec![7, 1], vec![1, 1]]);
+        assert!((report.accuracy - 0.8).abs() < 1e-12);
+        assert!((report.precision[0] - 7.0 / 8.0).abs() < 1e-12);
+        assert!((report.recall[1] - 0.5).abs() < 1e-12);
+        assert!((report.f1[1] - 0.5).abs() < 1e-12);
+
+        // Always predicting the majority class hides the minority entirely
+        let majority = ClassificationReport::from_predictions(&[0; 10], &actual, 2);
+        assert!((majority.accuracy - 0.8).abs() < 1e-12);
+        assert_eq!(majority.precision[1], 0.0);
+        assert_eq!(majority.f1[1], 0.0);
+
+        let mut vqc = VQCBuilder::new()
+            .ansatz_depth(1)
+            .max_iterations(10)
+            .seed(5)
+            .verbose(false)
+            .build();
+        let data: Vec<Vec<f64>> = (0..10).map(|i| vec![0.1 * i as f64, 0.5]).collect();
+        vqc.train(data.clone(), actual.to_vec());
+        let report = vqc.evaluate_detailed(&data, &actual);
+        assert_eq!(report.accuracy, vqc.evaluate(data, actual.to_vec()));
+        assert_eq!(report.confusion_matrix.iter().flatten().sum::<usize>(), 10);
+    }
+
+    #[test]
+    fn test_vqc_seed_makes_training_reproducible() {
+        let training_data = vec![
+            vec![0.1, 0.2, 0.0, 0.0],
+            vec![0.9, 0.8, 0.0, 0.0],
+            vec![0.2, 0.1, 0.0, 0.0],
+            vec![0.8, 0.9, 0.0, 0.0],
+        ];
+        let training_labels = vec![0, 1, 0, 1];
+        let train = |seed: u64| {
+            VQCBuilder::new()
+                .ansatz_depth(1)
+                .optimizer(OptimizerType::SPSA)
+                .max_iterations(20)
+                .seed(seed)
+                .verbose(false)
+                .build()
+                .train(training_data.clone(), training_labels.clone())
+                .optimal_parameters
+        };
+
+        assert_eq!(train(3), train(3));
+        assert_ne!(train(3), train(4));
+    }
 }
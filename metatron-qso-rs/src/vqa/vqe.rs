@@ -10,12 +10,25 @@
 
 use crate::hamiltonian::MetatronHamiltonian;
 use crate::quantum::state::QuantumState;
-use crate::vqa::ansatz::{AnsatzType, create_ansatz};
-use crate::vqa::cost_function::{GradientMethod, VQECostFunction};
-use crate::vqa::optimizer::{OptimizationResult, Optimizer, OptimizerConfig, OptimizerType};
-use rand::Rng;
+use crate::vqa::HistoryEntry;
+use crate::vqa::ansatz::{Ansatz, AnsatzType, InitStrategy, create_ansatz};
+use crate::vqa::cost_function::{
+    CostFunction, GradientMethod, VQECostFunction, shift_gradient_with_value,
+};
+use crate::vqa::optimizer::{
+    CallbackAction, OptimizationResult, Optimizer, OptimizerConfig, OptimizerType, SharedCallback,
+};
+use crate::vqa::{MultistartResult, ParameterVector};
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
 use std::sync::Arc;
 
+/// Half-width of the uniform draw for a freshly added layer in
+/// [`VQE::run_layerwise`] when no [`InitStrategy`] is configured; all ansätze
+/// reduce to (close to) the identity at zero angles, so the new layer barely
+/// perturbs the trained prefix.
+const LAYERWISE_INIT_SCALE: f64 = 1e-2;
+
 /// VQE Algorithm Configuration
 #[derive(Clone, Debug)]
 pub struct VQEConfig {
@@ -27,6 +40,15 @@ pub struct VQEConfig {
     /// Number of random initialization attempts (multi-start strategy)
     /// If > 1, runs optimization multiple times and keeps best result
     pub num_random_starts: usize,
+    /// Initial-parameter strategy. `None` keeps the ansatz-specific default:
+    /// `SmallRandom { scale: 0.1 }` for HardwareEfficient/EfficientSU2 and a
+    /// deterministic symmetric cosine pattern for Metatron.
+    pub init_strategy: Option<InitStrategy>,
+    /// Seed for the initial parameters (`None` draws from entropy)
+    pub seed: Option<u64>,
+    /// Capacity of the LRU evaluation cache; 0 (the default) disables it.
+    /// Leave it off for stochastic optimizers such as SPSA.
+    pub cache_capacity: usize,
 }
 
 /// Type of initial state
@@ -51,6 +73,9 @@ impl Default for VQEConfig {
             optimizer_config: OptimizerConfig::default(),
             initial_state_type: InitialStateType::UniformSuperposition,
             num_random_starts: 1,
+            init_strategy: None,
+            seed: None,
+            cache_capacity: 0,
         }
     }
 }
@@ -64,12 +89,23 @@ pub struct VQEResult {
     pub optimization_result: OptimizationResult,
     pub classical_ground_energy: f64,
     pub approximation_error: f64,
+    /// Optimised energy after each layer of [`VQE::run_layerwise`];
+    /// empty for [`VQE::run`].
+    pub layer_energies: Vec<f64>,
+    /// Norm of the energy gradient at the optimal parameters, computed with
+    /// the configured gradient method (parameter shifts if it is `None`).
+    pub gradient_norm: f64,
+    /// Energy variance ⟨H²⟩ − ⟨H⟩² of the optimised state; zero for an
+    /// exact eigenstate.
+    pub energy_variance: f64,
 }
 
 /// Variational Quantum Eigensolver
 pub struct VQE {
     hamiltonian: Arc<MetatronHamiltonian>,
     config: VQEConfig,
+    custom_ansatz: Option<Arc<dyn Ansatz>>,
+    callback: Option<SharedCallback>,
 }
 
 impl VQE {
@@ -78,6 +114,8 @@ impl VQE {
         Self {
             hamiltonian,
             config,
+            custom_ansatz: None,
+            callback: None,
         }
     }
 
@@ -96,19 +134,18 @@ impl VQE {
         println!("Initial State: {:?}", self.config.initial_state_type);
 
         // Create ansatz
-        let ansatz1 = create_ansatz(self.config.ansatz_type.clone(), self.config.ansatz_depth);
-        let ansatz2 = create_ansatz(self.config.ansatz_type.clone(), self.config.ansatz_depth);
+        let ansatz1 = self.ansatz();
+        let ansatz2 = self.ansatz();
         let num_params = ansatz1.num_parameters();
         println!("Ansatz Type: {:?}", self.config.ansatz_type);
         println!("Ansatz Depth: {}", self.config.ansatz_depth);
         println!("Number of Parameters: {}", num_params);
 
         // Create cost function
-        let cost_function = Arc::new(VQECostFunction::new(
-            self.hamiltonian.clone(),
-            ansatz1,
-            initial_state.clone(),
-        ));
+        let cost_function = Arc::new(
+            VQECostFunction::new(self.hamiltonian.clone(), ansatz1, initial_state.clone())
+                .with_cache_capacity(self.config.cache_capacity),
+        );
 
         println!("Optimizer: {:?}", self.config.optimizer_type);
 
@@ -125,47 +162,14 @@ impl VQE {
         let optimizer = Optimizer::new(
             self.config.optimizer_type.clone(),
             self.config.optimizer_config.clone(),
-        );
-
-        let mut best_result: Option<OptimizationResult> = None;
-        let mut total_evaluations = 0;
-
-        for trial in 0..self.config.num_random_starts {
-            if self.config.num_random_starts > 1 && self.config.optimizer_config.verbose {
-                println!(
-                    "\n--- Trial {}/{} ---",
-                    trial + 1,
-                    self.config.num_random_starts
-                );
-            }
-
-            // Generate initial parameters for this trial
-            let initial_parameters = self.generate_initial_parameters(num_params);
-
-            // Run optimization
-            let result = optimizer.optimize(cost_function.clone(), initial_parameters);
-            total_evaluations += result.history.total_quantum_evaluations;
-
-            // Keep best result
-            match &best_result {
-                None => best_result = Some(result),
-                Some(prev_best) => {
-                    if result.optimal_cost < prev_best.optimal_cost {
-                        if self.config.num_random_starts > 1 && self.config.optimizer_config.verbose
-                        {
-                            println!("  → New best energy: {:.10}", result.optimal_cost);
-                        }
-                        best_result = Some(result);
-                    }
-                }
-            }
-        }
+        )
+        .with_shared_callback(self.callback.as_ref());
 
-        let mut optimization_result =
-            best_result.expect("At least one optimization should have run");
-
-        // Update total evaluations to include all trials
-        optimization_result.history.total_quantum_evaluations = total_evaluations;
+        let mut rng = self.rng();
+        let optimization_result =
+            self.optimize_multistart(&optimizer, cost_function.clone(), || {
+                self.generate_initial_parameters(num_params, &mut rng)
+            });
 
         // Reconstruct ground state wavefunction
         let ground_state_wavefunction =
@@ -173,6 +177,11 @@ impl VQE {
 
         // Compute approximation error
         let approximation_error = (optimization_result.optimal_cost - classical_ground).abs();
+        let gradient_norm = self.gradient_norm(
+            cost_function.as_ref(),
+            &optimization_result.optimal_parameters,
+        );
+        let energy_variance = self.hamiltonian.energy_variance(&ground_state_wavefunction);
 
         println!("═══════════════════════════════════════════════════════");
         println!("  VQE Results");
@@ -187,8 +196,17 @@ impl VQE {
             "Relative Error:         {:.6}%",
             (approximation_error / classical_ground.abs()) * 100.0
         );
+        println!("Gradient Norm:          {:.3e}", gradient_norm);
+        println!("Energy Variance:        {:.3e}", energy_variance);
         println!("Iterations:             {}", optimization_result.iterations);
-        println!("Converged:              {}", optimization_result.converged);
+        println!(
+            "Converged:              {}",
+            optimization_result.converged()
+        );
+        println!(
+            "Stop Reason:            {:?}",
+            optimization_result.stop_reason
+        );
         println!(
             "Quantum Evaluations:    {}",
             optimization_result.history.total_quantum_evaluations
@@ -205,6 +223,218 @@ impl VQE {
             optimization_result,
             classical_ground_energy: classical_ground,
             approximation_error,
+            layer_energies: Vec::new(),
+            gradient_norm,
+            energy_variance,
+        }
+    }
+
+    /// Run [`VQE::run`] from `num_starts` independently seeded initial
+    /// parameter vectors and keep the lowest energy.
+    ///
+    /// Start `k` is seeded with `seed + k` (a seed drawn from entropy if none
+    /// is configured) and runs a single trial, ignoring `num_random_starts`.
+    /// The final energy of every start is kept, so the spread shows how
+    /// rugged the landscape is.
+    pub fn run_multistart(&self, num_starts: usize) -> MultistartResult<VQEResult> {
+        let base_seed = self.config.seed.unwrap_or_else(|| self.rng().r#gen());
+        MultistartResult::collect(base_seed, num_starts, |seed| {
+            let config = VQEConfig {
+                seed: Some(seed),
+                num_random_starts: 1,
+                ..self.config.clone()
+            };
+            let vqe = VQE {
+                hamiltonian: self.hamiltonian.clone(),
+                config,
+                custom_ansatz: self.custom_ansatz.clone(),
+                callback: self.callback.clone(),
+            };
+            let result = vqe.run();
+            let energy = result.ground_state_energy;
+            (result, energy)
+        })
+    }
+
+    /// Run VQE layer by layer up to the configured ansatz depth.
+    ///
+    /// The depth-1 circuit is optimised first. Its parameters are then frozen
+    /// and the next layer is appended with near-zero (near-identity) angles,
+    /// so every stage starts from the previous optimum and only trains the
+    /// new layer's parameters. This avoids the flat landscape of a deep,
+    /// randomly initialised circuit. Each stage honours `num_random_starts`,
+    /// and `OptimizerConfig::bounds`, if set, must cover the full depth.
+    ///
+    /// A new layer receives the slice of angles the configured
+    /// [`InitStrategy`] assigns to it at the current depth (so
+    /// `IdentityBlock` appends exact identity layers); without one, angles
+    /// are drawn uniformly from ±0.01.
+    ///
+    /// # Panics
+    ///
+    /// Panics with a custom ansatz, which cannot be grown layer by layer.
+    pub fn run_layerwise(&self) -> VQEResult {
+        assert!(
+            self.custom_ansatz.is_none(),
+            "layerwise VQE needs a built-in ansatz type, not a custom ansatz"
+        );
+        let depth = self.config.ansatz_depth.max(1);
+        let classical_ground = self.hamiltonian.eigenvalues()[0];
+        let initial_state = self.create_initial_state();
+        let per_layer = create_ansatz(self.config.ansatz_type.clone(), 1).num_parameters();
+        let strategy = self
+            .config
+            .init_strategy
+            .clone()
+            .unwrap_or(InitStrategy::SmallRandom {
+                scale: LAYERWISE_INIT_SCALE,
+            });
+        let mut rng = self.rng();
+
+        println!("═══════════════════════════════════════════════════════");
+        println!(
+            "  Layerwise VQE ({:?}, depth {})",
+            self.config.ansatz_type, depth
+        );
+        println!("═══════════════════════════════════════════════════════");
+
+        let mut parameters: ParameterVector = Vec::with_capacity(per_layer * depth);
+        let mut layer_energies = Vec::with_capacity(depth);
+        let mut total_evaluations = 0;
+        let mut last_result = None;
+
+        for layer in 1..=depth {
+            let mut optimizer_config = self.config.optimizer_config.clone();
+            if let Some(bounds) = &optimizer_config.bounds {
+                optimizer_config.bounds = Some(bounds[parameters.len()..][..per_layer].to_vec());
+            }
+            let optimizer = Optimizer::new(self.config.optimizer_type.clone(), optimizer_config)
+                .with_shared_callback(self.callback.as_ref());
+            let cost_function = Arc::new(FrozenPrefixCost {
+                inner: Arc::new(
+                    VQECostFunction::new(
+                        self.hamiltonian.clone(),
+                        create_ansatz(self.config.ansatz_type.clone(), layer),
+                        initial_state.clone(),
+                    )
+                    .with_cache_capacity(self.config.cache_capacity),
+                ),
+                frozen: parameters.clone(),
+            });
+
+            let frozen = parameters.len();
+            let result = self.optimize_multistart(&optimizer, cost_function, || {
+                strategy
+                    .sample(frozen + per_layer, layer, &mut rng)
+                    .split_off(frozen)
+            });
+            println!("Layer {:>2}: E = {:.10}", layer, result.optimal_cost);
+
+            parameters.extend_from_slice(&result.optimal_parameters);
+            layer_energies.push(result.optimal_cost);
+            total_evaluations += result.history.total_quantum_evaluations;
+            last_result = Some(result);
+        }
+
+        let mut optimization_result = last_result.expect("depth is at least one");
+        optimization_result.optimal_parameters = parameters.clone();
+        optimization_result.history.total_quantum_evaluations = total_evaluations;
+
+        let ansatz = create_ansatz(self.config.ansatz_type.clone(), depth);
+        let ground_state_wavefunction = ansatz.apply(&initial_state, &parameters);
+        let approximation_error = (optimization_result.optimal_cost - classical_ground).abs();
+        let full_cost = VQECostFunction::new(
+            self.hamiltonian.clone(),
+            create_ansatz(self.config.ansatz_type.clone(), depth),
+            initial_state.clone(),
+        );
+        let gradient_norm = self.gradient_norm(&full_cost, &parameters);
+        let energy_variance = self.hamiltonian.energy_variance(&ground_state_wavefunction);
+
+        println!("Classical Ground:       {:.10}", classical_ground);
+        println!("Approximation Error:    {:.10}", approximation_error);
+        println!("═══════════════════════════════════════════════════════");
+
+        VQEResult {
+            ground_state_energy: optimization_result.optimal_cost,
+            optimal_parameters: parameters,
+            ground_state_wavefunction,
+            optimization_result,
+            classical_ground_energy: classical_ground,
+            approximation_error,
+            layer_energies,
+            gradient_norm,
+            energy_variance,
+        }
+    }
+
+    /// Optimise from `num_random_starts` initial points and keep the best run.
+    ///
+    /// The returned history counts quantum evaluations across all trials.
+    fn optimize_multistart(
+        &self,
+        optimizer: &Optimizer,
+        cost_function: Arc<dyn CostFunction>,
+        mut initial_parameters: impl FnMut() -> ParameterVector,
+    ) -> OptimizationResult {
+        let mut best_result: Option<OptimizationResult> = None;
+        let mut total_evaluations = 0;
+
+        for trial in 0..self.config.num_random_starts {
+            if self.config.num_random_starts > 1 && self.config.optimizer_config.verbose {
+                println!(
+                    "\n--- Trial {}/{} ---",
+                    trial + 1,
+                    self.config.num_random_starts
+                );
+            }
+
+            // Run optimization
+            let result = optimizer.optimize(cost_function.clone(), initial_parameters());
+            total_evaluations += result.history.total_quantum_evaluations;
+
+            // Keep best result
+            match &best_result {
+                None => best_result = Some(result),
+                Some(prev_best) => {
+                    if result.optimal_cost < prev_best.optimal_cost {
+                        if self.config.num_random_starts > 1 && self.config.optimizer_config.verbose
+                        {
+                            println!("  → New best energy: {:.10}", result.optimal_cost);
+                        }
+                        best_result = Some(result);
+                    }
+                }
+            }
+        }
+
+        let mut optimization_result =
+            best_result.expect("At least one optimization should have run");
+
+        // Update total evaluations to include all trials
+        optimization_result.history.total_quantum_evaluations = total_evaluations;
+        optimization_result
+    }
+
+    /// Norm of the energy gradient at `parameters`.
+    fn gradient_norm(&self, cost_function: &dyn CostFunction, parameters: &[f64]) -> f64 {
+        let method = match &self.config.optimizer_config.gradient_method {
+            GradientMethod::None => GradientMethod::ParameterShift,
+            method => method.clone(),
+        };
+        cost_function
+            .gradient(parameters, method)
+            .iter()
+            .map(|g| g * g)
+            .sum::<f64>()
+            .sqrt()
+    }
+
+    /// The configured ansatz: the custom one if set, else a fresh built-in.
+    fn ansatz(&self) -> Box<dyn Ansatz> {
+        match &self.custom_ansatz {
+            Some(ansatz) => Box::new(ansatz.clone()),
+            None => create_ansatz(self.config.ansatz_type.clone(), self.config.ansatz_depth),
         }
     }
 
@@ -221,12 +451,27 @@ impl VQE {
         }
     }
 
+    /// Random source for initial parameters, seeded if configured.
+    fn rng(&self) -> SmallRng {
+        match self.config.seed {
+            Some(seed) => SmallRng::seed_from_u64(seed),
+            None => SmallRng::from_entropy(),
+        }
+    }
+
     /// Generate initial parameters for the ansatz
-    fn generate_initial_parameters(&self, num_params: usize) -> Vec<f64> {
-        let mut rng = rand::thread_rng();
+    fn generate_initial_parameters(&self, num_params: usize, rng: &mut SmallRng) -> Vec<f64> {
+        if let Some(strategy) = &self.config.init_strategy {
+            return strategy.sample(num_params, self.config.ansatz_depth, rng);
+        }
+        if self.custom_ansatz.is_some() {
+            return (0..num_params).map(|_| rng.gen_range(-0.1..0.1)).collect();
+        }
 
         match self.config.ansatz_type {
-            AnsatzType::HardwareEfficient | AnsatzType::EfficientSU2 => {
+            AnsatzType::HardwareEfficient
+            | AnsatzType::EfficientSU2
+            | AnsatzType::ParticleConserving => {
                 // Random small initialization
                 (0..num_params).map(|_| rng.gen_range(-0.1..0.1)).collect()
             }
@@ -279,10 +524,65 @@ impl VQE {
     }
 }
 
+/// Cost function over the trailing parameters of a circuit whose leading
+/// parameters are frozen, used by [`VQE::run_layerwise`].
+struct FrozenPrefixCost {
+    inner: Arc<dyn CostFunction>,
+    frozen: ParameterVector,
+}
+
+impl FrozenPrefixCost {
+    fn full(&self, parameters: &[f64]) -> ParameterVector {
+        let mut full = self.frozen.clone();
+        full.extend_from_slice(parameters);
+        full
+    }
+}
+
+impl CostFunction for FrozenPrefixCost {
+    fn evaluate(&self, parameters: &[f64]) -> f64 {
+        self.inner.evaluate(&self.full(parameters))
+    }
+
+    fn evaluate_batch(&self, param_sets: &[Vec<f64>]) -> Vec<f64> {
+        let full: Vec<_> = param_sets.iter().map(|p| self.full(p)).collect();
+        self.inner.evaluate_batch(&full)
+    }
+
+    fn gradient(&self, parameters: &[f64], method: GradientMethod) -> ParameterVector {
+        self.gradient_with_value(parameters, method).1
+    }
+
+    /// Differentiates only the trainable suffix, so a gradient costs the same
+    /// number of evaluations as for a single layer. The adjoint sweep covers
+    /// the whole circuit anyway, so it runs on the inner cost and drops the
+    /// frozen components.
+    fn gradient_with_value(
+        &self,
+        parameters: &[f64],
+        method: GradientMethod,
+    ) -> (f64, ParameterVector) {
+        if method == GradientMethod::Adjoint {
+            let (value, gradient) = self
+                .inner
+                .gradient_with_value(&self.full(parameters), method);
+            return (value, gradient[self.frozen.len()..].to_vec());
+        }
+
+        shift_gradient_with_value(self, parameters, method)
+    }
+
+    fn dimension(&self) -> usize {
+        self.inner.dimension() - self.frozen.len()
+    }
+}
+
 /// Builder pattern for VQE
 pub struct VQEBuilder {
     hamiltonian: Option<Arc<MetatronHamiltonian>>,
     config: VQEConfig,
+    custom_ansatz: Option<Arc<dyn Ansatz>>,
+    callback: Option<SharedCallback>,
 }
 
 impl VQEBuilder {
@@ -290,6 +590,8 @@ impl VQEBuilder {
         Self {
             hamiltonian: None,
             config: VQEConfig::default(),
+            custom_ansatz: None,
+            callback: None,
         }
     }
 
@@ -308,6 +610,17 @@ impl VQEBuilder {
         self
     }
 
+    /// Use `ansatz` instead of a built-in [`AnsatzType`]
+    ///
+    /// Takes precedence over the configured [`AnsatzType`] and sets the depth
+    /// to the ansatz's own. Without an [`InitStrategy`], initial angles are
+    /// drawn uniformly from ±0.1.
+    pub fn custom_ansatz(mut self, ansatz: Box<dyn Ansatz>) -> Self {
+        self.config.ansatz_depth = ansatz.depth();
+        self.custom_ansatz = Some(Arc::from(ansatz));
+        self
+    }
+
     pub fn optimizer(mut self, optimizer_type: OptimizerType) -> Self {
         self.config.optimizer_type = optimizer_type;
         self
@@ -353,10 +666,39 @@ impl VQEBuilder {
         self
     }
 
+    pub fn init_strategy(mut self, strategy: InitStrategy) -> Self {
+        self.config.init_strategy = Some(strategy);
+        self
+    }
+
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.config.seed = Some(seed);
+        self
+    }
+
+    pub fn cache_capacity(mut self, capacity: usize) -> Self {
+        self.config.cache_capacity = capacity;
+        self
+    }
+
+    /// Observe every optimizer iteration (see [`Optimizer::with_callback`])
+    ///
+    /// The callback is shared by all trials, layers and starts of a run;
+    /// [`CallbackAction::Stop`] ends the optimizer run it is returned from.
+    pub fn callback<F>(mut self, callback: F) -> Self
+    where
+        F: FnMut(&HistoryEntry) -> CallbackAction + Send + 'static,
+    {
+        self.callback = Some(Arc::new(std::sync::Mutex::new(Box::new(callback))));
+        self
+    }
+
     pub fn build(self) -> VQE {
         VQE {
             hamiltonian: self.hamiltonian.expect("Hamiltonian must be set"),
             config: self.config,
+            custom_ansatz: self.custom_ansatz,
+            callback: self.callback,
         }
     }
 }
@@ -372,6 +714,7 @@ mod tests {
     use super::*;
     use crate::graph::metatron::MetatronGraph;
     use crate::params::QSOParameters;
+    use crate::quantum::operator::QuantumOperator;
 
     #[test]
     fn test_vqe_basic() {
@@ -426,4 +769,164 @@ mod tests {
         let result = vqe.run();
         assert!(vqe.verify_result(&result));
     }
+
+    #[test]
+    fn test_vqe_layerwise_records_layer_energies() {
+        let graph = MetatronGraph::new();
+        let params = QSOParameters::default();
+        let hamiltonian = Arc::new(MetatronHamiltonian::new(&graph, &params));
+
+        let vqe = VQEBuilder::new()
+            .hamiltonian(hamiltonian)
+            .ansatz_type(AnsatzType::HardwareEfficient)
+            .ansatz_depth(2)
+            .max_iterations(40)
+            .init_strategy(InitStrategy::SmallRandom { scale: 0.01 })
+            .seed(5)
+            .verbose(false)
+            .build();
+
+        let result = vqe.run_layerwise();
+        let repeat = vqe.run_layerwise();
+        assert_eq!(result.optimal_parameters, repeat.optimal_parameters);
+        assert_eq!(result.layer_energies.len(), 2);
+        assert_eq!(result.optimal_parameters.len(), 2 * 2 * 13);
+        assert_eq!(result.ground_state_energy, result.layer_energies[1]);
+        // The second stage starts near the first optimum and only improves on it.
+        assert!(result.layer_energies[1] <= result.layer_energies[0] + 1e-3);
+        assert!(vqe.verify_result(&result));
+    }
+
+    #[test]
+    fn test_energy_variance_vanishes_for_eigenstates() {
+        let graph = MetatronGraph::new();
+        let params = QSOParameters::default();
+        let hamiltonian = Arc::new(MetatronHamiltonian::new(&graph, &params));
+        assert!(hamiltonian.energy_variance(&hamiltonian.ground_state()) < 1e-10);
+        assert!(hamiltonian.energy_variance(&QuantumState::basis_state(0).unwrap()) > 1e-3);
+
+        // Starting in the exact ground state with zero angles, the optimiser
+        // has nothing to improve: flat gradient, eigenstate output.
+        let vqe = VQEBuilder::new()
+            .hamiltonian(hamiltonian.clone())
+            .ansatz_depth(1)
+            .initial_state(InitialStateType::ClassicalGround)
+            .init_strategy(InitStrategy::Zeros)
+            .max_iterations(5)
+            .verbose(false)
+            .build();
+        let result = vqe.run();
+        assert!(result.energy_variance < 1e-8);
+        assert!(result.gradient_norm < 1e-6);
+        assert!(result.approximation_error < 1e-8);
+    }
+
+    #[test]
+    fn test_vqe_multistart_is_reproducible() {
+        let graph = MetatronGraph::new();
+        let params = QSOParameters::default();
+        let hamiltonian = Arc::new(MetatronHamiltonian::new(&graph, &params));
+        let builder = || {
+            VQEBuilder::new()
+                .hamiltonian(hamiltonian.clone())
+                .ansatz_depth(1)
+                .max_iterations(30)
+                .init_strategy(InitStrategy::SmallRandom { scale: 1.0 })
+                .verbose(false)
+        };
+
+        let multistart = builder().seed(11).build().run_multistart(3);
+        assert_eq!(multistart.seeds, vec![11, 12, 13]);
+        assert_eq!(multistart.final_values.len(), 3);
+        let lowest = multistart
+            .final_values
+            .iter()
+            .copied()
+            .fold(f64::INFINITY, f64::min);
+        assert_eq!(multistart.best.ground_state_energy, lowest);
+        assert!(multistart.spread() >= 0.0);
+
+        let best_seed = multistart.seeds[multistart.best_start];
+        let rerun = builder().seed(best_seed).build().run();
+        assert_eq!(rerun.optimal_parameters, multistart.best.optimal_parameters);
+    }
+
+    #[test]
+    fn test_callback_sees_every_start_and_can_stop() {
+        use crate::vqa::optimizer::StopReason;
+        use std::sync::Mutex;
+
+        let graph = MetatronGraph::new();
+        let params = QSOParameters::default();
+        let hamiltonian = Arc::new(MetatronHamiltonian::new(&graph, &params));
+        let costs = Arc::new(Mutex::new(Vec::new()));
+        let seen = costs.clone();
+        let vqe = VQEBuilder::new()
+            .hamiltonian(hamiltonian)
+            .ansatz_type(AnsatzType::HardwareEfficient)
+            .ansatz_depth(1)
+            .max_iterations(50)
+            .num_random_starts(2)
+            .seed(5)
+            .callback(move |entry| {
+                seen.lock().unwrap().push(entry.cost);
+                if entry.iteration >= 2 {
+                    CallbackAction::Stop
+                } else {
+                    CallbackAction::Continue
+                }
+            })
+            .build();
+
+        let result = vqe.run();
+        assert_eq!(
+            result.optimization_result.stop_reason,
+            StopReason::UserAbort
+        );
+        // Both starts report to the same callback and stop after three entries
+        let costs = costs.lock().unwrap();
+        assert_eq!(costs.len(), 6);
+        assert!(costs.iter().all(|cost| cost.is_finite()));
+    }
+
+    #[test]
+    fn test_custom_identity_ansatz() {
+        struct Identity;
+
+        impl Ansatz for Identity {
+            fn apply(&self, state: &QuantumState, _parameters: &[f64]) -> QuantumState {
+                state.clone()
+            }
+
+            fn num_parameters(&self) -> usize {
+                1
+            }
+
+            fn depth(&self) -> usize {
+                1
+            }
+        }
+
+        let graph = MetatronGraph::new();
+        let params = QSOParameters::default();
+        let hamiltonian = Arc::new(MetatronHamiltonian::new(&graph, &params));
+        let vqe = VQEBuilder::new()
+            .hamiltonian(hamiltonian.clone())
+            .custom_ansatz(Box::new(Identity))
+            .optimizer(OptimizerType::NelderMead)
+            .max_iterations(10)
+            .seed(3)
+            .verbose(false)
+            .build();
+        let result = vqe.run();
+
+        let initial = QuantumState::uniform_superposition();
+        let expected = initial
+            .expectation_value(&QuantumOperator::from_matrix(
+                hamiltonian.as_complex_operator(),
+            ))
+            .re;
+        assert!((result.ground_state_energy - expected).abs() < 1e-10);
+        assert_eq!(result.optimal_parameters.len(), 1);
+    }
 }
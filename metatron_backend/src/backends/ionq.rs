@@ -0,0 +1,471 @@
+//! IonQ backend
+//!
+//! Provides integration with IonQ trapped-ion hardware via the IonQ REST API.
+//! The submission and polling calls are stubs for now; circuits are already
+//! translated to the `ionq.circuit.v0` JSON format so dry-runs validate the
+//! full gate mapping.
+//!
+//! ## Safety Features
+//!
+//! - **Default Mode: Disabled** - No QPU access unless explicitly enabled
+//! - **Dry-Run Mode** - Translate and log circuits without consuming QPU time
+//! - **Explicit Configuration** - Must set environment variables or config file
+//!
+//! ## Configuration
+//!
+//! Set these environment variables:
+//! - `IONQ_API_KEY` - Your IonQ API key (required for Enabled mode)
+//! - `IONQ_TARGET` - Target device (e.g., "simulator", "qpu.aria-1")
+//! - `IONQ_BACKEND_MODE` - "disabled", "dry-run", or "enabled" (default: "disabled")
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! use metatron_backend::IonqQuantumBackend;
+//!
+//! // Create IonQ backend (reads from environment)
+//! let backend = IonqQuantumBackend::from_env().unwrap();
+//!
+//! // Check mode
+//! println!("IonQ mode: {:?}", backend.mode());
+//! ```
+
+use super::{BackendCapabilities, QuantumBackend};
+use crate::circuit::{GateType, MeasurementResult, MetatronCircuit};
+use anyhow::{bail, Result};
+use figment::{providers::Env, Figment};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::time::Instant;
+
+/// Native trapped-ion gates of IonQ hardware.
+pub const IONQ_NATIVE_GATES: &[&str] = &["gpi", "gpi2", "ms"];
+
+/// Gates of the IonQ `qis` gateset that circuits are translated to.
+pub const IONQ_QIS_GATES: &[&str] = &[
+    "x", "y", "z", "h", "s", "si", "t", "ti", "rx", "ry", "rz", "cnot", "swap",
+];
+
+/// IonQ backend execution mode
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum IonqMode {
+    /// Backend is disabled - will error on circuit execution
+    ///
+    /// This is the SAFE DEFAULT. No QPU access possible.
+    #[default]
+    Disabled,
+
+    /// Dry-run mode - translate and log circuits without executing
+    ///
+    /// Circuits are converted to IonQ JSON and logged, but not sent.
+    /// Returns stubbed measurement results for testing.
+    DryRun,
+
+    /// Enabled - submit circuits to IonQ
+    ///
+    /// **WARNING**: This mode consumes QPU time and may incur costs.
+    /// Only use when explicitly authorized.
+    Enabled,
+}
+
+/// Status of a submitted IonQ job
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IonqJobStatus {
+    /// Waiting in the IonQ queue
+    Queued,
+    /// Executing on the target
+    Running,
+    /// Finished; results can be fetched
+    Completed,
+    /// Rejected or failed, with the reported reason
+    Failed(String),
+}
+
+/// Configuration for IonQ backend
+#[derive(Debug, Clone, Deserialize)]
+pub struct IonqConfig {
+    /// IonQ API key
+    #[serde(default)]
+    pub api_key: Option<String>,
+
+    /// Target device (e.g., "simulator", "qpu.aria-1")
+    #[serde(default = "default_target")]
+    pub target: String,
+
+    /// Execution mode
+    #[serde(default)]
+    pub mode: IonqMode,
+
+    /// Maximum number of shots per job
+    #[serde(default = "default_max_shots")]
+    pub max_shots: u32,
+}
+
+fn default_target() -> String {
+    "qpu.aria-1".to_string()
+}
+
+fn default_max_shots() -> u32 {
+    10_000
+}
+
+impl Default for IonqConfig {
+    fn default() -> Self {
+        Self {
+            api_key: None,
+            target: default_target(),
+            mode: IonqMode::default(),
+            max_shots: default_max_shots(),
+        }
+    }
+}
+
+impl IonqConfig {
+    /// Load configuration from environment variables
+    ///
+    /// Environment variables:
+    /// - `IONQ_API_KEY`
+    /// - `IONQ_TARGET`
+    /// - `IONQ_BACKEND_MODE`
+    /// - `IONQ_MAX_SHOTS`
+    pub fn from_env() -> Result<Self> {
+        let config: IonqConfig = Figment::new()
+            .merge(Env::prefixed("IONQ_").map(|key| {
+                // Map IONQ_BACKEND_MODE -> mode, IONQ_API_KEY -> api_key
+                key.as_str()
+                    .strip_prefix("BACKEND_")
+                    .unwrap_or(key.as_str())
+                    .to_lowercase()
+                    .into()
+            }))
+            .extract()?;
+
+        if config.mode == IonqMode::Enabled && config.api_key.is_none() {
+            bail!("IONQ_API_KEY is required when mode is 'enabled'");
+        }
+
+        Ok(config)
+    }
+
+    /// Validate configuration
+    pub fn validate(&self) -> Result<()> {
+        match self.mode {
+            IonqMode::Disabled => Ok(()),
+            IonqMode::DryRun => {
+                if self.target.is_empty() {
+                    bail!("target must be set for dry-run mode");
+                }
+                Ok(())
+            }
+            IonqMode::Enabled => {
+                if self.api_key.is_none() {
+                    bail!("api_key is required for enabled mode");
+                }
+                if self.target.is_empty() {
+                    bail!("target must be set for enabled mode");
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// IonQ backend adapter
+///
+/// Supports three modes:
+/// - Disabled (default): No execution possible
+/// - Dry-run: Translate and log circuits without execution
+/// - Enabled: Submit to IonQ (job submission and polling are not yet
+///   implemented and return descriptive errors)
+pub struct IonqQuantumBackend {
+    config: IonqConfig,
+}
+
+impl IonqQuantumBackend {
+    /// Create IonQ backend with custom configuration
+    pub fn new(config: IonqConfig) -> Result<Self> {
+        config.validate()?;
+
+        tracing::info!(
+            "IonQ backend initialized: {} (mode: {:?})",
+            config.target,
+            config.mode
+        );
+
+        if config.mode == IonqMode::Enabled {
+            tracing::warn!("IonQ backend is in ENABLED mode - will consume QPU time!");
+        }
+
+        Ok(Self { config })
+    }
+
+    /// Create IonQ backend from environment variables
+    pub fn from_env() -> Result<Self> {
+        let config = IonqConfig::from_env()?;
+        Self::new(config)
+    }
+
+    /// Get the current execution mode
+    pub fn mode(&self) -> &IonqMode {
+        &self.config.mode
+    }
+
+    /// Translate a circuit into an IonQ job body (`qis` gateset)
+    ///
+    /// Measurements are dropped since IonQ measures every qubit at the end.
+    /// Gates without a `qis` equivalent (`U`, `CPhase`) are rejected.
+    pub fn job_body(&self, circuit: &MetatronCircuit, shots: u32) -> Result<Value> {
+        let mut gates = Vec::with_capacity(circuit.gates.len());
+        for gate in &circuit.gates {
+            let q = &gate.qubits;
+            let entry = match &gate.gate_type {
+                GateType::Measure => continue,
+                GateType::H => json!({ "gate": "h", "target": q[0] }),
+                GateType::X => json!({ "gate": "x", "target": q[0] }),
+                GateType::Y => json!({ "gate": "y", "target": q[0] }),
+                GateType::Z => json!({ "gate": "z", "target": q[0] }),
+                GateType::S => json!({ "gate": "s", "target": q[0] }),
+                GateType::Sdg => json!({ "gate": "si", "target": q[0] }),
+                GateType::T => json!({ "gate": "t", "target": q[0] }),
+                GateType::Tdg => json!({ "gate": "ti", "target": q[0] }),
+                GateType::RX(theta) => json!({ "gate": "rx", "target": q[0], "rotation": theta }),
+                GateType::RY(theta) => json!({ "gate": "ry", "target": q[0], "rotation": theta }),
+                GateType::RZ(theta) => json!({ "gate": "rz", "target": q[0], "rotation": theta }),
+                GateType::CNOT => json!({ "gate": "cnot", "control": q[0], "target": q[1] }),
+                GateType::CZ => json!({ "gate": "z", "control": q[0], "target": q[1] }),
+                GateType::SWAP => json!({ "gate": "swap", "targets": [q[0], q[1]] }),
+                GateType::Toffoli => {
+                    json!({ "gate": "cnot", "controls": [q[0], q[1]], "target": q[2] })
+                }
+                unsupported => bail!(
+                    "Gate {:?} has no IonQ qis equivalent; supported gates: {}",
+                    unsupported,
+                    IONQ_QIS_GATES.join(", ")
+                ),
+            };
+            gates.push(entry);
+        }
+
+        Ok(json!({
+            "target": self.config.target,
+            "shots": shots,
+            "input": {
+                "format": "ionq.circuit.v0",
+                "gateset": "qis",
+                "qubits": circuit.num_qubits,
+                "circuit": gates,
+            },
+        }))
+    }
+
+    /// Submit a circuit and return the IonQ job id
+    pub fn submit_job(&self, circuit: &MetatronCircuit, shots: u32) -> Result<String> {
+        let _body = self.job_body(circuit, shots)?;
+        let _api_key = self.require_enabled()?;
+
+        // TODO: POST the body to https://api.ionq.co/v0.3/jobs with
+        // `Authorization: apiKey <key>` and return the `id` field.
+        bail!("IonQ job submission not yet implemented - use the IonQ REST API directly")
+    }
+
+    /// Poll the status of a submitted job
+    pub fn poll_job(&self, job_id: &str) -> Result<IonqJobStatus> {
+        let _api_key = self.require_enabled()?;
+
+        // TODO: GET https://api.ionq.co/v0.3/jobs/{job_id} and map `status`.
+        bail!(
+            "IonQ job polling not yet implemented (job '{}') - use the IonQ REST API directly",
+            job_id
+        )
+    }
+
+    /// Fetch the measurement counts of a completed job
+    pub fn fetch_results(&self, job_id: &str, shots: u32) -> Result<MeasurementResult> {
+        let _api_key = self.require_enabled()?;
+        let _ = shots;
+
+        // TODO: GET the job's results endpoint and convert the returned
+        // probability histogram (keyed by integer state) into bitstring counts.
+        bail!(
+            "IonQ result retrieval not yet implemented (job '{}') - use the IonQ REST API directly",
+            job_id
+        )
+    }
+
+    /// API key, or an error unless the backend is in Enabled mode
+    fn require_enabled(&self) -> Result<&str> {
+        if self.config.mode != IonqMode::Enabled {
+            bail!(
+                "IonQ backend '{}' is not enabled (mode: {:?})",
+                self.config.target,
+                self.config.mode
+            );
+        }
+        match &self.config.api_key {
+            Some(key) => Ok(key),
+            None => bail!("api_key is required for enabled mode"),
+        }
+    }
+
+    /// Execute in dry-run mode (translate, log and return stubbed result)
+    fn execute_dry_run(&self, circuit: &MetatronCircuit, shots: u32) -> Result<MeasurementResult> {
+        let body = self.job_body(circuit, shots)?;
+        tracing::info!(
+            "[DRY-RUN] Would submit circuit to IonQ '{}': {} qubits, {} gates, {} shots",
+            self.config.target,
+            circuit.num_qubits,
+            circuit.gates.len(),
+            shots
+        );
+        tracing::debug!("[DRY-RUN] IonQ job body: {}", body);
+
+        // Return stubbed result (equal superposition)
+        let mut counts = HashMap::new();
+        let num_outcomes = 2_usize.pow(circuit.num_qubits.min(10) as u32);
+
+        for i in 0..num_outcomes.min(100) {
+            let bitstring = format!("{:0width$b}", i, width = circuit.num_qubits);
+            counts.insert(bitstring, (shots / num_outcomes.min(100) as u32) as u64);
+        }
+
+        let mut result =
+            MeasurementResult::new(counts, shots, format!("{}_dry_run", self.config.target));
+        result.execution_time_ms = Some(0.0);
+
+        Ok(result)
+    }
+
+    /// Submit, wait for completion and fetch results
+    fn execute_real(&self, circuit: &MetatronCircuit, shots: u32) -> Result<MeasurementResult> {
+        tracing::warn!("Executing circuit on IonQ target: {}", self.config.target);
+
+        let job_id = self.submit_job(circuit, shots)?;
+        loop {
+            match self.poll_job(&job_id)? {
+                IonqJobStatus::Completed => return self.fetch_results(&job_id, shots),
+                IonqJobStatus::Failed(reason) => bail!("IonQ job '{}' failed: {}", job_id, reason),
+                IonqJobStatus::Queued | IonqJobStatus::Running => {
+                    std::thread::sleep(std::time::Duration::from_secs(1));
+                }
+            }
+        }
+    }
+}
+
+impl QuantumBackend for IonqQuantumBackend {
+    fn info(&self) -> BackendCapabilities {
+        let available = match self.config.mode {
+            IonqMode::Disabled => false,
+            IonqMode::DryRun => true,
+            IonqMode::Enabled => self.config.api_key.is_some(),
+        };
+
+        BackendCapabilities {
+            provider: "ionq".to_string(),
+            name: self.config.target.clone(),
+            num_qubits: 25, // IonQ Aria
+            is_simulator: self.config.target == "simulator",
+            max_shots: Some(self.config.max_shots),
+            available,
+            metadata: serde_json::json!({
+                "mode": format!("{:?}", self.config.mode),
+                "max_shots": self.config.max_shots,
+                "native_gates": IONQ_NATIVE_GATES,
+                "supported_gates": IONQ_QIS_GATES,
+            }),
+        }
+    }
+
+    fn run_circuit(&self, circuit: &MetatronCircuit, shots: u32) -> Result<MeasurementResult> {
+        let start = Instant::now();
+
+        if shots > self.config.max_shots {
+            bail!(
+                "Requested {} shots exceeds maximum {} for backend '{}'",
+                shots,
+                self.config.max_shots,
+                self.config.target
+            );
+        }
+
+        let result = match self.config.mode {
+            IonqMode::Disabled => {
+                bail!(
+                    "IonQ backend '{}' is disabled. Set IONQ_BACKEND_MODE=dry-run or enabled",
+                    self.config.target
+                );
+            }
+
+            IonqMode::DryRun => self.execute_dry_run(circuit, shots)?,
+
+            IonqMode::Enabled => self.execute_real(circuit, shots)?,
+        };
+
+        let execution_time = start.elapsed().as_millis() as f64;
+        tracing::info!(
+            "IonQ backend '{}' completed in {:.2}ms (mode: {:?})",
+            self.config.target,
+            execution_time,
+            self.config.mode
+        );
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_config_is_disabled() {
+        let config = IonqConfig::default();
+        assert_eq!(config.mode, IonqMode::Disabled);
+        assert!(config.api_key.is_none());
+
+        let backend = IonqQuantumBackend::new(config).unwrap();
+        let err = backend
+            .run_circuit(&MetatronCircuit::new(2), 100)
+            .unwrap_err();
+        assert!(err.to_string().contains("disabled"));
+        assert!(IonqQuantumBackend::new(IonqConfig {
+            mode: IonqMode::Enabled,
+            ..Default::default()
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn test_dry_run_translates_circuit() {
+        let backend = IonqQuantumBackend::new(IonqConfig {
+            mode: IonqMode::DryRun,
+            ..Default::default()
+        })
+        .unwrap();
+        let circuit = MetatronCircuit::new(3)
+            .h(0)
+            .cnot(0, 1)
+            .ccx(0, 1, 2)
+            .rz(2, 0.5)
+            .measure_all();
+
+        let body = backend.job_body(&circuit, 100).unwrap();
+        let gates = body["input"]["circuit"].as_array().unwrap();
+        assert_eq!(gates.len(), 4);
+        assert_eq!(gates[2]["controls"], json!([0, 1]));
+        assert_eq!(gates[3]["rotation"], json!(0.5));
+
+        let result = backend.run_circuit(&circuit, 100).unwrap();
+        assert!(result.backend_name.contains("dry_run"));
+        assert_eq!(
+            backend.info().metadata["native_gates"],
+            json!(["gpi", "gpi2", "ms"])
+        );
+
+        let unsupported = MetatronCircuit::new(1).u(0, 0.1, 0.2, 0.3);
+        assert!(backend.run_circuit(&unsupported, 10).is_err());
+        assert!(backend.submit_job(&circuit, 10).is_err());
+    }
+}
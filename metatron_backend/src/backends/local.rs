@@ -1,23 +1,85 @@
 //! Local simulator backend
 //!
-//! Wraps the existing Q⊗DASH quantum state vector simulator
+//! Exact state vector simulation of qubit circuits. Qubit `q` is bit `q` of
+//! the basis-state index, so bitstrings read `q_{n-1} … q_1 q_0` as in Qiskit.
+//! With a [`NoiseModel`] attached, circuits are run as density matrices with
+//! depolarizing Kraus channels after every gate and classical readout flips.
 
 use super::{BackendCapabilities, QuantumBackend};
-use crate::circuit::{MeasurementResult, MetatronCircuit};
-use anyhow::Result;
-use metatron_qso::quantum::state::QuantumState;
+use crate::circuit::{GateType, MeasurementResult, MetatronCircuit};
+use anyhow::{bail, Result};
+use num_complex::Complex64;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::f64::consts::FRAC_PI_4;
 use std::time::Instant;
 
+/// 2×2 single-qubit unitary, row-major.
+type SingleQubitMatrix = [[Complex64; 2]; 2];
+
+/// Largest circuit simulated as a density matrix (4ⁿ entries).
+pub const MAX_DENSITY_MATRIX_QUBITS: usize = 10;
+
+/// Gate and readout noise for the local simulator
+///
+/// Every gate is followed by a single-qubit depolarizing channel
+/// ρ → (1 − p)ρ + p/3 (XρX + YρY + ZρZ) on each qubit it touches, with
+/// `p` chosen by the gate's arity. Measured bits are then flipped
+/// independently with probability `readout_error`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct NoiseModel {
+    /// Depolarizing probability after single-qubit gates
+    pub single_qubit_depolarizing: f64,
+    /// Depolarizing probability (per qubit) after two- and three-qubit gates
+    pub multi_qubit_depolarizing: f64,
+    /// Probability of reading a measured bit flipped
+    pub readout_error: f64,
+}
+
+impl NoiseModel {
+    /// Same depolarizing probability for every gate, no readout error
+    pub fn depolarizing(p: f64) -> Self {
+        Self {
+            single_qubit_depolarizing: p,
+            multi_qubit_depolarizing: p,
+            readout_error: 0.0,
+        }
+    }
+
+    /// Builder-style setter for the readout error
+    pub fn with_readout_error(mut self, p: f64) -> Self {
+        self.readout_error = p;
+        self
+    }
+
+    fn validate(&self) -> Result<()> {
+        for (name, p) in [
+            ("single_qubit_depolarizing", self.single_qubit_depolarizing),
+            ("multi_qubit_depolarizing", self.multi_qubit_depolarizing),
+            ("readout_error", self.readout_error),
+        ] {
+            if !(0.0..=1.0).contains(&p) {
+                bail!("Noise probability {} = {} is outside [0, 1]", name, p);
+            }
+        }
+        Ok(())
+    }
+}
+
 /// Local state vector simulator backend
 ///
-/// This backend simulates quantum circuits using exact state vector evolution.
-/// It supports the full Metatron 13-dimensional Hilbert space.
+/// This backend simulates quantum circuits using exact state vector evolution
+/// over 2ⁿ amplitudes, or density-matrix evolution when a [`NoiseModel`] is
+/// attached. Measurement gates are treated as terminal: the final state is
+/// sampled once per shot.
 pub struct LocalSimulatorBackend {
     /// Number of qubits to simulate
     num_qubits: u32,
     /// Backend name
     name: String,
+    /// Optional gate/readout noise
+    noise: Option<NoiseModel>,
 }
 
 impl LocalSimulatorBackend {
@@ -31,58 +93,328 @@ impl LocalSimulatorBackend {
         Self {
             num_qubits,
             name: "local_sim".to_string(),
+            noise: None,
         }
     }
 
-    /// Execute a circuit and return the final state vector
+    /// Attach a noise model; `run_circuit` then simulates density matrices
+    pub fn with_noise(mut self, model: NoiseModel) -> Self {
+        self.noise = Some(model);
+        self
+    }
+
+    /// The attached noise model, if any
+    pub fn noise_model(&self) -> Option<&NoiseModel> {
+        self.noise.as_ref()
+    }
+
+    /// Exact final state vector of `circuit`, without sampling
     ///
-    /// Note: This is a simplified implementation for the Metatron 13-dimensional system.
-    /// For demonstration purposes, we create an equal superposition over available basis states.
-    fn execute_statevector(&self, _circuit: &MetatronCircuit) -> Result<QuantumState> {
-        // For now, we create a simple superposition state
-        // A full implementation would properly execute the gate sequence
-        // using the Metatron operator algebra
-
-        // Start with |0⟩ state
-        let state = QuantumState::basis_state(0)?;
-
-        // TODO: Implement proper gate sequence execution
-        // This would require:
-        // 1. Mapping qubit gates to Metatron 13-dimensional operators
-        // 2. Building composite operators for multi-qubit gates
-        // 3. Sequential application of gates to the state
+    /// Amplitude `k` belongs to the basis state whose bit `q` is qubit `q`.
+    /// The noise model, if any, is ignored.
+    /// Trailing measurements are ignored; a measurement followed by any other
+    /// gate is rejected, since the collapse cannot be represented by a single
+    /// pure state.
+    pub fn run_statevector(&self, circuit: &MetatronCircuit) -> Result<Vec<Complex64>> {
+        let first_measure = circuit
+            .gates
+            .iter()
+            .position(|g| g.gate_type == GateType::Measure)
+            .unwrap_or(circuit.gates.len());
+        if let Some((index, gate)) = circuit
+            .gates
+            .iter()
+            .enumerate()
+            .skip(first_measure)
+            .find(|(_, g)| g.gate_type != GateType::Measure)
+        {
+            bail!(
+                "Cannot return a statevector: gate {} ({:?} on {:?}) follows a measurement; \
+                 move measurements to the end of the circuit",
+                index,
+                gate.gate_type,
+                gate.qubits
+            );
+        }
+        self.execute_statevector(circuit)
+    }
+
+    /// Execute a circuit from |0…0⟩ and return the final state vector
+    fn execute_statevector(&self, circuit: &MetatronCircuit) -> Result<Vec<Complex64>> {
+        if circuit.num_qubits > self.num_qubits as usize {
+            bail!(
+                "Circuit uses {} qubits but {} supports at most {}",
+                circuit.num_qubits,
+                self.name,
+                self.num_qubits
+            );
+        }
+
+        let mut state = vec![Complex64::new(0.0, 0.0); 1 << circuit.num_qubits];
+        state[0] = Complex64::new(1.0, 0.0);
+
+        for gate in &circuit.gates {
+            apply_gate(&mut state, &gate.gate_type, &gate.qubits, 0, false);
+        }
 
         Ok(state)
     }
 
-    /// Sample from the final state vector
+    /// Final diagonal of the density matrix under `noise`
     ///
-    /// Performs non-destructive sampling by measuring clones of the state
+    /// ρ is stored row-major as a vector over 2n qubits, so U ρ U† is
+    /// (U ⊗ U*) acting on the row (upper n) and column (lower n) qubits.
+    fn execute_density_matrix(
+        &self,
+        circuit: &MetatronCircuit,
+        noise: &NoiseModel,
+    ) -> Result<Vec<f64>> {
+        let n = circuit.num_qubits;
+        if n > self.num_qubits as usize {
+            bail!(
+                "Circuit uses {} qubits but {} supports at most {}",
+                n,
+                self.name,
+                self.num_qubits
+            );
+        }
+        if n > MAX_DENSITY_MATRIX_QUBITS {
+            bail!(
+                "Noisy simulation is limited to {} qubits, circuit uses {}",
+                MAX_DENSITY_MATRIX_QUBITS,
+                n
+            );
+        }
+
+        let mut rho = vec![Complex64::new(0.0, 0.0); 1 << (2 * n)];
+        rho[0] = Complex64::new(1.0, 0.0);
+
+        for gate in &circuit.gates {
+            if gate.gate_type == GateType::Measure {
+                continue;
+            }
+            apply_gate(&mut rho, &gate.gate_type, &gate.qubits, n, false);
+            apply_gate(&mut rho, &gate.gate_type, &gate.qubits, 0, true);
+
+            let p = if gate.qubits.len() == 1 {
+                noise.single_qubit_depolarizing
+            } else {
+                noise.multi_qubit_depolarizing
+            };
+            if p > 0.0 {
+                for &qubit in &gate.qubits {
+                    depolarize(&mut rho, qubit, n, p);
+                }
+            }
+        }
+
+        let dim = 1 << n;
+        Ok((0..dim).map(|k| rho[k * dim + k].re.max(0.0)).collect())
+    }
+
+    /// Sample bitstrings from outcome probabilities, flipping each bit with
+    /// probability `readout_error`
     fn sample_state(
         &self,
-        state: &QuantumState,
+        probabilities: &[f64],
         shots: u32,
         num_qubits: usize,
-    ) -> Result<HashMap<String, u64>> {
+        readout_error: f64,
+    ) -> HashMap<String, u64> {
         let mut counts = HashMap::new();
         let mut rng = rand::thread_rng();
 
         for _ in 0..shots {
-            // Clone state for non-destructive measurement
-            let mut state_clone = state.clone();
-            let outcome = state_clone.measure(&mut rng)?;
+            let r: f64 = rng.gen();
+            let mut cumulative = 0.0;
+            let mut outcome = probabilities.len() - 1;
+            for (index, &p) in probabilities.iter().enumerate() {
+                cumulative += p;
+                if r < cumulative {
+                    outcome = index;
+                    break;
+                }
+            }
+            if readout_error > 0.0 {
+                for qubit in 0..num_qubits {
+                    if rng.gen::<f64>() < readout_error {
+                        outcome ^= 1 << qubit;
+                    }
+                }
+            }
 
-            // Convert outcome index to bitstring
-            let bitstring = format!("{:0width$b}", outcome, width = num_qubits.max(4));
+            let bitstring = format!("{:0width$b}", outcome, width = num_qubits.max(1));
             *counts.entry(bitstring).or_insert(0) += 1;
         }
 
-        Ok(counts)
+        counts
+    }
+}
+
+fn pauli_x() -> SingleQubitMatrix {
+    let (zero, one) = (Complex64::new(0.0, 0.0), Complex64::new(1.0, 0.0));
+    [[zero, one], [one, zero]]
+}
+
+fn pauli_z() -> SingleQubitMatrix {
+    phase(std::f64::consts::PI)
+}
+
+/// diag(1, e^{iφ})
+fn phase(phi: f64) -> SingleQubitMatrix {
+    let zero = Complex64::new(0.0, 0.0);
+    [
+        [Complex64::new(1.0, 0.0), zero],
+        [zero, Complex64::from_polar(1.0, phi)],
+    ]
+}
+
+/// U(θ, φ, λ) in the OpenQASM convention.
+fn u3(theta: f64, phi: f64, lambda: f64) -> SingleQubitMatrix {
+    let (c, s) = ((theta / 2.0).cos(), (theta / 2.0).sin());
+    [
+        [Complex64::new(c, 0.0), -Complex64::from_polar(s, lambda)],
+        [
+            Complex64::from_polar(s, phi),
+            Complex64::from_polar(c, phi + lambda),
+        ],
+    ]
+}
+
+/// Matrix of a single-qubit gate, `None` for multi-qubit gates and measurement.
+fn single_qubit_matrix(gate: &GateType) -> Option<SingleQubitMatrix> {
+    let i = Complex64::new(0.0, 1.0);
+    let zero = Complex64::new(0.0, 0.0);
+    let matrix = match *gate {
+        GateType::H => {
+            let h = Complex64::new(std::f64::consts::FRAC_1_SQRT_2, 0.0);
+            [[h, h], [h, -h]]
+        }
+        GateType::X => pauli_x(),
+        GateType::Y => [[zero, -i], [i, zero]],
+        GateType::Z => pauli_z(),
+        GateType::S => phase(2.0 * FRAC_PI_4),
+        GateType::Sdg => phase(-2.0 * FRAC_PI_4),
+        GateType::T => phase(FRAC_PI_4),
+        GateType::Tdg => phase(-FRAC_PI_4),
+        GateType::RX(theta) => {
+            let (c, s) = ((theta / 2.0).cos(), (theta / 2.0).sin());
+            [
+                [Complex64::new(c, 0.0), Complex64::new(0.0, -s)],
+                [Complex64::new(0.0, -s), Complex64::new(c, 0.0)],
+            ]
+        }
+        GateType::RY(theta) => {
+            let (c, s) = ((theta / 2.0).cos(), (theta / 2.0).sin());
+            [
+                [Complex64::new(c, 0.0), Complex64::new(-s, 0.0)],
+                [Complex64::new(s, 0.0), Complex64::new(c, 0.0)],
+            ]
+        }
+        GateType::RZ(theta) => [
+            [Complex64::from_polar(1.0, -theta / 2.0), zero],
+            [zero, Complex64::from_polar(1.0, theta / 2.0)],
+        ],
+        GateType::U(theta, phi, lambda) => u3(theta, phi, lambda),
+        GateType::CNOT
+        | GateType::CZ
+        | GateType::SWAP
+        | GateType::CPhase(_)
+        | GateType::Toffoli
+        | GateType::Measure => return None,
+    };
+    Some(matrix)
+}
+
+/// Apply a gate to qubits shifted by `offset`, conjugating the matrix if asked.
+///
+/// The offset and conjugation let the same kernels act on either side of a
+/// vectorised density matrix.
+fn apply_gate(
+    state: &mut [Complex64],
+    gate_type: &GateType,
+    qubits: &[usize],
+    offset: usize,
+    conjugate: bool,
+) {
+    let q: Vec<usize> = qubits.iter().map(|&qubit| qubit + offset).collect();
+    let conj = |m: SingleQubitMatrix| {
+        if conjugate {
+            [
+                [m[0][0].conj(), m[0][1].conj()],
+                [m[1][0].conj(), m[1][1].conj()],
+            ]
+        } else {
+            m
+        }
+    };
+    match gate_type {
+        GateType::Measure => {}
+        GateType::CNOT => apply_controlled(state, &q[..1], q[1], &pauli_x()),
+        GateType::CZ => apply_controlled(state, &q[..1], q[1], &pauli_z()),
+        GateType::CPhase(phi) => apply_controlled(state, &q[..1], q[1], &conj(phase(*phi))),
+        GateType::Toffoli => apply_controlled(state, &q[..2], q[2], &pauli_x()),
+        GateType::SWAP => apply_swap(state, q[0], q[1]),
+        single => {
+            let matrix =
+                single_qubit_matrix(single).expect("all remaining gate types act on one qubit");
+            apply_controlled(state, &[], q[0], &conj(matrix));
+        }
+    }
+}
+
+/// Single-qubit depolarizing channel on a vectorised n-qubit density matrix.
+fn depolarize(rho: &mut [Complex64], qubit: usize, n: usize, p: f64) {
+    let i = Complex64::new(0.0, 1.0);
+    let zero = Complex64::new(0.0, 0.0);
+    let pauli_y = [[zero, -i], [i, zero]];
+
+    let mut mixed = vec![Complex64::new(0.0, 0.0); rho.len()];
+    for pauli in [pauli_x(), pauli_y, pauli_z()] {
+        let mut term = rho.to_vec();
+        let conjugated = [
+            [pauli[0][0].conj(), pauli[0][1].conj()],
+            [pauli[1][0].conj(), pauli[1][1].conj()],
+        ];
+        apply_controlled(&mut term, &[], qubit + n, &pauli);
+        apply_controlled(&mut term, &[], qubit, &conjugated);
+        for (acc, value) in mixed.iter_mut().zip(term) {
+            *acc += value;
+        }
+    }
+    for (value, noise) in rho.iter_mut().zip(mixed) {
+        *value = *value * (1.0 - p) + noise * (p / 3.0);
+    }
+}
+
+/// Apply `matrix` to `target` on the amplitudes where every control qubit is 1.
+fn apply_controlled(
+    state: &mut [Complex64],
+    controls: &[usize],
+    target: usize,
+    matrix: &SingleQubitMatrix,
+) {
+    let control_mask = controls.iter().fold(0usize, |mask, &c| mask | (1 << c));
+    let target_bit = 1 << target;
+    for index in 0..state.len() {
+        if index & target_bit != 0 || index & control_mask != control_mask {
+            continue;
+        }
+        let partner = index | target_bit;
+        let (a0, a1) = (state[index], state[partner]);
+        state[index] = matrix[0][0] * a0 + matrix[0][1] * a1;
+        state[partner] = matrix[1][0] * a0 + matrix[1][1] * a1;
     }
+}
 
-    // Gate creation helpers would go here
-    // TODO: Implement proper gate construction for the Metatron 13-dimensional space
-    // This requires careful mapping from qubit gates to the 13-dimensional operator algebra
+fn apply_swap(state: &mut [Complex64], a: usize, b: usize) {
+    let (bit_a, bit_b) = (1 << a, 1 << b);
+    for index in 0..state.len() {
+        // Visit each |…1_a…0_b…⟩ ↔ |…0_a…1_b…⟩ pair once
+        if index & bit_a != 0 && index & bit_b == 0 {
+            state.swap(index, index ^ bit_a ^ bit_b);
+        }
+    }
 }
 
 impl Default for LocalSimulatorBackend {
@@ -93,17 +425,33 @@ impl Default for LocalSimulatorBackend {
 
 impl QuantumBackend for LocalSimulatorBackend {
     fn info(&self) -> BackendCapabilities {
-        BackendCapabilities::simulator("local", &self.name, self.num_qubits)
+        let mut caps = BackendCapabilities::simulator("local", &self.name, self.num_qubits);
+        if let Some(noise) = &self.noise {
+            caps.metadata = serde_json::json!({ "noise_model": noise });
+        }
+        caps
     }
 
     fn run_circuit(&self, circuit: &MetatronCircuit, shots: u32) -> Result<MeasurementResult> {
         let start = Instant::now();
 
-        // Execute circuit to get final state vector
-        let final_state = self.execute_statevector(circuit)?;
+        // Outcome probabilities of the final (pure or mixed) state
+        let (probabilities, readout_error) = match &self.noise {
+            Some(noise) => {
+                noise.validate()?;
+                (
+                    self.execute_density_matrix(circuit, noise)?,
+                    noise.readout_error,
+                )
+            }
+            None => {
+                let state = self.execute_statevector(circuit)?;
+                (state.iter().map(|a| a.norm_sqr()).collect(), 0.0)
+            }
+        };
 
         // Sample measurements from the final state
-        let counts = self.sample_state(&final_state, shots, circuit.num_qubits)?;
+        let counts = self.sample_state(&probabilities, shots, circuit.num_qubits, readout_error);
 
         let execution_time = start.elapsed().as_millis() as f64;
 
@@ -143,5 +491,105 @@ mod tests {
 
         assert_eq!(result.shots, 100);
         assert!(!result.counts.is_empty());
+        // Bell state: only correlated outcomes
+        assert!(result.counts.keys().all(|k| k == "00" || k == "11"));
+    }
+
+    #[test]
+    fn test_h_t_h_distribution() {
+        let backend = LocalSimulatorBackend::new();
+        let circuit = MetatronCircuit::new(1).h(0).t(0).h(0);
+
+        // |⟨0|HTH|0⟩|² = (1 + cos π/4) / 2
+        let state = backend.execute_statevector(&circuit).unwrap();
+        let p0 = (1.0 + FRAC_PI_4.cos()) / 2.0;
+        assert!((state[0].norm_sqr() - p0).abs() < 1e-12);
+        assert!((state[1].norm_sqr() - (1.0 - p0)).abs() < 1e-12);
+
+        let result = backend.run_circuit(&circuit.measure_all(), 20_000).unwrap();
+        assert!((result.probability("0") - p0).abs() < 0.02);
+    }
+
+    #[test]
+    fn test_run_statevector() {
+        let backend = LocalSimulatorBackend::new();
+        let bell = MetatronCircuit::new(2).h(0).cnot(0, 1).measure_all();
+        let state = backend.run_statevector(&bell).unwrap();
+        let amplitude = std::f64::consts::FRAC_1_SQRT_2;
+        assert!((state[0b00] - Complex64::new(amplitude, 0.0)).norm() < 1e-12);
+        assert!((state[0b11] - Complex64::new(amplitude, 0.0)).norm() < 1e-12);
+        assert!(state[0b01].norm() < 1e-12 && state[0b10].norm() < 1e-12);
+
+        let mid_circuit = MetatronCircuit::new(1).h(0).measure(0).h(0);
+        let err = backend.run_statevector(&mid_circuit).unwrap_err();
+        assert!(err.to_string().contains("follows a measurement"));
+    }
+
+    #[test]
+    fn test_depolarizing_noise_drives_toward_uniform() {
+        let circuit = MetatronCircuit::new(2).x(0).cnot(0, 1).h(1).h(1).x(0).x(0);
+
+        // Noiseless density simulation matches the statevector
+        let exact = LocalSimulatorBackend::new()
+            .execute_density_matrix(&circuit, &NoiseModel::default())
+            .unwrap();
+        assert!((exact[0b11] - 1.0).abs() < 1e-12);
+
+        let distance_from_uniform = |p: f64| {
+            let backend = LocalSimulatorBackend::new().with_noise(NoiseModel::depolarizing(p));
+            let probs = backend
+                .execute_density_matrix(&circuit, backend.noise_model().unwrap())
+                .unwrap();
+            assert!((probs.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+            probs.iter().map(|q| (q - 0.25).abs()).sum::<f64>() / 2.0
+        };
+        let distances: Vec<f64> = [0.0, 0.05, 0.2, 0.5]
+            .iter()
+            .map(|&p| distance_from_uniform(p))
+            .collect();
+        assert!(distances.windows(2).all(|w| w[1] < w[0]), "{:?}", distances);
+        assert!(distance_from_uniform(0.75) < 1e-9);
+
+        // Sampling path, readout error and capability metadata
+        let noisy = LocalSimulatorBackend::new()
+            .with_noise(NoiseModel::depolarizing(0.0).with_readout_error(0.5));
+        assert!(noisy.info().is_simulator);
+        assert!(noisy.info().metadata.get("noise_model").is_some());
+        let counts = noisy.run_circuit(&circuit.measure_all(), 4000).unwrap();
+        for outcome in ["00", "01", "10", "11"] {
+            assert!((counts.probability(outcome) - 0.25).abs() < 0.05);
+        }
+    }
+
+    #[test]
+    fn test_multi_qubit_gates() {
+        let backend = LocalSimulatorBackend::new();
+
+        // |110⟩ → Toffoli → |111⟩ → SWAP(0, 2) leaves it, RX(π) on 1 → |101⟩
+        let circuit = MetatronCircuit::new(3)
+            .x(1)
+            .x(2)
+            .ccx(1, 2, 0)
+            .swap(0, 2)
+            .rx(1, std::f64::consts::PI);
+        let state = backend.execute_statevector(&circuit).unwrap();
+        assert!((state[0b101].norm_sqr() - 1.0).abs() < 1e-12);
+
+        // SWAP moves an excitation
+        let swapped = backend
+            .execute_statevector(&MetatronCircuit::new(2).x(0).swap(0, 1))
+            .unwrap();
+        assert!((swapped[0b10].norm_sqr() - 1.0).abs() < 1e-12);
+
+        // RY(π/2) then RZ and S keep the populations balanced
+        let balanced = backend
+            .execute_statevector(
+                &MetatronCircuit::new(1)
+                    .ry(0, 2.0 * FRAC_PI_4)
+                    .rz(0, 0.3)
+                    .s(0),
+            )
+            .unwrap();
+        assert!((balanced[1].norm_sqr() - 0.5).abs() < 1e-12);
     }
 }
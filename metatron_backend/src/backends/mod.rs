@@ -5,6 +5,9 @@ pub mod local;
 #[cfg(feature = "ibm")]
 pub mod ibm;
 
+#[cfg(feature = "ionq")]
+pub mod ionq;
+
 use crate::circuit::{MeasurementResult, MetatronCircuit};
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
@@ -3,8 +3,12 @@
 //! Provides a backend-agnostic circuit representation that can be executed
 //! on any quantum backend.
 
+use anyhow::{anyhow, bail, Result};
+use metatron_qso::hamiltonian::MetatronHamiltonian;
+use metatron_qso::quantum::pauli::{Pauli, PAULI_QUBITS};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
+use std::f64::consts::PI;
 
 /// Gate types supported by the backend abstraction
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -121,11 +125,21 @@ impl MetatronCircuit {
         self
     }
 
+    pub fn sdg(mut self, qubit: usize) -> Self {
+        self.add_gate(GateType::Sdg, vec![qubit]);
+        self
+    }
+
     pub fn t(mut self, qubit: usize) -> Self {
         self.add_gate(GateType::T, vec![qubit]);
         self
     }
 
+    pub fn tdg(mut self, qubit: usize) -> Self {
+        self.add_gate(GateType::Tdg, vec![qubit]);
+        self
+    }
+
     pub fn rx(mut self, qubit: usize, theta: f64) -> Self {
         self.add_gate(GateType::RX(theta), vec![qubit]);
         self
@@ -156,11 +170,22 @@ impl MetatronCircuit {
         self
     }
 
+    pub fn cphase(mut self, control: usize, target: usize, phi: f64) -> Self {
+        self.add_gate(GateType::CPhase(phi), vec![control, target]);
+        self
+    }
+
     pub fn swap(mut self, qubit1: usize, qubit2: usize) -> Self {
         self.add_gate(GateType::SWAP, vec![qubit1, qubit2]);
         self
     }
 
+    /// Toffoli gate: flip `target` when both controls are |1⟩
+    pub fn ccx(mut self, control1: usize, control2: usize, target: usize) -> Self {
+        self.add_gate(GateType::Toffoli, vec![control1, control2, target]);
+        self
+    }
+
     pub fn measure(mut self, qubit: usize) -> Self {
         self.add_gate(GateType::Measure, vec![qubit]);
         self
@@ -173,6 +198,83 @@ impl MetatronCircuit {
         self
     }
 
+    /// Compile exp(-iHt) into a first-order Trotter circuit on 4 qubits
+    ///
+    /// The 13 Metatron nodes are embedded in ⌈log₂ 13⌉ = 4 qubits: node `k`
+    /// is the computational basis state with index `k`, i.e. circuit qubit
+    /// `q` carries bit `q` of the node index. H is zero-padded on the unused
+    /// states |13⟩–|15⟩ and expanded via
+    /// [`MetatronHamiltonian::pauli_decomposition`]; every Pauli term
+    /// exp(-i c Δt P) becomes a basis change, a CNOT parity ladder and one RZ.
+    /// The identity term only contributes a global phase and is dropped.
+    ///
+    /// Each of the `trotter_steps` steps applies all terms once with
+    /// Δt = t / trotter_steps. The error is O(t² / trotter_steps) and grows
+    /// with the commutators of the Pauli terms; unlike exact evolution, it can
+    /// also leak amplitude into the padding states.
+    ///
+    /// # Panics
+    /// If `trotter_steps` is zero.
+    pub fn from_hamiltonian_evolution(
+        h: &MetatronHamiltonian,
+        t: f64,
+        trotter_steps: usize,
+    ) -> Self {
+        assert!(trotter_steps > 0, "at least one Trotter step is required");
+        let dt = t / trotter_steps as f64;
+        let terms: Vec<_> = h
+            .pauli_decomposition()
+            .into_iter()
+            .filter(|(_, pauli)| pauli.weight() > 0)
+            .collect();
+
+        let mut circuit = MetatronCircuit::new(PAULI_QUBITS);
+        for _ in 0..trotter_steps {
+            for (coefficient, pauli) in &terms {
+                // Pauli-string qubit k is the k-th most significant bit
+                let support: Vec<(usize, Pauli)> = pauli
+                    .paulis()
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, &p)| p != Pauli::I)
+                    .map(|(k, &p)| (PAULI_QUBITS - 1 - k, p))
+                    .collect();
+
+                // Rotate X and Y into Z
+                for &(qubit, p) in &support {
+                    match p {
+                        Pauli::X => circuit.add_gate(GateType::H, vec![qubit]),
+                        Pauli::Y => {
+                            circuit.add_gate(GateType::Sdg, vec![qubit]);
+                            circuit.add_gate(GateType::H, vec![qubit]);
+                        }
+                        _ => {}
+                    }
+                }
+                // Parity onto the last qubit, rotate, uncompute
+                let target = support.last().expect("non-identity term").0;
+                for pair in support.windows(2) {
+                    circuit.add_gate(GateType::CNOT, vec![pair[0].0, pair[1].0]);
+                }
+                circuit.add_gate(GateType::RZ(2.0 * coefficient * dt), vec![target]);
+                for pair in support.windows(2).rev() {
+                    circuit.add_gate(GateType::CNOT, vec![pair[0].0, pair[1].0]);
+                }
+                for &(qubit, p) in &support {
+                    match p {
+                        Pauli::X => circuit.add_gate(GateType::H, vec![qubit]),
+                        Pauli::Y => {
+                            circuit.add_gate(GateType::H, vec![qubit]);
+                            circuit.add_gate(GateType::S, vec![qubit]);
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+        circuit
+    }
+
     /// Get depth of the circuit (number of layers)
     pub fn depth(&self) -> usize {
         // Simple depth calculation: just count gates
@@ -189,6 +291,389 @@ impl MetatronCircuit {
     }
 }
 
+impl MetatronCircuit {
+    /// Serialize as an OpenQASM 2.0 program using `qelib1.inc` gate names
+    ///
+    /// Controlled phase is written as `cu1`, the arbitrary unitary as `u3`.
+    pub fn to_qasm(&self) -> String {
+        let mut qasm = String::from("OPENQASM 2.0;\ninclude \"qelib1.inc\";\n");
+        qasm.push_str(&format!("qreg q[{}];\n", self.num_qubits));
+        qasm.push_str(&format!("creg c[{}];\n", self.num_clbits));
+
+        for gate in &self.gates {
+            let q = &gate.qubits;
+            let line = match &gate.gate_type {
+                GateType::Measure => format!("measure q[{}] -> c[{}]", q[0], q[0]),
+                other => {
+                    let (name, params) = qasm_name(other);
+                    let params = if params.is_empty() {
+                        String::new()
+                    } else {
+                        let list: Vec<String> = params.iter().map(|p| p.to_string()).collect();
+                        format!("({})", list.join(","))
+                    };
+                    let args: Vec<String> = q.iter().map(|i| format!("q[{}]", i)).collect();
+                    format!("{}{} {}", name, params, args.join(","))
+                }
+            };
+            qasm.push_str(&line);
+            qasm.push_str(";\n");
+        }
+        qasm
+    }
+
+    /// Parse an OpenQASM 2.0 program
+    ///
+    /// Supports a single quantum register, the gates produced by
+    /// [`to_qasm`](Self::to_qasm) (plus `cx`/`cp`/`u` spellings) and
+    /// `barrier`, which is ignored. Parameters may use `pi`, numbers,
+    /// parentheses and `+ - * /`. All unsupported gate names are reported
+    /// together in the error.
+    ///
+    /// Every gate must carry exactly its parameters, and `measure q[i] -> c[j]`
+    /// must store into the declared classical register with `j == i`, the only
+    /// layout [`MetatronCircuit`] can represent.
+    pub fn from_qasm(source: &str) -> Result<Self> {
+        let mut circuit: Option<MetatronCircuit> = None;
+        let mut qreg = String::new();
+        let mut creg: Option<String> = None;
+        let mut unsupported = BTreeSet::new();
+
+        let without_comments: String = source
+            .lines()
+            .map(|line| line.split("//").next().unwrap_or(""))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        for statement in without_comments.split(';') {
+            let statement = statement.trim();
+            if statement.is_empty()
+                || statement.starts_with("OPENQASM")
+                || statement.starts_with("include")
+                || statement.starts_with("barrier")
+            {
+                continue;
+            }
+
+            if let Some(decl) = statement.strip_prefix("qreg") {
+                if circuit.is_some() {
+                    bail!("Only a single qreg is supported");
+                }
+                let (name, size) = parse_indexed(decl.trim())?;
+                qreg = name.to_string();
+                circuit = Some(MetatronCircuit::new(size));
+                continue;
+            }
+            if let Some(decl) = statement.strip_prefix("creg") {
+                if creg.is_some() {
+                    bail!("Only a single creg is supported");
+                }
+                let (name, size) = parse_indexed(decl.trim())?;
+                creg = Some(name.to_string());
+                if let Some(c) = circuit.as_mut() {
+                    c.num_clbits = size;
+                }
+                continue;
+            }
+
+            let circuit = circuit
+                .as_mut()
+                .ok_or_else(|| anyhow!("Gate '{}' appears before qreg", statement))?;
+
+            if let Some(rest) = statement.strip_prefix("measure") {
+                let (source, target) = rest.split_once("->").ok_or_else(|| {
+                    anyhow!("Expected 'measure q[i] -> c[j]', got '{}'", statement)
+                })?;
+                let qubit = parse_register_arg(source.trim(), &qreg)?;
+                let creg = creg
+                    .as_deref()
+                    .ok_or_else(|| anyhow!("Measurement '{}' appears before creg", statement))?;
+                let clbit = parse_register_arg(target.trim(), creg)?;
+                if clbit != qubit {
+                    bail!(
+                        "Measurement of {}[{}] into {}[{}] is not supported; each qubit is read into the classical bit of the same index",
+                        qreg,
+                        qubit,
+                        creg,
+                        clbit
+                    );
+                }
+                push_checked(circuit, GateType::Measure, vec![qubit])?;
+                continue;
+            }
+
+            // name[(params)] args
+            let split = statement
+                .find(|c: char| c.is_whitespace() || c == '(')
+                .ok_or_else(|| anyhow!("Malformed statement '{}'", statement))?;
+            let name = &statement[..split];
+            let mut rest = statement[split..].trim_start();
+            let mut params = Vec::new();
+            if rest.starts_with('(') {
+                // Match the outermost pair so nested parentheses survive
+                let mut depth = 0usize;
+                let mut close = None;
+                for (i, c) in rest.char_indices() {
+                    match c {
+                        '(' => depth += 1,
+                        ')' => {
+                            depth -= 1;
+                            if depth == 0 {
+                                close = Some(i);
+                                break;
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                let end =
+                    close.ok_or_else(|| anyhow!("Unclosed parameter list in '{}'", statement))?;
+                for expr in split_top_level(&rest[1..end]) {
+                    params.push(eval_expression(expr)?);
+                }
+                rest = rest[end + 1..].trim_start();
+            }
+            let qubits = rest
+                .split(',')
+                .map(|arg| parse_register_arg(arg.trim(), &qreg))
+                .collect::<Result<Vec<_>>>()?;
+
+            match gate_from_qasm(name, &params) {
+                Some(gate) => {
+                    let (gate_type, arity) = gate?;
+                    if qubits.len() != arity {
+                        bail!(
+                            "Gate '{}' expects {} qubit(s), got {}",
+                            name,
+                            arity,
+                            qubits.len()
+                        );
+                    }
+                    push_checked(circuit, gate_type, qubits)?;
+                }
+                None => {
+                    unsupported.insert(name.to_string());
+                }
+            }
+        }
+
+        if !unsupported.is_empty() {
+            let names: Vec<String> = unsupported.into_iter().collect();
+            bail!(
+                "Unsupported QASM gate(s): {}; supported gates are {}",
+                names.join(", "),
+                SUPPORTED_QASM_GATES.join(", ")
+            );
+        }
+        circuit.ok_or_else(|| anyhow!("QASM program declares no qreg"))
+    }
+}
+
+/// Gate names accepted by [`MetatronCircuit::from_qasm`].
+const SUPPORTED_QASM_GATES: &[&str] = &[
+    "h", "x", "y", "z", "s", "sdg", "t", "tdg", "rx", "ry", "rz", "u3", "u", "cx", "cz", "swap",
+    "cu1", "cp", "ccx", "measure", "barrier",
+];
+
+/// QASM name and parameters of a (non-measurement) gate.
+fn qasm_name(gate: &GateType) -> (&'static str, Vec<f64>) {
+    match *gate {
+        GateType::H => ("h", vec![]),
+        GateType::X => ("x", vec![]),
+        GateType::Y => ("y", vec![]),
+        GateType::Z => ("z", vec![]),
+        GateType::S => ("s", vec![]),
+        GateType::Sdg => ("sdg", vec![]),
+        GateType::T => ("t", vec![]),
+        GateType::Tdg => ("tdg", vec![]),
+        GateType::RX(theta) => ("rx", vec![theta]),
+        GateType::RY(theta) => ("ry", vec![theta]),
+        GateType::RZ(theta) => ("rz", vec![theta]),
+        GateType::U(theta, phi, lambda) => ("u3", vec![theta, phi, lambda]),
+        GateType::CNOT => ("cx", vec![]),
+        GateType::CZ => ("cz", vec![]),
+        GateType::SWAP => ("swap", vec![]),
+        GateType::CPhase(phi) => ("cu1", vec![phi]),
+        GateType::Toffoli => ("ccx", vec![]),
+        GateType::Measure => ("measure", vec![]),
+    }
+}
+
+/// Gate type and qubit arity for a QASM gate name, `None` if unsupported.
+///
+/// Fails if the number of parameters does not match the gate.
+fn gate_from_qasm(name: &str, params: &[f64]) -> Option<Result<(GateType, usize)>> {
+    let expect = |count: usize| -> Result<&[f64]> {
+        if params.len() != count {
+            bail!(
+                "Gate '{}' expects {} parameter(s), got {}",
+                name,
+                count,
+                params.len()
+            );
+        }
+        Ok(params)
+    };
+    let (gate, arity) = match name {
+        "h" => (expect(0).map(|_| GateType::H), 1),
+        "x" => (expect(0).map(|_| GateType::X), 1),
+        "y" => (expect(0).map(|_| GateType::Y), 1),
+        "z" => (expect(0).map(|_| GateType::Z), 1),
+        "s" => (expect(0).map(|_| GateType::S), 1),
+        "sdg" => (expect(0).map(|_| GateType::Sdg), 1),
+        "t" => (expect(0).map(|_| GateType::T), 1),
+        "tdg" => (expect(0).map(|_| GateType::Tdg), 1),
+        "rx" => (expect(1).map(|p| GateType::RX(p[0])), 1),
+        "ry" => (expect(1).map(|p| GateType::RY(p[0])), 1),
+        "rz" => (expect(1).map(|p| GateType::RZ(p[0])), 1),
+        "u3" | "u" => (expect(3).map(|p| GateType::U(p[0], p[1], p[2])), 1),
+        "cx" => (expect(0).map(|_| GateType::CNOT), 2),
+        "cz" => (expect(0).map(|_| GateType::CZ), 2),
+        "swap" => (expect(0).map(|_| GateType::SWAP), 2),
+        "cu1" | "cp" => (expect(1).map(|p| GateType::CPhase(p[0])), 2),
+        "ccx" => (expect(0).map(|_| GateType::Toffoli), 3),
+        _ => return None,
+    };
+    Some(gate.map(|gate| (gate, arity)))
+}
+
+fn push_checked(
+    circuit: &mut MetatronCircuit,
+    gate_type: GateType,
+    qubits: Vec<usize>,
+) -> Result<()> {
+    if let Some(&q) = qubits.iter().find(|&&q| q >= circuit.num_qubits) {
+        bail!(
+            "Qubit index {} out of bounds for {} qubits",
+            q,
+            circuit.num_qubits
+        );
+    }
+    circuit.add_gate(gate_type, qubits);
+    Ok(())
+}
+
+/// Parse `name[index]`.
+fn parse_indexed(text: &str) -> Result<(&str, usize)> {
+    let open = text
+        .find('[')
+        .ok_or_else(|| anyhow!("Expected 'name[index]', got '{}'", text))?;
+    let close = text
+        .find(']')
+        .ok_or_else(|| anyhow!("Expected 'name[index]', got '{}'", text))?;
+    let index = text[open + 1..close]
+        .trim()
+        .parse()
+        .map_err(|_| anyhow!("Invalid index in '{}'", text))?;
+    Ok((text[..open].trim(), index))
+}
+
+/// Parse `register[index]`, requiring the declared register name.
+fn parse_register_arg(text: &str, register: &str) -> Result<usize> {
+    let (name, index) = parse_indexed(text)?;
+    if name != register {
+        bail!("Unknown register '{}', expected '{}'", name, register);
+    }
+    Ok(index)
+}
+
+/// Split a parameter list on commas outside parentheses.
+fn split_top_level(list: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, c) in list.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                parts.push(list[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(list[start..].trim());
+    parts
+}
+
+/// Evaluate a QASM parameter expression over numbers, `pi`, `+ - * /`
+/// and parentheses.
+fn eval_expression(expr: &str) -> Result<f64> {
+    let tokens: Vec<char> = expr.chars().filter(|c| !c.is_whitespace()).collect();
+    let mut pos = 0;
+    let value = parse_sum(&tokens, &mut pos)?;
+    if pos != tokens.len() {
+        bail!("Unexpected trailing input in parameter '{}'", expr);
+    }
+    Ok(value)
+}
+
+fn parse_sum(tokens: &[char], pos: &mut usize) -> Result<f64> {
+    let mut value = parse_product(tokens, pos)?;
+    while let Some(&op) = tokens.get(*pos) {
+        if op != '+' && op != '-' {
+            break;
+        }
+        *pos += 1;
+        let rhs = parse_product(tokens, pos)?;
+        value = if op == '+' { value + rhs } else { value - rhs };
+    }
+    Ok(value)
+}
+
+fn parse_product(tokens: &[char], pos: &mut usize) -> Result<f64> {
+    let mut value = parse_atom(tokens, pos)?;
+    while let Some(&op) = tokens.get(*pos) {
+        if op != '*' && op != '/' {
+            break;
+        }
+        *pos += 1;
+        let rhs = parse_atom(tokens, pos)?;
+        value = if op == '*' { value * rhs } else { value / rhs };
+    }
+    Ok(value)
+}
+
+fn parse_atom(tokens: &[char], pos: &mut usize) -> Result<f64> {
+    match tokens.get(*pos) {
+        Some('-') => {
+            *pos += 1;
+            Ok(-parse_atom(tokens, pos)?)
+        }
+        Some('(') => {
+            *pos += 1;
+            let value = parse_sum(tokens, pos)?;
+            if tokens.get(*pos) != Some(&')') {
+                bail!("Missing ')' in parameter expression");
+            }
+            *pos += 1;
+            Ok(value)
+        }
+        Some('p') if tokens.get(*pos + 1) == Some(&'i') => {
+            *pos += 2;
+            Ok(PI)
+        }
+        Some(_) => {
+            let start = *pos;
+            while let Some(&c) = tokens.get(*pos) {
+                let exponent_sign =
+                    (c == '-' || c == '+') && matches!(tokens.get(*pos - 1), Some('e' | 'E'));
+                if c.is_ascii_digit() || c == '.' || c == 'e' || c == 'E' || exponent_sign {
+                    *pos += 1;
+                } else {
+                    break;
+                }
+            }
+            let literal: String = tokens[start..*pos].iter().collect();
+            literal
+                .parse()
+                .map_err(|_| anyhow!("Invalid number '{}' in parameter expression", literal))
+        }
+        None => bail!("Empty parameter expression"),
+    }
+}
+
 /// Result of measuring a quantum circuit
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MeasurementResult {
@@ -254,6 +739,98 @@ mod tests {
         assert_eq!(circuit.gates.len(), 4); // H + CNOT + 2 measurements
     }
 
+    #[test]
+    fn test_qasm_round_trip() {
+        let circuit = MetatronCircuit::new(3)
+            .h(0)
+            .t(1)
+            .sdg(2)
+            .rx(0, 0.25)
+            .rz(1, -1.5e-3)
+            .u(2, 0.1, 0.2, 0.3)
+            .cnot(0, 1)
+            .cphase(1, 2, PI / 3.0)
+            .swap(0, 2)
+            .ccx(0, 1, 2)
+            .measure_all();
+
+        let qasm = circuit.to_qasm();
+        assert!(qasm.contains("ccx q[0],q[1],q[2];"));
+        let parsed = MetatronCircuit::from_qasm(&qasm).unwrap();
+        assert_eq!(parsed.num_qubits, 3);
+        let gates = |c: &MetatronCircuit| -> Vec<(GateType, Vec<usize>)> {
+            c.gates
+                .iter()
+                .map(|g| (g.gate_type.clone(), g.qubits.clone()))
+                .collect()
+        };
+        assert_eq!(gates(&parsed), gates(&circuit));
+
+        let external =
+            "OPENQASM 2.0;\nqreg q[2];\nrx(-pi/2) q[0]; // comment\ncp(2*(pi-1)) q[0],q[1];";
+        let parsed = MetatronCircuit::from_qasm(external).unwrap();
+        assert_eq!(parsed.gates[0].gate_type, GateType::RX(-PI / 2.0));
+        assert_eq!(
+            parsed.gates[1].gate_type,
+            GateType::CPhase(2.0 * (PI - 1.0))
+        );
+
+        let err = MetatronCircuit::from_qasm("qreg q[2];\nrxx(0.1) q[0],q[1];\nsx q[0];")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("rxx, sx"), "{}", err);
+    }
+
+    #[test]
+    fn test_qasm_rejects_malformed_operands() {
+        let header = "OPENQASM 2.0;\nqreg q[2];\ncreg c[2];\n";
+        let err = |body: &str| {
+            MetatronCircuit::from_qasm(&format!("{}{}", header, body))
+                .unwrap_err()
+                .to_string()
+        };
+
+        assert!(err("rx q[0];").contains("expects 1 parameter(s), got 0"));
+        assert!(err("h(0.5) q[0];").contains("expects 0 parameter(s), got 1"));
+        assert!(err("u3(0.1,0.2) q[0];").contains("expects 3 parameter(s), got 2"));
+        assert!(err("measure q[0] -> c[1];").contains("not supported"));
+        assert!(err("measure q[0] -> m[0];").contains("Unknown register 'm'"));
+        assert!(err("h r[0];").contains("Unknown register 'r'"));
+        assert!(err("measure q[0];").contains("Expected 'measure"));
+
+        let parsed = MetatronCircuit::from_qasm(&format!("{}measure q[1] -> c[1];", header));
+        assert_eq!(parsed.unwrap().gates[0].qubits, vec![1]);
+    }
+
+    #[test]
+    fn test_hamiltonian_evolution_matches_exact_walk() {
+        use crate::backends::local::LocalSimulatorBackend;
+        use metatron_qso::graph::metatron::MetatronGraph;
+        use metatron_qso::params::QSOParameters;
+        use metatron_qso::quantum::state::QuantumState;
+
+        let graph = MetatronGraph::new();
+        let mut params = QSOParameters::default();
+        params.epsilon[3] = 0.7; // break the degeneracy a little
+        let hamiltonian = MetatronHamiltonian::new(&graph, &params);
+        let t = 0.3;
+
+        let exact = hamiltonian.evolve_state(&QuantumState::basis_state(0).unwrap(), t);
+        let circuit = MetatronCircuit::from_hamiltonian_evolution(&hamiltonian, t, 60);
+        assert_eq!(circuit.num_qubits, 4);
+        let simulated = LocalSimulatorBackend::new()
+            .run_statevector(&circuit)
+            .unwrap();
+
+        let overlap: num_complex::Complex64 = exact
+            .amplitudes()
+            .iter()
+            .zip(&simulated)
+            .map(|(a, b)| a.conj() * b)
+            .sum();
+        assert!(overlap.norm_sqr() > 0.99, "fidelity {}", overlap.norm_sqr());
+    }
+
     #[test]
     fn test_measurement_result() {
         let mut counts = HashMap::new();
@@ -32,21 +32,30 @@ pub mod backends;
 pub mod circuit;
 pub mod registry;
 
-pub use backends::{local::LocalSimulatorBackend, BackendCapabilities, QuantumBackend};
+pub use backends::{
+    local::{LocalSimulatorBackend, NoiseModel},
+    BackendCapabilities, QuantumBackend,
+};
 pub use circuit::{Gate, GateType, MeasurementResult, MetatronCircuit};
 
 #[cfg(feature = "ibm")]
 pub use backends::ibm::{IbmConfig, IbmMode, IbmQuantumBackend};
 
+#[cfg(feature = "ionq")]
+pub use backends::ionq::{IonqConfig, IonqJobStatus, IonqMode, IonqQuantumBackend};
+
 pub use registry::{BackendMode, BackendRegistry};
 
 /// Re-export commonly used types
 pub mod prelude {
     pub use crate::{
         BackendCapabilities, BackendMode, BackendRegistry, LocalSimulatorBackend,
-        MeasurementResult, MetatronCircuit, QuantumBackend,
+        MeasurementResult, MetatronCircuit, NoiseModel, QuantumBackend,
     };
 
     #[cfg(feature = "ibm")]
     pub use crate::{IbmConfig, IbmMode, IbmQuantumBackend};
+
+    #[cfg(feature = "ionq")]
+    pub use crate::{IonqConfig, IonqMode, IonqQuantumBackend};
 }
@@ -3,7 +3,7 @@
 //! Manages available quantum backends and selects appropriate backends
 //! based on execution mode and circuit requirements.
 
-use crate::backends::{BackendCapabilities, BoxedBackend};
+use crate::backends::{BackendCapabilities, BoxedBackend, QuantumBackend};
 use crate::circuit::MetatronCircuit;
 use anyhow::{anyhow, bail, Result};
 use std::collections::HashMap;
@@ -105,6 +105,35 @@ impl BackendRegistry {
         self.backends.values().map(|b| b.info()).collect()
     }
 
+    /// Select a backend by capability requirements
+    ///
+    /// Considers available backends with at least `min_qubits` qubits,
+    /// restricted to simulators when `require_simulator` is set. Simulators
+    /// are preferred over QPUs, then the smallest backend that fits; ties
+    /// are broken by registered name so the choice is deterministic.
+    /// Unlike [`select_backend_for`](Self::select_backend_for) this ignores
+    /// the registry mode.
+    pub fn select(
+        &self,
+        min_qubits: usize,
+        require_simulator: bool,
+    ) -> Option<&dyn QuantumBackend> {
+        self.backends
+            .iter()
+            .map(|(name, backend)| (name, backend, backend.info()))
+            .filter(|(_, backend, caps)| {
+                (!require_simulator || caps.is_simulator) && backend.can_run(min_qubits)
+            })
+            .min_by(|(a_name, _, a), (b_name, _, b)| {
+                (!a.is_simulator, a.num_qubits, a_name).cmp(&(
+                    !b.is_simulator,
+                    b.num_qubits,
+                    b_name,
+                ))
+            })
+            .map(|(_, backend, _)| backend.as_ref())
+    }
+
     /// Select the best backend for a given circuit
     ///
     /// Selection logic:
@@ -245,4 +274,73 @@ mod tests {
         assert!(selected.info().is_simulator);
         assert_eq!(selected.info().provider, "local");
     }
+
+    #[cfg(feature = "ionq")]
+    #[test]
+    fn test_force_provider_ionq() {
+        use crate::backends::ionq::{IonqConfig, IonqMode, IonqQuantumBackend};
+
+        let mut registry = BackendRegistry::new();
+        registry
+            .register(
+                "local_sim".to_string(),
+                Box::new(LocalSimulatorBackend::new()),
+            )
+            .unwrap();
+        let ionq = IonqQuantumBackend::new(IonqConfig {
+            mode: IonqMode::DryRun,
+            ..Default::default()
+        })
+        .unwrap();
+        registry
+            .register("ionq".to_string(), Box::new(ionq))
+            .unwrap();
+
+        registry.set_mode(BackendMode::ForceProvider("ionq".to_string()));
+        let circuit = MetatronCircuit::new(3);
+        let selected = registry.select_backend_for(&circuit).unwrap();
+        assert_eq!(selected.info().provider, "ionq");
+    }
+
+    struct QpuStub;
+
+    impl QuantumBackend for QpuStub {
+        fn info(&self) -> BackendCapabilities {
+            BackendCapabilities {
+                available: true,
+                ..BackendCapabilities::qpu("stub", "stub_qpu", 127)
+            }
+        }
+
+        fn run_circuit(
+            &self,
+            _circuit: &MetatronCircuit,
+            _shots: u32,
+        ) -> Result<crate::circuit::MeasurementResult> {
+            bail!("stub QPU cannot execute circuits")
+        }
+    }
+
+    #[test]
+    fn test_select_by_capabilities() {
+        let mut registry = BackendRegistry::new();
+        registry
+            .register(
+                "local_sim".to_string(),
+                Box::new(LocalSimulatorBackend::with_qubits(8)),
+            )
+            .unwrap();
+        registry
+            .register("stub_qpu".to_string(), Box::new(QpuStub))
+            .unwrap();
+
+        let small = registry.select(4, false).unwrap();
+        assert!(small.info().is_simulator);
+
+        let large = registry.select(20, false).unwrap();
+        assert_eq!(large.info().name, "stub_qpu");
+
+        assert!(registry.select(20, true).is_none());
+        assert!(registry.select(200, false).is_none());
+    }
 }
@@ -44,11 +44,96 @@ pub mod python;
 
 use anyhow::{Context, Result};
 use apollyon_mef_bridge::trichter::{
-    coupling_tick, FunnelGraph, HDAGField, Hyperbion, Policy, State4D, TickResult,
+    coupling_tick, lift, FunnelGraph, HDAGField, Hyperbion, Policy, State4D, State5D, TickResult,
 };
 use serde::{Deserialize, Serialize};
 use serde_json::json;
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
+
+/// Number of recent states fed into each coupling tick by default
+pub const DEFAULT_HISTORY_WINDOW: usize = 8;
+
+/// y-coordinate reserved for algorithm families missing from the registry
+pub const UNKNOWN_ALGORITHM_CODE: f64 = 0.0;
+
+/// Name decoded from [`UNKNOWN_ALGORITHM_CODE`]
+pub const UNKNOWN_ALGORITHM: &str = "UNKNOWN";
+
+/// Mapping between algorithm families and their y-coordinate in 4D space
+///
+/// Names are case-insensitive and stored upper-case. Codes must be finite,
+/// unique and different from [`UNKNOWN_ALGORITHM_CODE`], so every registered
+/// family round-trips through `encode`/`decode`. Decoding picks the nearest
+/// code, which lets advected (non-integer) coordinates snap back to a family.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlgorithmRegistry {
+    codes: HashMap<String, f64>,
+}
+
+impl AlgorithmRegistry {
+    /// Build a registry from explicit name → code pairs
+    pub fn new(codes: HashMap<String, f64>) -> Result<Self> {
+        let mut registry = Self {
+            codes: HashMap::new(),
+        };
+        for (name, code) in codes {
+            registry.register(&name, code)?;
+        }
+        Ok(registry)
+    }
+
+    /// Add or re-code an algorithm family
+    pub fn register(&mut self, name: &str, code: f64) -> Result<()> {
+        let name = name.to_uppercase();
+        if !code.is_finite() || code == UNKNOWN_ALGORITHM_CODE {
+            anyhow::bail!("Invalid code {} for algorithm {}", code, name);
+        }
+        if let Some((other, _)) = self
+            .codes
+            .iter()
+            .find(|(other, &existing)| existing == code && **other != name)
+        {
+            anyhow::bail!("Code {} already assigned to algorithm {}", code, other);
+        }
+        self.codes.insert(name, code);
+        Ok(())
+    }
+
+    /// Numeric coordinate of an algorithm family
+    pub fn encode(&self, algorithm: &str) -> f64 {
+        self.codes
+            .get(&algorithm.to_uppercase())
+            .copied()
+            .unwrap_or(UNKNOWN_ALGORITHM_CODE)
+    }
+
+    /// Algorithm family whose code is nearest to `y`
+    pub fn decode(&self, y: f64) -> String {
+        self.codes
+            .iter()
+            .map(|(name, &code)| (name.as_str(), code))
+            .chain(std::iter::once((UNKNOWN_ALGORITHM, UNKNOWN_ALGORITHM_CODE)))
+            .min_by(|(a_name, a), (b_name, b)| {
+                (a - y)
+                    .abs()
+                    .total_cmp(&(b - y).abs())
+                    .then_with(|| a_name.cmp(b_name))
+            })
+            .map(|(name, _)| name.to_string())
+            .unwrap_or_else(|| UNKNOWN_ALGORITHM.to_string())
+    }
+}
+
+impl Default for AlgorithmRegistry {
+    /// VQE = 1, QAOA = 2, VQC = 3
+    fn default() -> Self {
+        let codes = [("VQE", 1.0), ("QAOA", 2.0), ("VQC", 3.0)]
+            .into_iter()
+            .map(|(name, code)| (name.to_string(), code))
+            .collect();
+        Self { codes }
+    }
+}
 
 /// Q⊗DASH calibration state mapped to dioniceOS coordinate space
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -93,6 +178,13 @@ pub struct DioniceKernel {
     time_step: f64,
     /// History of states for analysis
     state_history: Vec<State4D>,
+    /// Number of most recent states passed to each coupling tick
+    history_window: usize,
+    /// Algorithm family encoding for the y-coordinate
+    algorithms: AlgorithmRegistry,
+    /// HDAG tensors of the most recent advected states, oldest first; at
+    /// most `history_window` of them are kept
+    trajectory_tensors: VecDeque<usize>,
 }
 
 impl DioniceKernel {
@@ -110,9 +202,33 @@ impl DioniceKernel {
             policy,
             time_step: 0.0,
             state_history: Vec::new(),
+            history_window: DEFAULT_HISTORY_WINDOW,
+            algorithms: AlgorithmRegistry::default(),
+            trajectory_tensors: VecDeque::new(),
         }
     }
 
+    /// Use a custom algorithm family registry
+    pub fn with_algorithms(mut self, algorithms: AlgorithmRegistry) -> Self {
+        self.algorithms = algorithms;
+        self
+    }
+
+    /// Algorithm family registry used for encoding and decoding
+    pub fn algorithms(&self) -> &AlgorithmRegistry {
+        &self.algorithms
+    }
+
+    /// Set how many recent states each coupling tick sees (at least 1)
+    ///
+    /// A window of 1 reproduces single-state ticks without trajectory context.
+    /// The HDAG likewise keeps only the tensors of the last `window` advected
+    /// states, so memory and the resonance score stay bounded per step.
+    pub fn with_history_window(mut self, window: usize) -> Self {
+        self.history_window = window.max(1);
+        self
+    }
+
     /// Ingest Q⊗DASH calibration state into the dioniceOS backend
     pub fn ingest_state(&mut self, state: QDashCalibrationState) -> Result<()> {
         // Map Q⊗DASH state to 4D state space
@@ -120,7 +236,7 @@ impl DioniceKernel {
         // y: derived from algorithm family
         // z: combined metric
         // ψ: quality metric (psi)
-        let state_4d = Self::map_to_4d(&state);
+        let state_4d = self.map_to_4d(&state);
 
         self.state_history.push(state_4d);
 
@@ -133,8 +249,9 @@ impl DioniceKernel {
             anyhow::bail!("No state ingested. Call ingest_state() first.");
         }
 
-        // Get current states (use last N states or just last one)
-        let current_states = vec![*self.state_history.last().unwrap()];
+        // Feed the most recent trajectory window, oldest first
+        let start = self.state_history.len().saturating_sub(self.history_window);
+        let current_states = self.state_history[start..].to_vec();
 
         // Execute coupling tick
         let policy_params = self.policy.params();
@@ -157,52 +274,72 @@ impl DioniceKernel {
         // Extract next states after analysis
         let next_states = tick_result.states_4d_next;
 
-        // Update history
-        if let Some(next_state) = next_states.first() {
+        // Update history and record the advected state as a resonance tensor,
+        // chained to the previous one so the HDAG tracks the trajectory
+        if let Some(next_state) = Self::advected_latest(&current_states, &next_states) {
             self.state_history.push(*next_state);
+            let tensor = self.hdag.add_tensor(lift(*next_state, self.time_step));
+            if let Some(&previous) = self.trajectory_tensors.back() {
+                self.hdag.add_transition(previous, tensor);
+            }
+            self.trajectory_tensors.push_back(tensor);
+            self.prune_hdag();
         }
 
         Ok(suggestion)
     }
 
+    /// Drop the oldest trajectory tensors beyond the history window, together
+    /// with their transitions
+    fn prune_hdag(&mut self) {
+        while self.trajectory_tensors.len() > self.history_window {
+            if let Some(oldest) = self.trajectory_tensors.pop_front() {
+                self.hdag.tensors.remove(&oldest);
+                self.hdag
+                    .transitions
+                    .retain(|t| t.from != oldest && t.to != oldest);
+            }
+        }
+    }
+
+    /// The next state that continues the most recent input state
+    ///
+    /// The funnel returns its nodes in no particular order and advects all of
+    /// them by the same guidance vector, so the nearest one to the latest
+    /// input is taken as its successor.
+    fn advected_latest<'a>(current: &[State4D], next: &'a [State4D]) -> Option<&'a State4D> {
+        let latest = current.last()?;
+        let distance = |s: &State4D| {
+            (s.x - latest.x).powi(2)
+                + (s.y - latest.y).powi(2)
+                + (s.z - latest.z).powi(2)
+                + (s.psi - latest.psi).powi(2)
+        };
+        next.iter()
+            .min_by(|a, b| distance(a).total_cmp(&distance(b)))
+    }
+
     /// Map Q⊗DASH calibration state to 4D coordinate space
-    fn map_to_4d(state: &QDashCalibrationState) -> State4D {
+    fn map_to_4d(&self, state: &QDashCalibrationState) -> State4D {
         // Coordinate mapping strategy:
         // x ← rho (stability) - centered around 0
-        // y ← algorithm family encoding (e.g., VQE=1.0, QAOA=2.0, VQC=3.0)
+        // y ← algorithm family code from the registry (default VQE=1, QAOA=2, VQC=3)
         // z ← omega (efficiency)
         // ψ ← psi (quality)
 
         let x = (state.rho - 0.5) * 2.0; // Map [0,1] to [-1,1]
-        let y = Self::encode_algorithm(&state.algorithm);
+        let y = self.algorithms.encode(&state.algorithm);
         let z = (state.omega - 0.5) * 2.0;
         let psi = state.psi;
 
         State4D::new(x, y, z, psi)
     }
 
-    /// Encode algorithm family as numeric coordinate
-    fn encode_algorithm(algo: &str) -> f64 {
-        match algo.to_uppercase().as_str() {
-            "VQE" => 1.0,
-            "QAOA" => 2.0,
-            "VQC" => 3.0,
-            _ => 0.0,
-        }
-    }
-
-    /// Decode algorithm family from numeric coordinate
-    fn decode_algorithm(y: f64) -> String {
-        let rounded = y.round();
-        match rounded as i32 {
-            1 => "VQE".to_string(),
-            2 => "QAOA".to_string(),
-            3 => "VQC".to_string(),
-            _ => "VQE".to_string(), // Default fallback
-        }
-    }
-
     /// Analyze state evolution and generate calibration suggestion
+    ///
+    /// Deltas run from the oldest state of the tick window to the advected
+    /// latest state, so with a history window they describe the trajectory
+    /// over the last N steps rather than a single tick.
     fn analyze_evolution(
         &self,
         current: &[State4D],
@@ -210,7 +347,7 @@ impl DioniceKernel {
         tick_result: &TickResult,
     ) -> Result<QDashCalibrationSuggestion> {
         let curr = current.first().context("No current state")?;
-        let nxt = next.first().context("No next state")?;
+        let nxt = Self::advected_latest(current, next).context("No next state")?;
 
         // Compute deltas
         let delta_x = nxt.x - curr.x;
@@ -266,7 +403,7 @@ impl DioniceKernel {
 
         // Algorithm family (y-coordinate)
         if regime_change {
-            let new_algo = Self::decode_algorithm(nxt.y);
+            let new_algo = self.algorithms.decode(nxt.y);
             notes.push(format!(
                 "Regime change suggested: Consider switching to {}",
                 new_algo
@@ -289,7 +426,7 @@ impl DioniceKernel {
         }
 
         // Compute resonance score from HDAG field
-        let resonance_score = self.compute_resonance_score(nxt);
+        let resonance_score = self.compute_resonance_score(lift(*nxt, self.time_step));
 
         let suggestion = QDashCalibrationSuggestion {
             new_config: serde_json::Value::Object(config_updates),
@@ -302,9 +439,50 @@ impl DioniceKernel {
     }
 
     /// Compute resonance quality score from HDAG field
-    fn compute_resonance_score(&self, state: &State4D) -> f64 {
-        // Use ψ (quality) as primary component, bounded to [0, 1]
-        state.psi.clamp(0.0, 1.0)
+    ///
+    /// The score is the normalized resonance of the HDAG tensors Tᵢ, weighted
+    /// by their proximity to the lifted state s:
+    ///
+    /// ```text
+    /// score = Σᵢ wᵢ·(1 + rᵢ/r_max)/2 / Σᵢ wᵢ,   wᵢ = e^(−‖Tᵢ − s‖²)
+    /// ```
+    ///
+    /// where rᵢ is the resonance each tensor accumulated while the Hyperbion
+    /// fields relaxed the HDAG and r_max = maxⱼ |rⱼ|. Normalizing by r_max
+    /// keeps the score independent of the field's absolute scale, which grows
+    /// with the temporal phase. Each term lies in [0, 1], so the score does
+    /// too: 1 where nearby tensors are maximally in phase, 0 where they are
+    /// maximally out of phase or the field carries no resonance yet.
+    fn compute_resonance_score(&self, state: State5D) -> f64 {
+        let scale = self
+            .hdag
+            .tensors
+            .values()
+            .map(|tensor| tensor.resonance.abs())
+            .fold(0.0, f64::max);
+        if scale == 0.0 {
+            return 0.0;
+        }
+        let (weighted, total) =
+            self.hdag
+                .tensors
+                .values()
+                .fold((0.0, 0.0), |(weighted, total), tensor| {
+                    let t = tensor.tensor;
+                    let distance_sq = (t.x - state.x).powi(2)
+                        + (t.y - state.y).powi(2)
+                        + (t.z - state.z).powi(2)
+                        + (t.psi - state.psi).powi(2)
+                        + (t.omega - state.omega).powi(2);
+                    let weight = (-distance_sq).exp();
+                    let alignment = 0.5 * (1.0 + tensor.resonance / scale);
+                    (weighted + weight * alignment, total + weight)
+                });
+        if total > 0.0 {
+            (weighted / total).clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
     }
 
     /// Get current funnel density
@@ -396,13 +574,128 @@ mod tests {
 
     #[test]
     fn test_algorithm_encoding_decoding() {
-        assert_eq!(DioniceKernel::encode_algorithm("VQE"), 1.0);
-        assert_eq!(DioniceKernel::encode_algorithm("QAOA"), 2.0);
-        assert_eq!(DioniceKernel::encode_algorithm("VQC"), 3.0);
+        let registry = AlgorithmRegistry::default();
+        assert_eq!(registry.encode("VQE"), 1.0);
+        assert_eq!(registry.encode("qaoa"), 2.0);
+        assert_eq!(registry.encode("VQC"), 3.0);
+
+        assert_eq!(registry.decode(1.0), "VQE");
+        assert_eq!(registry.decode(2.0), "QAOA");
+        assert_eq!(registry.decode(3.0), "VQC");
+
+        // Unknown families no longer collide with VQE.
+        assert_eq!(registry.encode("VQD"), UNKNOWN_ALGORITHM_CODE);
+        assert_eq!(registry.decode(UNKNOWN_ALGORITHM_CODE), UNKNOWN_ALGORITHM);
+    }
+
+    #[test]
+    fn test_custom_algorithm_round_trip() {
+        let mut registry = AlgorithmRegistry::default();
+        registry.register("VQD", 4.0).unwrap();
+        assert!(registry.register("Grover", 2.0).is_err());
+        assert!(registry.register("Grover", UNKNOWN_ALGORITHM_CODE).is_err());
+
+        let kernel = DioniceKernel::new().with_algorithms(registry);
+        for name in ["VQE", "QAOA", "VQC", "VQD"] {
+            let code = kernel.algorithms().encode(name);
+            assert_eq!(kernel.algorithms().decode(code), name);
+        }
+        assert_eq!(kernel.algorithms().decode(3.8), "VQD");
+    }
 
-        assert_eq!(DioniceKernel::decode_algorithm(1.0), "VQE");
-        assert_eq!(DioniceKernel::decode_algorithm(2.0), "QAOA");
-        assert_eq!(DioniceKernel::decode_algorithm(3.0), "VQC");
+    #[test]
+    fn test_history_window_adds_trajectory_context() {
+        let trajectory: Vec<_> = (0..DEFAULT_HISTORY_WINDOW)
+            .map(|i| QDashCalibrationState {
+                psi: 0.5 + 0.05 * i as f64,
+                rho: 0.90,
+                omega: 0.75,
+                algorithm: "VQE".to_string(),
+                extra_params: HashMap::new(),
+            })
+            .collect();
+        let run = |window: usize| {
+            let mut kernel = DioniceKernel::new().with_history_window(window);
+            for state in &trajectory {
+                kernel.ingest_state(state.clone()).unwrap();
+            }
+            kernel.step().unwrap()
+        };
+
+        // A single state carries no trend; the window sees ψ rise by 0.35.
+        let single = run(1);
+        let windowed = run(DEFAULT_HISTORY_WINDOW);
+        assert!(single.new_config.get("quality_direction").is_none());
+        assert_eq!(windowed.new_config["quality_direction"], "improving");
+        assert!(windowed.notes.contains("Quality improvement detected"));
+    }
+
+    #[test]
+    fn test_resonance_score_follows_hdag_field() {
+        let mut kernel = DioniceKernel::new();
+        let near = State5D::new(0.0, 1.0, 0.0, 0.5, 0.0);
+        let far = State5D::new(0.0, 3.0, 0.0, 0.5, 0.0);
+        assert_eq!(kernel.compute_resonance_score(near), 0.0);
+
+        let resonant = kernel.hdag.add_tensor(near);
+        let dissonant = kernel.hdag.add_tensor(far);
+        kernel.hdag.tensors.get_mut(&resonant).unwrap().resonance = 2.0;
+        kernel.hdag.tensors.get_mut(&dissonant).unwrap().resonance = -1.0;
+
+        let in_phase = kernel.compute_resonance_score(near);
+        let out_of_phase = kernel.compute_resonance_score(far);
+        assert!(in_phase > 0.9 && in_phase <= 1.0);
+        assert!((0.0..0.3).contains(&out_of_phase));
+
+        // Advected states feed the field; identical trajectories at very
+        // different ψ resonate alike, so the score is no ψ passthrough.
+        let scores: Vec<f64> = [0.2, 0.9]
+            .into_iter()
+            .map(|psi| {
+                let mut kernel = DioniceKernel::new();
+                let mut score = 0.0;
+                for _ in 0..3 {
+                    kernel
+                        .ingest_state(QDashCalibrationState {
+                            psi,
+                            rho: 0.90,
+                            omega: 0.75,
+                            algorithm: "VQE".to_string(),
+                            extra_params: HashMap::new(),
+                        })
+                        .unwrap();
+                    score = kernel.step().unwrap().resonance_score;
+                }
+                assert_eq!(kernel.hdag.tensor_count(), 3);
+                score
+            })
+            .collect();
+        assert!(scores[0] > 0.0);
+        assert!((scores[0] - scores[1]).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_hdag_is_capped_by_history_window() {
+        let window = 3;
+        let mut kernel = DioniceKernel::new().with_history_window(window);
+        for i in 0..20 {
+            kernel
+                .ingest_state(QDashCalibrationState {
+                    psi: 0.5 + 0.01 * i as f64,
+                    rho: 0.90,
+                    omega: 0.75,
+                    algorithm: "VQE".to_string(),
+                    extra_params: HashMap::new(),
+                })
+                .unwrap();
+            kernel.step().unwrap();
+            assert!(kernel.hdag.tensor_count() <= window);
+        }
+        assert_eq!(kernel.hdag.tensor_count(), window);
+        assert!(kernel.hdag.transition_count() < window);
+        assert!(kernel.hdag.transitions.iter().all(|t| {
+            kernel.hdag.tensors.contains_key(&t.from) && kernel.hdag.tensors.contains_key(&t.to)
+        }));
     }
 
     #[test]
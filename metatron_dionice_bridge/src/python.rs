@@ -35,7 +35,7 @@ impl PyDioniceKernel {
     ///     dict: Calibration suggestion with keys:
     ///         - new_config (dict): Suggested configuration updates
     ///         - notes (str): Human-readable suggestions
-    ///         - resonance_score (float): Quality score
+    ///         - resonance_score (float): Normalized HDAG resonance score in [0, 1]
     ///         - regime_change_suggested (bool): Whether to switch algorithms
     fn step(
         &mut self,
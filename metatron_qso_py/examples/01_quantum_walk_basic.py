@@ -62,10 +62,10 @@ def main():
     print("Final State Analysis (t = 5.0):")
     print(f"  Total probability: {sum(final_probs):.6f} (should be 1.0)")
     print(
-        f"  Max probability: {max(final_probs):.6f} at node {final_probs.index(max(final_probs))}"
+        f"  Max probability: {max(final_probs):.6f} at node {final_probs.argmax()}"
     )
     print(
-        f"  Min probability: {min(final_probs):.6f} at node {final_probs.index(min(final_probs))}"
+        f"  Min probability: {min(final_probs):.6f} at node {final_probs.argmin()}"
     )
     print()
 
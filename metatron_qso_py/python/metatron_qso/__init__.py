@@ -17,14 +17,22 @@ Example:
 # Import from the internal Rust module
 from ._metatron_qso_internal import (
     MetatronGraph,
+    QuantumState,
+    DTLState,
+    DTLResonatorNetwork,
     run_quantum_walk,
     solve_maxcut_qaoa,
     run_vqe,
+    hamiltonian_spectrum,
+    # Gate-level circuits
+    run_circuit,
     # High-level toolkits
     quantum_walk_centrality,
     quantum_walk_anomaly_score,
     quantum_walk_connectivity,
     solve_maxcut_qaoa_advanced,
+    # Dynamic Tripolar Logic
+    dtl_information_capacity,
     __version__,
 )
 
@@ -38,16 +46,24 @@ from .auto_tuning import (
 
 __all__ = [
     "MetatronGraph",
+    "QuantumState",
     # Core functions
     "run_quantum_walk",
     "solve_maxcut_qaoa",
     "run_vqe",
+    "hamiltonian_spectrum",
+    # Gate-level circuits
+    "run_circuit",
     # Quantum Walk Toolkit
     "quantum_walk_centrality",
     "quantum_walk_anomaly_score",
     "quantum_walk_connectivity",
     # QAOA Optimizer
     "solve_maxcut_qaoa_advanced",
+    # Dynamic Tripolar Logic
+    "DTLState",
+    "DTLResonatorNetwork",
+    "dtl_information_capacity",
     # Auto-Tuning Integration (SCS)
     "run_quantum_walk_with_tuning",
     "solve_maxcut_qaoa_with_tuning",
@@ -263,7 +263,7 @@ def _compute_qw_metrics(
     """
     # Quality (ψ): Spreading quality based on entropy
     final_state = result.get("final_state", [])
-    if final_state:
+    if len(final_state) > 0:
         import math
 
         # Compute entropy
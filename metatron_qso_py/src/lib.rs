@@ -2,13 +2,17 @@
 //!
 //! This module provides a Python-friendly API for the Metatron QSO quantum computing framework.
 
-use pyo3::exceptions::{PyRuntimeError, PyValueError};
+use numpy::{PyArray1, PyArray2};
+use pyo3::exceptions::{PyRuntimeError, PyTypeError, PyValueError};
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
-use std::sync::Arc;
+use pyo3::types::{PyBool, PyDict};
+use std::sync::{Arc, Mutex};
 
 // Import from the Rust core library with explicit path to avoid module conflicts
 use core::prelude::*;
+use core::vqa::optimizer::CallbackAction;
+use core::vqa::HistoryEntry;
+use metatron_backend::{GateType, LocalSimulatorBackend, MetatronCircuit, QuantumBackend};
 use metatron_qso as core;
 
 /// Python wrapper for MetatronGraph
@@ -36,19 +40,42 @@ impl PyMetatronGraph {
     ///     adjacency (list of lists): Adjacency list where adjacency[i] contains neighbors of node i
     ///
     /// Returns:
-    ///         MetatronGraph: A new graph instance
+    ///         MetatronGraph: A new graph instance with exactly the listed edges (unit weight)
     #[staticmethod]
     fn from_adjacency(adjacency: Vec<Vec<usize>>) -> PyResult<Self> {
-        // For now, we return the default Metatron graph
-        // In a full implementation, this would validate and construct from adjacency
         if adjacency.len() != 13 {
             return Err(PyValueError::new_err(
                 "Metatron graph must have exactly 13 nodes",
             ));
         }
-        Ok(PyMetatronGraph {
-            inner: MetatronGraph::new(),
-        })
+
+        let mut edges: Vec<(usize, usize, f64)> = Vec::new();
+        for (u, neighbours) in adjacency.iter().enumerate() {
+            for &v in neighbours {
+                let (a, b) = (u.min(v), u.max(v));
+                if !edges.iter().any(|&(x, y, _)| (x, y) == (a, b)) {
+                    edges.push((a, b, 1.0));
+                }
+            }
+        }
+
+        let inner = MetatronGraph::from_weighted_edges(&edges)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+        Ok(PyMetatronGraph { inner })
+    }
+
+    /// Create a graph from weighted edges
+    ///
+    /// Args:
+    ///     edges (list of (int, int, float)): Undirected edges (u, v, weight)
+    ///
+    /// Returns:
+    ///     MetatronGraph: A new graph whose Laplacian uses the given weights
+    #[staticmethod]
+    fn from_weighted_edges(edges: Vec<(usize, usize, f64)>) -> PyResult<Self> {
+        let inner = MetatronGraph::from_weighted_edges(&edges)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+        Ok(PyMetatronGraph { inner })
     }
 
     /// Get the number of nodes in the graph
@@ -93,6 +120,391 @@ impl PyMetatronGraph {
     }
 }
 
+/// Python wrapper for a pure state on the 13-dimensional Metatron space
+///
+/// Amplitude i belongs to node i of the Metatron graph.
+#[pyclass(name = "QuantumState")]
+#[derive(Clone)]
+struct PyQuantumState {
+    inner: QuantumState,
+}
+
+#[pymethods]
+impl PyQuantumState {
+    /// Basis state |i⟩, the walker localized on node `index`
+    ///
+    /// Raises:
+    ///     ValueError: If the index is not a node of the graph
+    #[staticmethod]
+    fn basis_state(index: usize) -> PyResult<Self> {
+        if index >= METATRON_DIMENSION {
+            return Err(PyValueError::new_err(format!(
+                "Node index {} out of bounds (graph has {} nodes)",
+                index, METATRON_DIMENSION
+            )));
+        }
+        let inner = QuantumState::basis_state(index)
+            .map_err(|e| PyRuntimeError::new_err(format!("Failed to create state: {}", e)))?;
+        Ok(PyQuantumState { inner })
+    }
+
+    /// Equal superposition of all 13 nodes
+    #[staticmethod]
+    fn uniform_superposition() -> Self {
+        PyQuantumState {
+            inner: QuantumState::uniform_superposition(),
+        }
+    }
+
+    /// Create a state from 13 amplitudes, normalized automatically
+    ///
+    /// Args:
+    ///     amplitudes (list of complex): One amplitude per node (floats are accepted)
+    ///
+    /// Raises:
+    ///     ValueError: If there are not 13 amplitudes or all of them are zero
+    #[staticmethod]
+    fn from_amplitudes(amplitudes: Vec<num_complex::Complex64>) -> PyResult<Self> {
+        if amplitudes.len() != METATRON_DIMENSION {
+            return Err(PyValueError::new_err(format!(
+                "amplitudes must have {} entries, got {}",
+                METATRON_DIMENSION,
+                amplitudes.len()
+            )));
+        }
+        if amplitudes.iter().all(|a| a.norm_sqr() == 0.0) {
+            return Err(PyValueError::new_err("amplitudes cannot all be zero"));
+        }
+        let inner = QuantumState::from_amplitudes(amplitudes)
+            .map_err(|e| PyRuntimeError::new_err(format!("Failed to create state: {}", e)))?;
+        Ok(PyQuantumState { inner })
+    }
+
+    /// Node occupation probabilities |αᵢ|²
+    ///
+    /// Args:
+    ///     as_list (bool): Return a plain Python list instead of a numpy array (default: False)
+    #[pyo3(signature = (as_list=false))]
+    fn probabilities(&self, py: Python<'_>, as_list: bool) -> PyResult<Py<PyAny>> {
+        let probabilities = self.inner.probabilities().to_vec();
+        if as_list {
+            Ok(probabilities.into_pyobject(py)?.into_any().unbind())
+        } else {
+            Ok(PyArray1::from_vec(py, probabilities).into_any().unbind())
+        }
+    }
+
+    /// Complex amplitudes, one per node
+    fn amplitudes(&self) -> Vec<num_complex::Complex64> {
+        self.inner.amplitudes().iter().copied().collect()
+    }
+
+    /// Inner product ⟨self|other⟩ (conjugate-linear in `self`)
+    fn inner_product(&self, other: &PyQuantumState) -> num_complex::Complex64 {
+        self.inner.inner_product(&other.inner)
+    }
+
+    /// String representation
+    fn __repr__(&self) -> String {
+        let probabilities = self.inner.probabilities();
+        let (node, probability) =
+            probabilities
+                .iter()
+                .copied()
+                .enumerate()
+                .fold(
+                    (0, f64::MIN),
+                    |best, (i, p)| if p > best.1 { (i, p) } else { best },
+                );
+        format!(
+            "QuantumState(nodes={}, most_likely_node={}, probability={:.4})",
+            METATRON_DIMENSION, node, probability
+        )
+    }
+}
+
+/// Python wrapper for a Dynamic Tripolar Logic state
+///
+/// A state is either static (L0 = 0, L1 = 1) or dynamic (LD), in which case
+/// its value follows a trajectory x(t) in [0, 1].
+#[pyclass(name = "DTLState")]
+#[derive(Clone)]
+struct PyDTLState {
+    inner: DTLState,
+}
+
+#[pymethods]
+impl PyDTLState {
+    /// Static null-pole state L0
+    #[staticmethod]
+    fn l0() -> Self {
+        PyDTLState {
+            inner: DTLState::l0(),
+        }
+    }
+
+    /// Static one-pole state L1
+    #[staticmethod]
+    fn l1() -> Self {
+        PyDTLState {
+            inner: DTLState::l1(),
+        }
+    }
+
+    /// Dynamic LD state with a sinusoidal trajectory
+    ///
+    /// Args:
+    ///     frequency (float): Oscillation frequency
+    ///     phase (float): Phase offset in radians (default: 0.0)
+    ///     amplitude (float): Oscillation amplitude (default: 0.5)
+    ///     offset (float): Mean value (default: 0.5)
+    ///
+    /// Returns:
+    ///     DTLState: x(t) = offset + amplitude * sin(2π·frequency·t + phase), clamped to [0, 1]
+    #[staticmethod]
+    #[pyo3(signature = (frequency, phase=0.0, amplitude=0.5, offset=0.5))]
+    fn oscillatory(frequency: f64, phase: f64, amplitude: f64, offset: f64) -> Self {
+        PyDTLState {
+            inner: DTLState::ld_oscillatory(frequency, phase, amplitude, offset),
+        }
+    }
+
+    /// State class: "L0", "L1" or "Ld"
+    #[getter]
+    fn kind(&self) -> String {
+        format!("{:?}", self.inner.kind())
+    }
+
+    /// Evaluate the state value at time t
+    fn evaluate(&self, t: f64) -> f64 {
+        self.inner.evaluate(t)
+    }
+
+    /// Tripolar conjunction
+    fn __and__(&self, other: &PyDTLState) -> Self {
+        PyDTLState {
+            inner: DTLOperations::and(&self.inner, &other.inner),
+        }
+    }
+
+    /// Tripolar disjunction
+    fn __or__(&self, other: &PyDTLState) -> Self {
+        PyDTLState {
+            inner: DTLOperations::or(&self.inner, &other.inner),
+        }
+    }
+
+    /// Tripolar negation
+    fn __invert__(&self) -> Self {
+        PyDTLState {
+            inner: DTLOperations::not(&self.inner),
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        format!("DTLState(kind={})", self.kind())
+    }
+}
+
+/// Python wrapper for a network of 13 coupled DTL resonators
+///
+/// Each node of the graph carries a Kuramoto phase oscillator; phases map to
+/// tripolar values via x = (1 + cos φ) / 2.
+#[pyclass(name = "DTLResonatorNetwork")]
+struct PyDTLResonatorNetwork {
+    inner: DTLResonatorNetwork,
+    time: f64,
+}
+
+#[pymethods]
+impl PyDTLResonatorNetwork {
+    /// Create a resonator network on a graph
+    ///
+    /// Args:
+    ///     graph (MetatronGraph): Coupling topology
+    ///     kappa (float): Kuramoto coupling strength (default: 1.0)
+    ///     omega (list of float, optional): Intrinsic frequencies, one per node (default: all 0)
+    ///     phases (list of float, optional): Initial phases, one per node (default: random)
+    #[new]
+    #[pyo3(signature = (graph, kappa=1.0, omega=None, phases=None))]
+    fn new(
+        graph: &PyMetatronGraph,
+        kappa: f64,
+        omega: Option<Vec<f64>>,
+        phases: Option<Vec<f64>>,
+    ) -> PyResult<Self> {
+        let mut params = QSOParameters {
+            kappa,
+            ..QSOParameters::default()
+        };
+        if let Some(omega) = omega {
+            params.omega = node_array("omega", omega)?;
+        }
+
+        let mut inner = DTLResonatorNetwork::new(graph.inner.clone(), params);
+        if let Some(phases) = phases {
+            inner = inner.with_phases(node_array("phases", phases)?);
+        }
+
+        Ok(PyDTLResonatorNetwork { inner, time: 0.0 })
+    }
+
+    /// Advance the network by a number of Euler steps
+    ///
+    /// Args:
+    ///     steps (int): Number of update steps
+    ///     dt (float): Time step (default: 0.01)
+    ///
+    /// Returns:
+    ///     dict: Dictionary containing:
+    ///         - 'times': Time after each step
+    ///         - 'order_parameter': Kuramoto order parameter r after each step
+    #[pyo3(signature = (steps, dt=0.01))]
+    fn run(&mut self, steps: usize, dt: f64) -> PyResult<Py<PyAny>> {
+        if dt <= 0.0 {
+            return Err(PyValueError::new_err("dt must be positive"));
+        }
+
+        let (times, order) = self.advance(steps, dt);
+
+        Python::attach(|py| {
+            let result = PyDict::new(py);
+            result.set_item("times", times.into_pyobject(py)?)?;
+            result.set_item("order_parameter", order.into_pyobject(py)?)?;
+            Ok(result.into_any().unbind())
+        })
+    }
+
+    /// Current simulation time
+    #[getter]
+    fn time(&self) -> f64 {
+        self.time
+    }
+
+    /// Current resonator phases (radians)
+    fn phases(&self) -> Vec<f64> {
+        self.inner.phases().to_vec()
+    }
+
+    /// Current tripolar values x = (1 + cos φ) / 2 in [0, 1]
+    fn tripolar_values(&self) -> Vec<f64> {
+        self.inner.phases_to_dtl(self.inner.phases()).to_vec()
+    }
+
+    /// Classify each node as "L0", "L1" or "Ld"
+    ///
+    /// Args:
+    ///     tolerance (float): Distance from 0 or 1 that still counts as a pole (default: 0.1)
+    #[pyo3(signature = (tolerance=0.1))]
+    fn tripolar_states(&self, tolerance: f64) -> Vec<String> {
+        self.inner
+            .phases_to_dtl(self.inner.phases())
+            .iter()
+            .map(|&x| {
+                if x.abs() < tolerance {
+                    "L0".to_string()
+                } else if (x - 1.0).abs() < tolerance {
+                    "L1".to_string()
+                } else {
+                    "Ld".to_string()
+                }
+            })
+            .collect()
+    }
+
+    /// Resonance metrics of the current configuration
+    ///
+    /// Returns:
+    ///     dict: Dictionary containing:
+    ///         - 'order_parameter': Kuramoto order parameter r in [0, 1]
+    ///         - 'synchronization_threshold': Approximate critical coupling κ_c
+    ///         - 'mean_tripolar_value': Mean of the tripolar values
+    fn resonance_metrics(&self) -> PyResult<Py<PyAny>> {
+        let phases = self.inner.phases();
+        let values = self.inner.phases_to_dtl(phases);
+        let mean = values.iter().sum::<f64>() / values.len() as f64;
+
+        Python::attach(|py| {
+            let result = PyDict::new(py);
+            result.set_item("order_parameter", self.inner.order_parameter(phases))?;
+            result.set_item(
+                "synchronization_threshold",
+                self.inner.synchronization_threshold(),
+            )?;
+            result.set_item("mean_tripolar_value", mean)?;
+            Ok(result.into_any().unbind())
+        })
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "DTLResonatorNetwork(t={:.3}, r={:.4})",
+            self.time,
+            self.inner.order_parameter(self.inner.phases())
+        )
+    }
+}
+
+/// Convert a per-node Python list into a fixed-size array
+impl PyDTLResonatorNetwork {
+    /// Take exactly `steps` Euler steps, returning the time and order
+    /// parameter after each one.
+    ///
+    /// Times are computed from the step index rather than accumulated, so
+    /// rounding never changes how far the network advances.
+    fn advance(&mut self, steps: usize, dt: f64) -> (Vec<f64>, Vec<f64>) {
+        let start = self.time;
+        let mut times = Vec::with_capacity(steps);
+        let mut order = Vec::with_capacity(steps);
+        for step in 0..steps {
+            self.inner.step(start + step as f64 * dt, dt);
+            times.push(start + (step + 1) as f64 * dt);
+            order.push(self.inner.order_parameter(self.inner.phases()));
+        }
+        self.time = start + steps as f64 * dt;
+        (times, order)
+    }
+}
+
+fn node_array(name: &str, values: Vec<f64>) -> PyResult<[f64; METATRON_DIMENSION]> {
+    let len = values.len();
+    values.try_into().map_err(|_| {
+        PyValueError::new_err(format!(
+            "{} must have {} entries, got {}",
+            name, METATRON_DIMENSION, len
+        ))
+    })
+}
+
+/// Information capacity of tripolar versus binary logic
+///
+/// Returns:
+///     dict: Dictionary containing:
+///         - 'tripolar_capacity': log2(3) bits per symbol
+///         - 'binary_capacity': 1 bit per symbol
+///         - 'relative_advantage': (C_tri - C_bin) / C_bin ≈ 0.585
+#[pyfunction]
+fn dtl_information_capacity() -> PyResult<Py<PyAny>> {
+    use core::dtl::state::TripolarInformationTheory;
+
+    Python::attach(|py| {
+        let result = PyDict::new(py);
+        result.set_item(
+            "tripolar_capacity",
+            TripolarInformationTheory::channel_capacity_tripolar(),
+        )?;
+        result.set_item(
+            "binary_capacity",
+            TripolarInformationTheory::channel_capacity_binary(),
+        )?;
+        result.set_item(
+            "relative_advantage",
+            TripolarInformationTheory::relative_advantage(),
+        )?;
+        Ok(result.into_any().unbind())
+    })
+}
+
 /// Run a continuous-time quantum walk on a graph
 ///
 /// Args:
@@ -100,24 +512,26 @@ impl PyMetatronGraph {
 ///     source_nodes (list of int): Initial nodes with equal probability
 ///     t_max (float): Maximum evolution time (default: 10.0)
 ///     dt (float): Time step for evolution (default: 0.1)
+///     as_list (bool): Return plain Python lists instead of numpy arrays (default: False)
 ///
 /// Returns:
 ///     dict: Dictionary containing:
-///         - 'times': List of time points
-///         - 'probabilities': List of probability distributions at each time
-///         - 'final_state': Final probability distribution
+///         - 'times': Time points, shape (steps,)
+///         - 'probabilities': Probability distribution at each time, shape (steps, nodes)
+///         - 'final_state': Final probability distribution, shape (nodes,)
 ///
 /// Example:
 ///     >>> graph = MetatronGraph()
 ///     >>> result = run_quantum_walk(graph, [0], t_max=5.0, dt=0.1)
 ///     >>> print(result['final_state'])
 #[pyfunction]
-#[pyo3(signature = (graph, source_nodes, t_max=10.0, dt=0.1))]
+#[pyo3(signature = (graph, source_nodes, t_max=10.0, dt=0.1, as_list=false))]
 fn run_quantum_walk(
     graph: &PyMetatronGraph,
     source_nodes: Vec<usize>,
     t_max: f64,
     dt: f64,
+    as_list: bool,
 ) -> PyResult<Py<PyAny>> {
     // Validate inputs
     if source_nodes.is_empty() {
@@ -175,38 +589,111 @@ fn run_quantum_walk(
     // Return as Python dict
     Python::attach(|py| {
         let result = PyDict::new(py);
-        result.set_item("times", times.into_pyobject(py)?)?;
         // Extract final state before moving probabilities
         let final_state = probabilities.last().unwrap().clone();
-        result.set_item("probabilities", probabilities.into_pyobject(py)?)?;
-        result.set_item("final_state", final_state.into_pyobject(py)?)?;
+        if as_list {
+            result.set_item("times", times.into_pyobject(py)?)?;
+            result.set_item("probabilities", probabilities.into_pyobject(py)?)?;
+            result.set_item("final_state", final_state.into_pyobject(py)?)?;
+        } else {
+            let probabilities = PyArray2::from_vec2(py, &probabilities)
+                .map_err(|e| PyRuntimeError::new_err(e.to_string()))?;
+            result.set_item("times", PyArray1::from_vec(py, times))?;
+            result.set_item("probabilities", probabilities)?;
+            result.set_item("final_state", PyArray1::from_vec(py, final_state))?;
+        }
         Ok(result.into_any().unbind())
     })
 }
 
+/// Exception raised inside an optimizer iteration, re-raised after the run
+type PendingError = Arc<Mutex<Option<PyErr>>>;
+
+/// Per-iteration hook for long-running optimizations
+///
+/// Checks for pending signals, so Ctrl-C raises KeyboardInterrupt between
+/// iterations, then calls `callback(cost)` if given. Returning `False` from
+/// the callback stops the optimization early; any other value, including
+/// other falsy ones such as `0`, continues. Any exception stops it as well
+/// and is stored in `error`.
+fn iteration_hook(
+    callback: Option<Py<PyAny>>,
+    error: PendingError,
+) -> impl FnMut(&HistoryEntry) -> CallbackAction + Send + 'static {
+    move |entry| {
+        if error.lock().unwrap().is_some() {
+            return CallbackAction::Stop;
+        }
+        let outcome = Python::attach(|py| -> PyResult<bool> {
+            py.check_signals()?;
+            match &callback {
+                Some(callback) => {
+                    let returned = callback.call1(py, (entry.cost,))?;
+                    Ok(!returned.bind(py).is(PyBool::new(py, false)))
+                }
+                None => Ok(true),
+            }
+        });
+        match outcome {
+            Ok(true) => CallbackAction::Continue,
+            Ok(false) => CallbackAction::Stop,
+            Err(err) => {
+                *error.lock().unwrap() = Some(err);
+                CallbackAction::Stop
+            }
+        }
+    }
+}
+
+/// Validate an optional Python callback argument
+fn optional_callable(callback: Option<Bound<'_, PyAny>>) -> PyResult<Option<Py<PyAny>>> {
+    match callback {
+        Some(callback) if !callback.is_callable() => {
+            Err(PyTypeError::new_err("callback must be callable"))
+        }
+        callback => Ok(callback.map(Bound::unbind)),
+    }
+}
+
+/// Re-raise an exception stored by [`iteration_hook`]
+fn raise_pending(error: &PendingError) -> PyResult<()> {
+    match error.lock().unwrap().take() {
+        Some(err) => Err(err),
+        None => Ok(()),
+    }
+}
+
 /// Solve the MaxCut problem using QAOA
 ///
 /// Args:
 ///     graph (MetatronGraph): The graph for the MaxCut problem
 ///     depth (int): QAOA circuit depth (default: 3)
 ///     max_iters (int): Maximum optimization iterations (default: 100)
+///     callback (callable, optional): Called as `callback(cost)` after every
+///         optimizer iteration; returning False stops early
 ///
 /// Returns:
 ///     dict: Dictionary containing:
 ///         - 'cut_value': The best cut value found
-///         - 'approximation_ratio': Quality of the solution
+///         - 'approximation_ratio': cut_value over the exact optimum
+///         - 'optimal_cut': Exact MaxCut value from brute-force enumeration
 ///         - 'meta': Additional metadata about the optimization
 ///
+/// Raises:
+///     KeyboardInterrupt: If interrupted (e.g. Ctrl-C) during optimization
+///
 /// Example:
 ///     >>> graph = MetatronGraph()
 ///     >>> result = solve_maxcut_qaoa(graph, depth=3, max_iters=100)
 ///     >>> print(f"Cut value: {result['cut_value']}")
 #[pyfunction]
-#[pyo3(signature = (graph, depth=3, max_iters=100))]
+#[pyo3(signature = (graph, depth=3, max_iters=100, callback=None))]
 fn solve_maxcut_qaoa(
+    py: Python<'_>,
     graph: &PyMetatronGraph,
     depth: usize,
     max_iters: usize,
+    callback: Option<Bound<'_, PyAny>>,
 ) -> PyResult<Py<PyAny>> {
     if depth == 0 {
         return Err(PyValueError::new_err("depth must be positive"));
@@ -214,31 +701,53 @@ fn solve_maxcut_qaoa(
     if max_iters == 0 {
         return Err(PyValueError::new_err("max_iters must be positive"));
     }
+    let callback = optional_callable(callback)?;
+    let pending: PendingError = Arc::default();
 
-    // Create MaxCut Hamiltonian from graph edges
-    let edges: Vec<(usize, usize)> = graph.inner.edges().to_vec();
+    let hook = iteration_hook(callback, pending.clone());
 
-    let cost_hamiltonian = Arc::new(core::vqa::qaoa::create_maxcut_hamiltonian(&edges));
+    // Optimize without holding the GIL; the hook re-acquires it per iteration
+    let (result, mean_cost, std_dev, optimal_cut) = py.detach(|| {
+        // Create MaxCut Hamiltonian from graph edges
+        let edges: Vec<(usize, usize, f64)> = graph.inner.weighted_edges().collect();
 
-    // Build and run QAOA
-    let qaoa = QAOABuilder::new()
-        .cost_hamiltonian(cost_hamiltonian)
-        .depth(depth)
-        .optimizer(OptimizerType::NelderMead)
-        .max_iterations(max_iters)
-        .verbose(false)
-        .build();
+        let cost_hamiltonian =
+            Arc::new(core::vqa::qaoa::create_weighted_maxcut_hamiltonian(&edges));
 
-    let result = qaoa.run();
+        // Build and run QAOA
+        let qaoa = QAOABuilder::new()
+            .cost_hamiltonian(cost_hamiltonian)
+            .depth(depth)
+            .optimizer(OptimizerType::NelderMead)
+            .max_iterations(max_iters)
+            .verbose(false)
+            .callback(hook)
+            .build();
 
-    // Sample to get statistics
-    let (mean_cost, std_dev, _costs) = qaoa.analyze_samples(&result.optimal_state, 100);
+        let result = qaoa.run();
+
+        // Sample to get statistics
+        let (mean_cost, std_dev, _costs) = qaoa.analyze_samples(&result.optimal_state, 100);
+
+        // Exact optimum to rate the cut against
+        let (optimal_cut, _) = core::optimizer::maxcut_exact(&graph.inner);
+        (result, mean_cost, std_dev, optimal_cut)
+    });
+    raise_pending(&pending)?;
+
+    let cut_value = -result.optimal_cost; // Negate because we minimize
+    let approximation_ratio = if optimal_cut > 0.0 {
+        cut_value / optimal_cut
+    } else {
+        1.0
+    };
 
     // Return as Python dict
     Python::attach(|py| {
         let result_dict = PyDict::new(py);
-        result_dict.set_item("cut_value", -result.optimal_cost)?; // Negate because we minimize
-        result_dict.set_item("approximation_ratio", result.approximation_ratio)?;
+        result_dict.set_item("cut_value", cut_value)?;
+        result_dict.set_item("approximation_ratio", approximation_ratio)?;
+        result_dict.set_item("optimal_cut", optimal_cut)?;
 
         let meta = PyDict::new(py);
         meta.set_item("iterations", result.optimization_result.iterations)?;
@@ -257,7 +766,9 @@ fn solve_maxcut_qaoa(
 ///     graph (MetatronGraph): The graph to create the Hamiltonian from
 ///     depth (int): Ansatz circuit depth (default: 2)
 ///     max_iters (int): Maximum optimization iterations (default: 100)
-///     ansatz_type (str): Type of ansatz - "hardware_efficient", "metatron", or "efficient_su2" (default: "hardware_efficient")
+///     ansatz_type (str): Type of ansatz - "hardware_efficient", "metatron", "efficient_su2", or "particle_conserving" (default: "hardware_efficient")
+///     callback (callable, optional): Called as `callback(energy)` after every
+///         optimizer iteration; returning False stops early
 ///
 /// Returns:
 ///     dict: Dictionary containing:
@@ -265,19 +776,26 @@ fn solve_maxcut_qaoa(
 ///         - 'classical_ground_energy': Exact ground state energy for comparison
 ///         - 'error': Absolute error from exact result
 ///         - 'iterations': Number of optimization iterations
+///         - 'gradient_norm': Energy gradient norm at the optimum
+///         - 'energy_variance': ⟨H²⟩ − ⟨H⟩² of the final state (0 for an eigenstate)
 ///         - 'final_state': The final quantum state probabilities
 ///
+/// Raises:
+///     KeyboardInterrupt: If interrupted (e.g. Ctrl-C) during optimization
+///
 /// Example:
 ///     >>> graph = MetatronGraph()
 ///     >>> result = run_vqe(graph, depth=2, max_iters=100)
 ///     >>> print(f"Ground state energy: {result['ground_state_energy']:.6f}")
 #[pyfunction]
-#[pyo3(signature = (graph, depth=2, max_iters=100, ansatz_type="hardware_efficient"))]
+#[pyo3(signature = (graph, depth=2, max_iters=100, ansatz_type="hardware_efficient", callback=None))]
 fn run_vqe(
+    py: Python<'_>,
     graph: &PyMetatronGraph,
     depth: usize,
     max_iters: usize,
     ansatz_type: &str,
+    callback: Option<Bound<'_, PyAny>>,
 ) -> PyResult<Py<PyAny>> {
     if depth == 0 {
         return Err(PyValueError::new_err("depth must be positive"));
@@ -291,30 +809,41 @@ fn run_vqe(
         "hardware_efficient" => AnsatzType::HardwareEfficient,
         "metatron" => AnsatzType::Metatron,
         "efficient_su2" => AnsatzType::EfficientSU2,
+        "particle_conserving" => AnsatzType::ParticleConserving,
         _ => {
             return Err(PyValueError::new_err(
-                "ansatz_type must be 'hardware_efficient', 'metatron', or 'efficient_su2'",
+                "ansatz_type must be 'hardware_efficient', 'metatron', 'efficient_su2', \
+                 or 'particle_conserving'",
             ))
         }
     };
 
-    // Create Hamiltonian
-    let params = QSOParameters::default();
-    let hamiltonian = Arc::new(MetatronHamiltonian::new(&graph.inner, &params));
-
-    // Build and run VQE
-    let vqe = VQEBuilder::new()
-        .hamiltonian(hamiltonian)
-        .ansatz_type(ansatz)
-        .ansatz_depth(depth)
-        .optimizer(OptimizerType::Adam)
-        .max_iterations(max_iters)
-        .learning_rate(0.01)
-        .tolerance(1e-6)
-        .verbose(false)
-        .build();
-
-    let result = vqe.run();
+    let callback = optional_callable(callback)?;
+    let pending: PendingError = Arc::default();
+
+    let hook = iteration_hook(callback, pending.clone());
+
+    // Optimize without holding the GIL; the hook re-acquires it per iteration
+    let result = py.detach(|| {
+        // Create Hamiltonian
+        let params = QSOParameters::default();
+        let hamiltonian = Arc::new(MetatronHamiltonian::new(&graph.inner, &params));
+
+        // Build and run VQE
+        VQEBuilder::new()
+            .hamiltonian(hamiltonian)
+            .ansatz_type(ansatz)
+            .ansatz_depth(depth)
+            .optimizer(OptimizerType::Adam)
+            .max_iterations(max_iters)
+            .learning_rate(0.01)
+            .tolerance(1e-6)
+            .verbose(false)
+            .callback(hook)
+            .build()
+            .run()
+    });
+    raise_pending(&pending)?;
 
     // Return as Python dict
     Python::attach(|py| {
@@ -323,6 +852,8 @@ fn run_vqe(
         result_dict.set_item("classical_ground_energy", result.classical_ground_energy)?;
         result_dict.set_item("error", result.approximation_error)?;
         result_dict.set_item("iterations", result.optimization_result.iterations)?;
+        result_dict.set_item("gradient_norm", result.gradient_norm)?;
+        result_dict.set_item("energy_variance", result.energy_variance)?;
         result_dict.set_item(
             "final_state",
             result
@@ -336,6 +867,64 @@ fn run_vqe(
     })
 }
 
+/// Compute the exact spectrum of the Metatron Hamiltonian
+///
+/// Useful for validating variational results against exact diagonalization.
+///
+/// Args:
+///     graph (MetatronGraph): The graph to create the Hamiltonian from
+///
+/// Returns:
+///     dict: Dictionary containing:
+///         - 'eigenvalues': All eigenvalues in ascending order
+///         - 'ground_energy': Lowest eigenvalue
+///         - 'spectral_gap': Smallest gap between distinct eigenvalues
+///         - 'ground_state': Ground state amplitudes (list of complex)
+///
+/// Example:
+///     >>> graph = MetatronGraph()
+///     >>> spectrum = hamiltonian_spectrum(graph)
+///     >>> print(f"Exact ground energy: {spectrum['ground_energy']:.6f}")
+#[pyfunction]
+fn hamiltonian_spectrum(graph: &PyMetatronGraph) -> PyResult<Py<PyAny>> {
+    let params = QSOParameters::default();
+    let hamiltonian = MetatronHamiltonian::new(&graph.inner, &params);
+    let spectrum = hamiltonian.spectrum_info();
+    let ground_state: Vec<num_complex::Complex64> = hamiltonian
+        .ground_state()
+        .amplitudes()
+        .iter()
+        .copied()
+        .collect();
+
+    Python::attach(|py| {
+        let result = PyDict::new(py);
+        result.set_item("ground_energy", spectrum.ground_state_energy)?;
+        result.set_item("spectral_gap", spectrum.spectral_gap())?;
+        result.set_item("eigenvalues", spectrum.eigenvalues.into_pyobject(py)?)?;
+        result.set_item("ground_state", ground_state.into_pyobject(py)?)?;
+        Ok(result.into_any().unbind())
+    })
+}
+
+/// Validate toolkit sampling parameters before they reach the core, which
+/// panics on step counts that would never finish
+fn walk_params(
+    t_max: f64,
+    dt: f64,
+    samples: usize,
+) -> PyResult<core::quantum_walk_toolkit::QuantumWalkParams> {
+    if !(t_max >= 0.0 && t_max.is_finite()) {
+        return Err(PyValueError::new_err(
+            "t_max must be non-negative and finite",
+        ));
+    }
+    if !(dt > 0.0 && dt.is_finite()) {
+        return Err(PyValueError::new_err("dt must be positive and finite"));
+    }
+    Ok(core::quantum_walk_toolkit::QuantumWalkParams { t_max, dt, samples })
+}
+
 /// Compute quantum walk centrality for nodes
 ///
 /// Returns a centrality score for each node based on quantum walk dynamics.
@@ -346,21 +935,29 @@ fn run_vqe(
 /// * `t_max` - Maximum evolution time (default: 10.0)
 /// * `dt` - Time step (default: 0.1)
 /// * `samples` - Number of samples for averaging (default: 128)
+/// * `as_list` - Return a Python list instead of a numpy array (default: false)
 ///
 /// # Returns
-/// List of centrality scores (one per node, normalized to [0, 1])
+/// Array of centrality scores (one per node, normalized to [0, 1])
 #[pyfunction]
-#[pyo3(signature = (graph, t_max=10.0, dt=0.1, samples=128))]
+#[pyo3(signature = (graph, t_max=10.0, dt=0.1, samples=128, as_list=false))]
 fn quantum_walk_centrality(
+    py: Python<'_>,
     graph: &PyMetatronGraph,
     t_max: f64,
     dt: f64,
     samples: usize,
-) -> PyResult<Vec<f64>> {
-    let params = core::quantum_walk_toolkit::QuantumWalkParams { t_max, dt, samples };
+    as_list: bool,
+) -> PyResult<Py<PyAny>> {
+    let params = walk_params(t_max, dt, samples)?;
 
-    let centrality = core::quantum_walk_toolkit::quantum_walk_centrality(&graph.inner, &params);
-    Ok(centrality)
+    let centrality =
+        py.detach(|| core::quantum_walk_toolkit::quantum_walk_centrality(&graph.inner, &params));
+    if as_list {
+        Ok(centrality.into_pyobject(py)?.into_any().unbind())
+    } else {
+        Ok(PyArray1::from_vec(py, centrality).into_any().unbind())
+    }
 }
 
 /// Compute anomaly scores comparing base graph to current graph
@@ -385,7 +982,7 @@ fn quantum_walk_anomaly_score(
     dt: f64,
     samples: usize,
 ) -> PyResult<Vec<f64>> {
-    let params = core::quantum_walk_toolkit::QuantumWalkParams { t_max, dt, samples };
+    let params = walk_params(t_max, dt, samples)?;
 
     let anomaly = core::quantum_walk_toolkit::quantum_walk_anomaly_score(
         &base_graph.inner,
@@ -421,7 +1018,7 @@ fn quantum_walk_connectivity(
     dt: f64,
     samples: usize,
 ) -> PyResult<Py<PyAny>> {
-    let params = core::quantum_walk_toolkit::QuantumWalkParams { t_max, dt, samples };
+    let params = walk_params(t_max, dt, samples)?;
 
     let metrics =
         core::quantum_walk_toolkit::quantum_walk_connectivity(&graph.inner, &source_nodes, &params);
@@ -451,9 +1048,9 @@ fn quantum_walk_connectivity(
 ///
 /// # Returns
 /// Dictionary with:
-///   - 'cut_value': Number of edges cut
+///   - 'cut_value': Total weight of the edges cut by 'assignment'
 ///   - 'assignment': Binary node assignment (list of bool)
-///   - 'approximation_ratio': Quality metric
+///   - 'approximation_ratio': cut_value over the exact MaxCut optimum
 ///   - 'meta': Metadata (iterations, partition sizes, etc.)
 #[pyfunction]
 #[pyo3(signature = (graph, depth=3, max_iters=100, seed=None))]
@@ -483,15 +1080,182 @@ fn solve_maxcut_qaoa_advanced(
     })
 }
 
+/// Parse one gate spec `(name, *qubits, *params)` into a gate and its qubits
+fn parse_gate_spec(spec: &Bound<'_, PyAny>) -> PyResult<(GateType, Vec<usize>)> {
+    let items: Vec<Bound<'_, PyAny>> = spec.extract().map_err(|_| {
+        PyValueError::new_err(format!(
+            "gate spec must be a tuple or list like ('cnot', 0, 1), got {}",
+            spec.repr()
+                .map_or_else(|_| "?".to_string(), |r| r.to_string())
+        ))
+    })?;
+    let Some((name, args)) = items.split_first() else {
+        return Err(PyValueError::new_err("gate spec cannot be empty"));
+    };
+    let name: String = name
+        .extract()
+        .map_err(|_| PyValueError::new_err("gate name must be a string"))?;
+    let name = name.to_lowercase();
+
+    let (num_qubits, num_params) = match name.as_str() {
+        "h" | "x" | "y" | "z" | "s" | "sdg" | "t" | "tdg" | "measure" => (1, 0),
+        "rx" | "ry" | "rz" => (1, 1),
+        "u" => (1, 3),
+        "cnot" | "cx" | "cz" | "swap" => (2, 0),
+        "cphase" => (2, 1),
+        "ccx" | "toffoli" => (3, 0),
+        _ => {
+            return Err(PyValueError::new_err(format!("unknown gate '{}'", name)));
+        }
+    };
+    if args.len() != num_qubits + num_params {
+        return Err(PyValueError::new_err(format!(
+            "gate '{}' takes {} qubit(s) and {} parameter(s), got {} argument(s)",
+            name,
+            num_qubits,
+            num_params,
+            args.len()
+        )));
+    }
+
+    let qubits = args[..num_qubits]
+        .iter()
+        .map(|q| q.extract::<usize>())
+        .collect::<PyResult<Vec<_>>>()
+        .map_err(|_| {
+            PyValueError::new_err(format!(
+                "qubits of gate '{}' must be non-negative integers",
+                name
+            ))
+        })?;
+    if (1..qubits.len()).any(|i| qubits[..i].contains(&qubits[i])) {
+        return Err(PyValueError::new_err(format!(
+            "gate '{}' acts on repeated qubits {:?}",
+            name, qubits
+        )));
+    }
+    let params = args[num_qubits..]
+        .iter()
+        .map(|p| p.extract::<f64>())
+        .collect::<PyResult<Vec<_>>>()
+        .map_err(|_| {
+            PyValueError::new_err(format!("parameters of gate '{}' must be numbers", name))
+        })?;
+
+    let gate_type = match name.as_str() {
+        "h" => GateType::H,
+        "x" => GateType::X,
+        "y" => GateType::Y,
+        "z" => GateType::Z,
+        "s" => GateType::S,
+        "sdg" => GateType::Sdg,
+        "t" => GateType::T,
+        "tdg" => GateType::Tdg,
+        "measure" => GateType::Measure,
+        "rx" => GateType::RX(params[0]),
+        "ry" => GateType::RY(params[0]),
+        "rz" => GateType::RZ(params[0]),
+        "u" => GateType::U(params[0], params[1], params[2]),
+        "cnot" | "cx" => GateType::CNOT,
+        "cz" => GateType::CZ,
+        "swap" => GateType::SWAP,
+        "cphase" => GateType::CPhase(params[0]),
+        _ => GateType::Toffoli,
+    };
+    Ok((gate_type, qubits))
+}
+
+/// Run a gate-level circuit on the local state vector simulator
+///
+/// Each gate is a tuple `(name, *qubits, *params)`, e.g. `('h', 0)`,
+/// `('cnot', 0, 1)`, `('rx', 0, 0.5)` or `('u', 0, theta, phi, lam)`.
+/// Supported names: h, x, y, z, s, sdg, t, tdg, rx, ry, rz, u, cnot (cx),
+/// cz, swap, cphase, ccx (toffoli) and measure. The whole register is
+/// sampled at the end of every shot, so measure gates are optional.
+///
+/// Args:
+///     gates (list of tuple): Gate specs, applied in order
+///     shots (int): Number of measurement shots (default: 1024)
+///     num_qubits (int, optional): Register size; defaults to the highest
+///         qubit index used plus one
+///
+/// Returns:
+///     dict: Counts per measured bitstring, e.g. {'00': 512, '11': 512}
+///
+/// Raises:
+///     ValueError: If a gate spec is malformed or the register is too large
+///
+/// Example:
+///     >>> counts = run_circuit([('h', 0), ('cnot', 0, 1)], shots=1000)
+///     >>> print(counts)
+#[pyfunction]
+#[pyo3(signature = (gates, shots=1024, num_qubits=None))]
+fn run_circuit(
+    gates: Vec<Bound<'_, PyAny>>,
+    shots: u32,
+    num_qubits: Option<usize>,
+) -> PyResult<Py<PyAny>> {
+    if shots == 0 {
+        return Err(PyValueError::new_err("shots must be positive"));
+    }
+    let specs = gates
+        .iter()
+        .enumerate()
+        .map(|(index, spec)| {
+            parse_gate_spec(spec).map_err(|e| {
+                PyValueError::new_err(format!("gate {}: {}", index, e.value(spec.py())))
+            })
+        })
+        .collect::<PyResult<Vec<_>>>()?;
+
+    let used = specs
+        .iter()
+        .flat_map(|(_, qubits)| qubits.iter().map(|&q| q + 1))
+        .max()
+        .unwrap_or(1);
+    let num_qubits = num_qubits.unwrap_or(used);
+    if num_qubits == 0 || num_qubits < used {
+        return Err(PyValueError::new_err(format!(
+            "num_qubits is {} but the gates use {} qubit(s)",
+            num_qubits, used
+        )));
+    }
+    let backend = LocalSimulatorBackend::new();
+    if !backend.can_run(num_qubits) {
+        return Err(PyValueError::new_err(format!(
+            "circuit needs {} qubits; the local simulator supports at most {}",
+            num_qubits,
+            backend.info().num_qubits
+        )));
+    }
+
+    let mut circuit = MetatronCircuit::new(num_qubits);
+    for (gate_type, qubits) in specs {
+        circuit.add_gate(gate_type, qubits);
+    }
+    let result = backend
+        .run_circuit(&circuit, shots)
+        .map_err(|e| PyRuntimeError::new_err(format!("Circuit execution failed: {}", e)))?;
+
+    Python::attach(|py| Ok(result.counts.into_pyobject(py)?.into_any().unbind()))
+}
+
 /// Python module initialization
 #[pymodule]
 fn _metatron_qso_internal(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PyMetatronGraph>()?;
+    m.add_class::<PyQuantumState>()?;
+    m.add_class::<PyDTLState>()?;
+    m.add_class::<PyDTLResonatorNetwork>()?;
 
     // Core functions
     m.add_function(wrap_pyfunction!(run_quantum_walk, m)?)?;
     m.add_function(wrap_pyfunction!(solve_maxcut_qaoa, m)?)?;
     m.add_function(wrap_pyfunction!(run_vqe, m)?)?;
+    m.add_function(wrap_pyfunction!(hamiltonian_spectrum, m)?)?;
+
+    // Gate-level circuits
+    m.add_function(wrap_pyfunction!(run_circuit, m)?)?;
 
     // High-level toolkits
     m.add_function(wrap_pyfunction!(quantum_walk_centrality, m)?)?;
@@ -499,6 +1263,9 @@ fn _metatron_qso_internal(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(quantum_walk_connectivity, m)?)?;
     m.add_function(wrap_pyfunction!(solve_maxcut_qaoa_advanced, m)?)?;
 
+    // Dynamic Tripolar Logic
+    m.add_function(wrap_pyfunction!(dtl_information_capacity, m)?)?;
+
     // Module metadata
     m.add("__version__", env!("CARGO_PKG_VERSION"))?;
     m.add(
@@ -508,3 +1275,35 @@ fn _metatron_qso_internal(m: &Bound<'_, PyModule>) -> PyResult<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn run_takes_exactly_one_euler_step_per_iteration() {
+        let params = QSOParameters {
+            kappa: 0.0,
+            omega: [1.0; METATRON_DIMENSION],
+            ..QSOParameters::default()
+        };
+        let inner = DTLResonatorNetwork::new(MetatronGraph::new(), params)
+            .with_phases([0.0; METATRON_DIMENSION]);
+        let mut network = PyDTLResonatorNetwork { inner, time: 0.0 };
+
+        for dt in [0.01, 0.1] {
+            let start = network.time;
+            let before = *network.inner.phases();
+            let (times, order) = network.advance(10_000, dt);
+
+            assert_eq!(times.len(), 10_000);
+            assert_eq!(order.len(), 10_000);
+            assert_eq!(network.time, start + 10_000.0 * dt);
+            assert_eq!(times.last().copied(), Some(network.time));
+            // Free rotation at ω = 1 advances every phase by exactly t
+            for (after, before) in network.inner.phases().iter().zip(before) {
+                assert!((after - before - 10_000.0 * dt).abs() < 1e-9);
+            }
+        }
+    }
+}
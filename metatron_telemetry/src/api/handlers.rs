@@ -1,9 +1,10 @@
 //! HTTP request handlers
 
-use crate::state::{AppState, Job, JobMetrics, JobStatus};
+use super::metrics;
+use crate::state::{AppState, Job, JobMetrics, JobStatus, DEFAULT_TREND_WINDOW};
 use axum::{
     extract::{Path, Query, State},
-    http::StatusCode,
+    http::{header, StatusCode},
     response::IntoResponse,
     Json,
 };
@@ -62,6 +63,24 @@ pub async fn get_history(
     Json(history)
 }
 
+/// GET /trends - Rolling statistics of the metric history
+#[derive(Debug, Deserialize)]
+pub struct TrendsQuery {
+    #[serde(default)]
+    window: Option<usize>,
+}
+
+pub async fn get_trends(
+    State(state): State<AppState>,
+    Query(query): Query<TrendsQuery>,
+) -> impl IntoResponse {
+    let window = query.window.unwrap_or(DEFAULT_TREND_WINDOW);
+    match state.trend_report(window).await {
+        Some(report) => (StatusCode::OK, Json(report)).into_response(),
+        None => (StatusCode::NOT_FOUND, "No history in window").into_response(),
+    }
+}
+
 /// POST /control/start_calibration - Start new calibration run
 #[derive(Debug, Deserialize)]
 pub struct StartCalibrationRequest {
@@ -166,6 +185,15 @@ async fn simulate_calibration_run(state: AppState, job_id: Uuid) {
     tracing::info!("Calibration job {} completed", job_id);
 }
 
+/// GET /metrics - Prometheus scrape endpoint
+pub async fn get_metrics(State(state): State<AppState>) -> impl IntoResponse {
+    let snapshot = state.metrics().await;
+    (
+        [(header::CONTENT_TYPE, "text/plain; version=0.0.4")],
+        metrics::render(&snapshot),
+    )
+}
+
 /// Health check endpoint
 pub async fn health_check() -> impl IntoResponse {
     Json(serde_json::json!({
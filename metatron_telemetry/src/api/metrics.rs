@@ -0,0 +1,88 @@
+//! Prometheus text exposition of the telemetry metrics
+
+use crate::state::{MetricsSnapshot, LATENCY_BUCKETS};
+use std::fmt::Write;
+
+/// Render a snapshot in the Prometheus text format (version 0.0.4)
+pub fn render(snapshot: &MetricsSnapshot) -> String {
+    let mut out = String::new();
+
+    for (name, help, value) in [
+        ("qdash_psi", "Current quality metric psi", snapshot.psi),
+        ("qdash_rho", "Current stability metric rho", snapshot.rho),
+        (
+            "qdash_omega",
+            "Current efficiency metric omega",
+            snapshot.omega,
+        ),
+    ] {
+        let _ = writeln!(out, "# HELP {name} {help}");
+        let _ = writeln!(out, "# TYPE {name} gauge");
+        let _ = writeln!(out, "{name} {value}");
+    }
+
+    let _ = writeln!(
+        out,
+        "# HELP qdash_iterations_total Calibration status updates since startup"
+    );
+    let _ = writeln!(out, "# TYPE qdash_iterations_total counter");
+    let _ = writeln!(out, "qdash_iterations_total {}", snapshot.iterations);
+
+    let histogram = &snapshot.step_latencies;
+    let name = "qdash_step_latency_seconds";
+    let _ = writeln!(
+        out,
+        "# HELP {name} Time between consecutive calibration status updates"
+    );
+    let _ = writeln!(out, "# TYPE {name} histogram");
+    let mut cumulative = 0;
+    for (i, bound) in LATENCY_BUCKETS.iter().enumerate() {
+        cumulative += histogram.bucket_counts.get(i).copied().unwrap_or(0);
+        let _ = writeln!(out, "{name}_bucket{{le=\"{bound}\"}} {cumulative}");
+    }
+    let _ = writeln!(out, "{name}_bucket{{le=\"+Inf\"}} {}", histogram.count);
+    let _ = writeln!(out, "{name}_sum {}", histogram.sum);
+    let _ = writeln!(out, "{name}_count {}", histogram.count);
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::LatencyHistogram;
+
+    #[test]
+    fn histogram_buckets_are_cumulative() {
+        let mut step_latencies = LatencyHistogram::default();
+        for seconds in [0.001, 0.003, 0.02, 0.3, 20.0] {
+            step_latencies.observe(seconds);
+        }
+        let snapshot = MetricsSnapshot {
+            psi: 0.85,
+            rho: 0.9,
+            omega: 0.75,
+            iterations: 6,
+            step_latencies,
+        };
+
+        let text = render(&snapshot);
+        let buckets: Vec<(&str, u64)> = text
+            .lines()
+            .filter_map(|line| line.strip_prefix("qdash_step_latency_seconds_bucket{le=\""))
+            .map(|rest| {
+                let (bound, count) = rest.split_once("\"} ").unwrap();
+                (bound, count.parse().unwrap())
+            })
+            .collect();
+
+        assert_eq!(buckets.len(), LATENCY_BUCKETS.len() + 1);
+        assert!(buckets.windows(2).all(|pair| pair[0].1 <= pair[1].1));
+        assert_eq!(buckets[0], ("0.005", 2));
+        assert_eq!(buckets[2], ("0.025", 3));
+        assert_eq!(buckets[LATENCY_BUCKETS.len() - 1], ("10", 4));
+        assert_eq!(buckets[LATENCY_BUCKETS.len()], ("+Inf", 5));
+        assert!(text.contains("qdash_step_latency_seconds_count 5\n"));
+        assert!(text.contains("qdash_iterations_total 6\n"));
+    }
+}
@@ -1,6 +1,7 @@
 //! API endpoints
 
 mod handlers;
+mod metrics;
 mod routes;
 
 pub use routes::create_router;
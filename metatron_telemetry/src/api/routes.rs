@@ -20,11 +20,13 @@ pub fn create_router(state: AppState, static_dir: &str) -> Router {
         .route("/jobs", get(handlers::get_jobs))
         .route("/jobs/:id", get(handlers::get_job))
         .route("/history", get(handlers::get_history))
+        .route("/trends", get(handlers::get_trends))
         .route(
             "/control/start_calibration",
             post(handlers::start_calibration),
         )
         .route("/health", get(handlers::health_check))
+        .route("/metrics", get(handlers::get_metrics))
         .with_state(state);
 
     // CORS configuration
@@ -5,6 +5,7 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
+use std::time::Instant;
 use tokio::sync::RwLock;
 use uuid::Uuid;
 
@@ -129,6 +130,129 @@ pub struct HistoryPoint {
     pub algorithm: String,
 }
 
+/// Upper bounds (seconds) of the step-latency histogram buckets
+pub const LATENCY_BUCKETS: [f64; 11] = [
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+/// Histogram of calibration step latencies
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LatencyHistogram {
+    /// Observations per bucket of [`LATENCY_BUCKETS`] (not cumulative);
+    /// the extra last entry counts observations above the largest bound
+    pub bucket_counts: Vec<u64>,
+    /// Sum of all observed latencies (seconds)
+    pub sum: f64,
+    /// Total number of observations
+    pub count: u64,
+}
+
+impl LatencyHistogram {
+    /// Record one latency in seconds
+    pub fn observe(&mut self, seconds: f64) {
+        if self.bucket_counts.is_empty() {
+            self.bucket_counts = vec![0; LATENCY_BUCKETS.len() + 1];
+        }
+        let bucket = LATENCY_BUCKETS
+            .iter()
+            .position(|&bound| seconds <= bound)
+            .unwrap_or(LATENCY_BUCKETS.len());
+        self.bucket_counts[bucket] += 1;
+        self.sum += seconds;
+        self.count += 1;
+    }
+}
+
+/// Point-in-time view of the values exported as metrics
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricsSnapshot {
+    pub psi: f64,
+    pub rho: f64,
+    pub omega: f64,
+    /// Status updates since startup (not capped like the history)
+    pub iterations: u64,
+    /// Time between consecutive status updates
+    pub step_latencies: LatencyHistogram,
+}
+
+/// Window used by the trend endpoint when none is requested
+pub const DEFAULT_TREND_WINDOW: usize = 20;
+
+/// Least-squares slopes (per history point) whose magnitude stays below this
+/// are classified as stable
+pub const TREND_SLOPE_TOLERANCE: f64 = 1e-3;
+
+/// Direction of a metric over the trend window
+///
+/// psi, rho and omega all improve upwards, so a rising metric is improving.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Trend {
+    Improving,
+    Degrading,
+    Stable,
+}
+
+/// Rolling statistics of one metric
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricTrend {
+    /// Mean over the window
+    pub moving_average: f64,
+    /// Least-squares slope, in metric units per history point
+    pub slope: f64,
+    /// Classification of `slope` against [`TREND_SLOPE_TOLERANCE`]
+    pub trend: Trend,
+}
+
+impl MetricTrend {
+    fn from_values(values: &[f64]) -> Self {
+        let n = values.len() as f64;
+        let moving_average = values.iter().sum::<f64>() / n;
+
+        // Ordinary least squares against the point index 0..n
+        let x_mean = (n - 1.0) / 2.0;
+        let (covariance, variance) =
+            values
+                .iter()
+                .enumerate()
+                .fold((0.0, 0.0), |(cov, var), (i, &value)| {
+                    let dx = i as f64 - x_mean;
+                    (cov + dx * (value - moving_average), var + dx * dx)
+                });
+        let slope = if variance > 0.0 {
+            covariance / variance
+        } else {
+            0.0
+        };
+
+        let trend = if slope > TREND_SLOPE_TOLERANCE {
+            Trend::Improving
+        } else if slope < -TREND_SLOPE_TOLERANCE {
+            Trend::Degrading
+        } else {
+            Trend::Stable
+        };
+
+        Self {
+            moving_average,
+            slope,
+            trend,
+        }
+    }
+}
+
+/// Rolling-window statistics of psi, rho and omega
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrendReport {
+    /// Requested window size
+    pub window: usize,
+    /// History points actually used (fewer than `window` early on)
+    pub points: usize,
+    pub psi: MetricTrend,
+    pub rho: MetricTrend,
+    pub omega: MetricTrend,
+}
+
 /// Shared application state
 #[derive(Clone)]
 pub struct AppState {
@@ -142,6 +266,12 @@ struct AppStateInner {
     jobs: Vec<Job>,
     /// Historical metrics (last 1000 points)
     history: Vec<HistoryPoint>,
+    /// Number of status updates since startup
+    iterations: u64,
+    /// Time of the previous status update
+    last_step: Option<Instant>,
+    /// Latencies between status updates
+    step_latencies: LatencyHistogram,
 }
 
 impl AppState {
@@ -173,6 +303,9 @@ impl AppState {
                 },
                 jobs: Vec::new(),
                 history: Vec::new(),
+                iterations: 0,
+                last_step: None,
+                step_latencies: LatencyHistogram::default(),
             })),
         }
     }
@@ -191,6 +324,14 @@ impl AppState {
         state.status.algorithm = algorithm.clone();
         state.status.last_update = Utc::now();
 
+        // Step counter and latency since the previous update
+        let now = Instant::now();
+        if let Some(previous) = state.last_step.replace(now) {
+            let elapsed = now.duration_since(previous).as_secs_f64();
+            state.step_latencies.observe(elapsed);
+        }
+        state.iterations += 1;
+
         // Add to history
         state.history.push(HistoryPoint {
             timestamp: Utc::now(),
@@ -207,6 +348,18 @@ impl AppState {
         }
     }
 
+    /// Snapshot of the exported metrics
+    pub async fn metrics(&self) -> MetricsSnapshot {
+        let state = self.inner.read().await;
+        MetricsSnapshot {
+            psi: state.status.psi,
+            rho: state.status.rho,
+            omega: state.status.omega,
+            iterations: state.iterations,
+            step_latencies: state.step_latencies.clone(),
+        }
+    }
+
     /// Set system mode
     pub async fn set_mode(&self, mode: String) {
         self.inner.write().await.status.mode = mode;
@@ -266,6 +419,34 @@ impl AppState {
         let limit = limit.unwrap_or(1000).min(history.len());
         history[history.len().saturating_sub(limit)..].to_vec()
     }
+
+    /// Moving averages, slopes and trend classification over the most
+    /// recent `window` history points
+    ///
+    /// The window counts status updates, not wall-clock time. With fewer
+    /// than `window` points recorded the statistics use all of them and
+    /// `points` reports how many; a single point has slope 0 and is stable.
+    /// Returns `None` while the history is empty or `window` is 0.
+    pub async fn trend_report(&self, window: usize) -> Option<TrendReport> {
+        let state = self.inner.read().await;
+        let recent = &state.history[state.history.len().saturating_sub(window)..];
+        if recent.is_empty() {
+            return None;
+        }
+
+        let series = |metric: fn(&HistoryPoint) -> f64| -> MetricTrend {
+            let values: Vec<f64> = recent.iter().map(metric).collect();
+            MetricTrend::from_values(&values)
+        };
+
+        Some(TrendReport {
+            window,
+            points: recent.len(),
+            psi: series(|p| p.psi),
+            rho: series(|p| p.rho),
+            omega: series(|p| p.omega),
+        })
+    }
 }
 
 impl Default for AppState {
@@ -273,3 +454,61 @@ impl Default for AppState {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn linear_series_recovers_its_slope() {
+        let rising: Vec<f64> = (0..10).map(|i| 0.5 + 0.01 * i as f64).collect();
+        let stats = MetricTrend::from_values(&rising);
+        assert!((stats.slope - 0.01).abs() < 1e-12);
+        assert!((stats.moving_average - 0.545).abs() < 1e-12);
+        assert_eq!(stats.trend, Trend::Improving);
+
+        let falling: Vec<f64> = rising.iter().rev().copied().collect();
+        let stats = MetricTrend::from_values(&falling);
+        assert!((stats.slope + 0.01).abs() < 1e-12);
+        assert_eq!(stats.trend, Trend::Degrading);
+
+        // Slopes within the tolerance count as stable in either direction
+        let flat: Vec<f64> = (0..10)
+            .map(|i| 0.5 + 0.5 * TREND_SLOPE_TOLERANCE * i as f64)
+            .collect();
+        assert_eq!(MetricTrend::from_values(&flat).trend, Trend::Stable);
+    }
+
+    #[test]
+    fn single_point_is_stable() {
+        let stats = MetricTrend::from_values(&[0.8]);
+        assert_eq!(stats.slope, 0.0);
+        assert_eq!(stats.moving_average, 0.8);
+        assert_eq!(stats.trend, Trend::Stable);
+    }
+
+    #[tokio::test]
+    async fn trend_report_windows_the_history() {
+        let state = AppState::new();
+        assert!(state.trend_report(DEFAULT_TREND_WINDOW).await.is_none());
+
+        for i in 0..10 {
+            let psi = 0.5 + 0.01 * i as f64;
+            state.update_status(psi, 0.9, 0.7, "VQE".to_string()).await;
+        }
+        assert!(state.trend_report(0).await.is_none());
+
+        // Fewer points than the window: everything recorded is used
+        let report = state.trend_report(DEFAULT_TREND_WINDOW).await.unwrap();
+        assert_eq!(report.window, DEFAULT_TREND_WINDOW);
+        assert_eq!(report.points, 10);
+        assert!((report.psi.slope - 0.01).abs() < 1e-12);
+        assert_eq!(report.rho.trend, Trend::Stable);
+
+        // Only the most recent points enter a shorter window
+        let report = state.trend_report(4).await.unwrap();
+        assert_eq!(report.points, 4);
+        assert!((report.psi.moving_average - 0.575).abs() < 1e-12);
+        assert_eq!(report.psi.trend, Trend::Improving);
+    }
+}
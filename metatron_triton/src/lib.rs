@@ -41,7 +41,7 @@ pub mod signature;
 pub mod spiral;
 pub mod strategy;
 
-pub use search::{TritonSearch, TritonStepResult};
+pub use search::{TerminationReason, TritonRunResult, TritonSearch, TritonStepResult};
 pub use signature::SpectralSignature;
 pub use spiral::TritonSpiral;
 pub use strategy::{
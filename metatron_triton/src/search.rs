@@ -6,6 +6,12 @@
 use crate::{SpectralSignature, TritonSpiral};
 use serde::{Deserialize, Serialize};
 
+/// Default number of recent steps inspected by the stagnation detector
+pub const DEFAULT_STAGNATION_WINDOW: usize = 20;
+
+/// Default minimum best-resonance gain over the stagnation window
+pub const DEFAULT_STAGNATION_TOLERANCE: f64 = 1e-9;
+
 /// Result of a single TRITON search step
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TritonStepResult {
@@ -28,6 +34,33 @@ pub struct TritonStepResult {
     pub improvement: f64,
 }
 
+/// Why [`TritonSearch::run_until`] stopped
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TerminationReason {
+    /// Best resonance reached the requested target
+    TargetReached,
+    /// Best resonance stopped improving over the stagnation window
+    Stalled,
+    /// Step budget exhausted without reaching the target or stalling
+    MaxSteps,
+}
+
+/// Outcome of [`TritonSearch::run_until`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TritonRunResult {
+    /// Best parameter point found
+    pub best_point: Vec<f64>,
+
+    /// Spectral signature of the best point
+    pub best_signature: SpectralSignature,
+
+    /// Steps taken during this run
+    pub steps: usize,
+
+    /// Why the run stopped
+    pub reason: TerminationReason,
+}
+
 /// TRITON search engine
 ///
 /// Combines the evolutionary spiral with a user-provided evaluation function
@@ -88,8 +121,17 @@ where
     /// History of resonance values (last N steps)
     resonance_history: Vec<f64>,
 
+    /// Best resonance after each of the last N steps
+    best_history: Vec<f64>,
+
     /// Maximum history size
     history_size: usize,
+
+    /// Steps inspected by the stagnation detector
+    stagnation_window: usize,
+
+    /// Minimum best-resonance gain over the window to count as progress
+    stagnation_tolerance: f64,
 }
 
 impl<Eval> TritonSearch<Eval>
@@ -113,7 +155,10 @@ where
             step: 0,
             max_steps,
             resonance_history: Vec::new(),
+            best_history: Vec::new(),
             history_size: 100,
+            stagnation_window: DEFAULT_STAGNATION_WINDOW,
+            stagnation_tolerance: DEFAULT_STAGNATION_TOLERANCE,
         }
     }
 
@@ -147,10 +192,24 @@ where
             step: 0,
             max_steps,
             resonance_history: Vec::new(),
+            best_history: Vec::new(),
             history_size: 100,
+            stagnation_window: DEFAULT_STAGNATION_WINDOW,
+            stagnation_tolerance: DEFAULT_STAGNATION_TOLERANCE,
         }
     }
 
+    /// Configure the stagnation detector used by [`Self::run_until`]
+    ///
+    /// The search counts as stalled when the best resonance improved by less
+    /// than `tolerance` over the last `window` steps. The window is capped at
+    /// the history size (100 steps).
+    pub fn with_stagnation(mut self, window: usize, tolerance: f64) -> Self {
+        self.stagnation_window = window.clamp(1, self.history_size - 1);
+        self.stagnation_tolerance = tolerance;
+        self
+    }
+
     /// Perform one SOLVE-phase step
     ///
     /// 1. Generate next point from spiral
@@ -222,6 +281,10 @@ where
         if self.resonance_history.len() > self.history_size {
             self.resonance_history.remove(0);
         }
+        self.best_history.push(best_resonance);
+        if self.best_history.len() > self.history_size {
+            self.best_history.remove(0);
+        }
 
         self.step += 1;
 
@@ -290,6 +353,62 @@ where
             .expect("Search should have at least one evaluation")
     }
 
+    /// Step until the best resonance reaches `resonance_target` or stalls
+    ///
+    /// Takes at most `max_steps` further steps, independent of the limit given
+    /// at construction. Stalling is judged by [`Self::is_stagnant`] with the
+    /// window and tolerance set via [`Self::with_stagnation`].
+    ///
+    /// # Panics
+    /// Panics if `max_steps` is zero and no step has been taken before.
+    pub fn run_until(&mut self, max_steps: usize, resonance_target: f64) -> TritonRunResult {
+        let mut reason = TerminationReason::MaxSteps;
+        let mut steps = 0;
+
+        while steps < max_steps {
+            let result = self.step();
+            steps += 1;
+
+            if result.best_resonance >= resonance_target {
+                reason = TerminationReason::TargetReached;
+                break;
+            }
+            if self.is_stagnant(self.stagnation_window, self.stagnation_tolerance) {
+                reason = TerminationReason::Stalled;
+                break;
+            }
+        }
+
+        tracing::info!(
+            "TRITON: run_until stopped after {} steps ({:?})",
+            steps,
+            reason
+        );
+
+        let (best_point, best_signature) = self
+            .best()
+            .expect("Search should have at least one evaluation");
+        TritonRunResult {
+            best_point,
+            best_signature,
+            steps,
+            reason,
+        }
+    }
+
+    /// Whether the best resonance gained less than `tolerance` over the last
+    /// `k` steps
+    ///
+    /// Returns `false` until `k + 1` steps of history are available (at most
+    /// the history size of 100).
+    pub fn is_stagnant(&self, k: usize, tolerance: f64) -> bool {
+        let history = &self.best_history;
+        if k == 0 || history.len() < k + 1 {
+            return false;
+        }
+        history[history.len() - 1] - history[history.len() - 1 - k] < tolerance
+    }
+
     /// Get the best point and signature found so far
     pub fn best(&self) -> Option<(Vec<f64>, SpectralSignature)> {
         if let (Some(point), Some(sig)) = (&self.best_point, &self.best_signature) {
@@ -346,6 +465,7 @@ where
         self.prev_resonance = 0.0;
         self.step = 0;
         self.resonance_history.clear();
+        self.best_history.clear();
     }
 }
 
@@ -428,6 +548,35 @@ mod tests {
         assert!(final_sig.resonance() > 0.3);
     }
 
+    #[test]
+    fn test_run_until_stops_on_target_or_stall() {
+        let evaluator = |params: &[f64]| {
+            let psi = 1.0 - 4.0 * (params[0] - 0.5).powi(2);
+            let rho = 1.0 - 4.0 * (params[1] - 0.5).powi(2);
+            let omega = 1.0 - 4.0 * (params[2] - 0.5).powi(2);
+            SpectralSignature::new(psi.max(0.0), rho.max(0.0), omega.max(0.0))
+        };
+
+        let mut search = TritonSearch::new(3, 42, 1000, evaluator);
+        let result = search.run_until(1000, 0.5);
+        assert_eq!(result.reason, TerminationReason::TargetReached);
+        assert!(result.best_signature.resonance() >= 0.5);
+        assert_eq!(result.steps, search.current_step());
+
+        // A flat landscape never improves after the first evaluation
+        let flat = |_params: &[f64]| SpectralSignature::new(0.8, 0.9, 0.7);
+        let mut search = TritonSearch::new(3, 42, 1000, flat).with_stagnation(10, 1e-9);
+        let result = search.run_until(1000, 0.9);
+        assert_eq!(result.reason, TerminationReason::Stalled);
+        assert_eq!(result.steps, 11);
+        assert!(search.is_stagnant(10, 1e-9));
+
+        let mut search = TritonSearch::new(3, 42, 1000, flat);
+        let result = search.run_until(5, 0.9);
+        assert_eq!(result.reason, TerminationReason::MaxSteps);
+        assert_eq!(result.steps, 5);
+    }
+
     #[test]
     fn test_resonance_history() {
         let evaluator = |_params: &[f64]| SpectralSignature::new(0.8, 0.9, 0.7);